@@ -0,0 +1,2 @@
+DROP TABLE chain_status;
+DROP TYPE chain_status;
@@ -0,0 +1,8 @@
+CREATE TYPE chain_status AS ENUM ('active', 'draining', 'paused');
+
+CREATE TABLE chain_status
+(
+    chain_id    VARCHAR(255) PRIMARY KEY,
+    status      chain_status NOT NULL,
+    update_time TIMESTAMP    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,3 @@
+DROP INDEX bid_unfinalized_idx;
+ALTER TABLE bid DROP COLUMN finalization_mismatch;
+ALTER TABLE bid DROP COLUMN finalized_at;
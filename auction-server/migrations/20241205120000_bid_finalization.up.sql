@@ -0,0 +1,5 @@
+ALTER TABLE bid ADD COLUMN finalized_at TIMESTAMP;
+ALTER TABLE bid ADD COLUMN finalization_mismatch BOOLEAN NOT NULL DEFAULT FALSE;
+-- The signatures of the bids concluded before the settlement check are not retrievable anymore
+UPDATE bid SET finalized_at = conclusion_time WHERE status IN ('won', 'failed') AND conclusion_time IS NOT NULL;
+CREATE INDEX bid_unfinalized_idx ON bid (chain_id, conclusion_time) WHERE finalized_at IS NULL;
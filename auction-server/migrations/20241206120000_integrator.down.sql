@@ -0,0 +1,4 @@
+DROP TABLE integrator_profile;
+DROP TABLE integrator;
+DROP TYPE integrator_submitter;
+DROP TYPE integrator_program;
@@ -0,0 +1,22 @@
+CREATE TYPE integrator_program AS ENUM ('limo', 'phantom');
+CREATE TYPE integrator_submitter AS ENUM ('integrator', 'server');
+
+CREATE TABLE integrator
+(
+    id             UUID                 PRIMARY KEY,
+    chain_id       TEXT                 NOT NULL,
+    name           TEXT                 NOT NULL,
+    program        integrator_program   NOT NULL,
+    router_account TEXT                 NOT NULL,
+    submitter      integrator_submitter NOT NULL,
+    created_at     TIMESTAMP            NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (chain_id, name),
+    UNIQUE (chain_id, router_account)
+);
+
+CREATE TABLE integrator_profile
+(
+    integrator_id UUID NOT NULL REFERENCES integrator (id) ON DELETE CASCADE,
+    profile_id    UUID NOT NULL REFERENCES profile (id) ON DELETE CASCADE,
+    PRIMARY KEY (integrator_id, profile_id)
+);
@@ -0,0 +1,2 @@
+DROP TABLE quote;
+DROP TYPE quote_status;
@@ -0,0 +1,21 @@
+CREATE TYPE quote_status AS ENUM ('issued', 'submitted', 'landed', 'expired');
+
+CREATE TABLE quote
+(
+    id                          UUID             PRIMARY KEY,
+    chain_id                    TEXT             NOT NULL,
+    opportunity_id              UUID             NOT NULL,
+    bid_id                      UUID             NOT NULL REFERENCES bid (id),
+    user_wallet_address         TEXT             NOT NULL,
+    input_token                 JSONB            NOT NULL,
+    output_token                JSONB            NOT NULL,
+    maximum_slippage_percentage DOUBLE PRECISION NOT NULL,
+    signature                   TEXT             NOT NULL,
+    expiration_time             TIMESTAMP        NOT NULL,
+    status                      quote_status     NOT NULL,
+    creation_time               TIMESTAMP        NOT NULL,
+    update_time                 TIMESTAMP        NOT NULL
+);
+
+CREATE INDEX quote_bid_id_idx ON quote (bid_id);
+CREATE INDEX quote_chain_id_creation_time_idx ON quote (chain_id, creation_time);
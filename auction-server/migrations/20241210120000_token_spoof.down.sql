@@ -0,0 +1,3 @@
+DROP TABLE token_spoof_override;
+DROP TABLE token_spoof_info;
+DROP TYPE storage_layout;
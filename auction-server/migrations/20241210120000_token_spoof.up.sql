@@ -0,0 +1,24 @@
+CREATE TYPE storage_layout AS ENUM ('solidity', 'vyper');
+
+CREATE TABLE token_spoof_info
+(
+    chain_id       TEXT           NOT NULL,
+    token          BYTEA          NOT NULL,
+    balance_slot   NUMERIC(78, 0) NOT NULL,
+    allowance_slot NUMERIC(78, 0) NOT NULL,
+    layout         storage_layout NOT NULL,
+    implementation BYTEA,
+    creation_time  TIMESTAMP      NOT NULL,
+    PRIMARY KEY (chain_id, token)
+);
+
+CREATE TABLE token_spoof_override
+(
+    chain_id       TEXT           NOT NULL,
+    token          BYTEA          NOT NULL,
+    balance_slot   NUMERIC(78, 0) NOT NULL,
+    allowance_slot NUMERIC(78, 0) NOT NULL,
+    layout         storage_layout NOT NULL,
+    creation_time  TIMESTAMP      NOT NULL,
+    PRIMARY KEY (chain_id, token)
+);
@@ -0,0 +1,3 @@
+DROP INDEX opportunity_chain_id_permission_key_state_idx;
+ALTER TABLE opportunity DROP COLUMN state;
+DROP TYPE opportunity_state;
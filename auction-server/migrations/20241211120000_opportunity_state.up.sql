@@ -0,0 +1,6 @@
+CREATE TYPE opportunity_state AS ENUM ('live', 'in_auction', 'auction_concluded', 'removed');
+
+ALTER TABLE opportunity ADD COLUMN state opportunity_state NOT NULL DEFAULT 'live';
+UPDATE opportunity SET state = 'removed' WHERE removal_time IS NOT NULL;
+
+CREATE INDEX opportunity_chain_id_permission_key_state_idx ON opportunity (chain_id, permission_key, state);
@@ -0,0 +1,2 @@
+ALTER TABLE opportunity DROP COLUMN exclusivity;
+ALTER TABLE opportunity DROP COLUMN expiration_time;
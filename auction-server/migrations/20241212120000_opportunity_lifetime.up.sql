@@ -0,0 +1,2 @@
+ALTER TABLE opportunity ADD COLUMN expiration_time TIMESTAMP;
+ALTER TABLE opportunity ADD COLUMN exclusivity JSONB;
@@ -0,0 +1,2 @@
+ALTER TABLE opportunity DROP COLUMN profile_id;
+DROP TABLE permission_key_namespace;
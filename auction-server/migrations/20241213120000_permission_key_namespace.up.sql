@@ -0,0 +1,11 @@
+CREATE TABLE permission_key_namespace
+(
+    id         UUID      PRIMARY KEY,
+    chain_id   TEXT      NOT NULL,
+    profile_id UUID      NOT NULL REFERENCES profile (id) ON DELETE CASCADE,
+    prefix     BYTEA     NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (chain_id, prefix)
+);
+
+ALTER TABLE opportunity ADD COLUMN profile_id UUID REFERENCES profile (id);
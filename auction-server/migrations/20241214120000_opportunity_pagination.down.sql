@@ -0,0 +1,2 @@
+DROP INDEX opportunity_profile_id_creation_time_idx;
+DROP INDEX opportunity_creation_time_id_idx;
@@ -0,0 +1,2 @@
+CREATE INDEX opportunity_creation_time_id_idx ON opportunity (chain_id, creation_time, id);
+CREATE INDEX opportunity_profile_id_creation_time_idx ON opportunity (profile_id, creation_time);
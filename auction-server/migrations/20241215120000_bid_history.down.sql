@@ -0,0 +1,2 @@
+DROP INDEX bid_auction_id_idx;
+DROP INDEX bid_profile_id_chain_id_initiation_time_id_idx;
@@ -0,0 +1,2 @@
+CREATE INDEX bid_profile_id_chain_id_initiation_time_id_idx ON bid (profile_id, chain_id, initiation_time, id);
+CREATE INDEX bid_auction_id_idx ON bid (auction_id);
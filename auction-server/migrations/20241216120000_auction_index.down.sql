@@ -0,0 +1 @@
+DROP INDEX auction_chain_id_creation_time_id_idx;
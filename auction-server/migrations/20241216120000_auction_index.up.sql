@@ -0,0 +1 @@
+CREATE INDEX auction_chain_id_creation_time_id_idx ON auction (chain_id, creation_time, id);
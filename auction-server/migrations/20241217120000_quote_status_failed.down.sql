@@ -0,0 +1,7 @@
+UPDATE quote SET status = 'submitted' WHERE status = 'failed';
+CREATE TYPE temp_quote_status AS ENUM ('issued', 'submitted', 'landed', 'expired');
+ALTER TABLE quote
+    ALTER COLUMN status TYPE temp_quote_status
+    USING status::text::temp_quote_status;
+DROP TYPE IF EXISTS quote_status;
+ALTER TYPE temp_quote_status RENAME TO quote_status;
@@ -0,0 +1 @@
+ALTER TYPE quote_status ADD VALUE 'failed';
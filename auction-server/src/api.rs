@@ -4,9 +4,11 @@ use {
             APIResponse,
             ClientMessage,
             ClientRequest,
+            OpportunitySubscriptionFilter,
             ServerResultMessage,
             ServerResultResponse,
             ServerUpdateResponse,
+            SubscriptionCapability,
         },
         auction::api::{
             self as bid,
@@ -86,6 +88,9 @@ async fn root() -> String {
     format!("Express Relay Auction Server API {}", crate_version!())
 }
 
+pub mod health;
+pub mod integrator;
+pub mod permission_key_namespace;
 pub mod profile;
 pub(crate) mod ws;
 
@@ -103,6 +108,8 @@ pub enum RestError {
     OpportunityNotFound,
     /// The bid was not found.
     BidNotFound,
+    /// The auction was not found.
+    AuctionNotFound,
     /// Internal error occurred during processing the request.
     TemporarilyUnavailable,
     /// Auth token is invalid.
@@ -115,6 +122,12 @@ pub enum RestError {
     ProfileNotFound,
     /// The quote was not found.
     QuoteNotFound,
+    /// The quote with the specified id was not found.
+    QuoteIdNotFound,
+    /// The relayer of the chain is out of funds.
+    RelayerUnavailable,
+    /// The chain is paused or draining for maintenance.
+    ChainUnderMaintenance,
 }
 
 impl RestError {
@@ -143,6 +156,10 @@ impl RestError {
                 StatusCode::NOT_FOUND,
                 "Bid with the specified id was not found".to_string(),
             ),
+            RestError::AuctionNotFound => (
+                StatusCode::NOT_FOUND,
+                "Auction with the specified id was not found".to_string(),
+            ),
             RestError::TemporarilyUnavailable => (
                 StatusCode::SERVICE_UNAVAILABLE,
                 "This service is temporarily unavailable".to_string(),
@@ -161,6 +178,20 @@ impl RestError {
                 StatusCode::NOT_FOUND,
                 "No quote is currently available".to_string(),
             ),
+            RestError::QuoteIdNotFound => (
+                StatusCode::NOT_FOUND,
+                "Quote with the specified id was not found".to_string(),
+            ),
+            RestError::RelayerUnavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Relayer is unavailable for this chain, bids are not accepted until it is funded"
+                    .to_string(),
+            ),
+            RestError::ChainUnderMaintenance => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "The chain is under maintenance, new bids and opportunities are not accepted"
+                    .to_string(),
+            ),
         }
     }
 }
@@ -222,7 +253,11 @@ impl FromRequestParts<Arc<StoreNew>> for Auth {
     }
 }
 
-async fn admin_middleware(auth: Auth, req: extract::Request, next: middleware::Next) -> Response {
+pub async fn admin_middleware(
+    auth: Auth,
+    req: extract::Request,
+    next: middleware::Next,
+) -> Response {
     match auth {
         Auth::Admin => next.run(req).await,
         _ => (StatusCode::FORBIDDEN, "Forbidden").into_response(),
@@ -278,17 +313,27 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
     paths(
     bid::post_bid,
     bid::get_bid_status,
+    bid::post_bid_statuses,
     bid::get_bids_by_time,
     bid::get_bids_by_time_deprecated,
     bid::get_bid_status_deprecated,
+    bid::get_auction,
+    bid::get_auctions,
+    bid::get_chain_status,
+    bid::put_chain_status,
 
     opportunity::post_opportunity,
     opportunity::opportunity_bid,
     opportunity::get_opportunities,
     opportunity::post_quote,
+    opportunity::get_quote_status,
     opportunity::delete_opportunities,
+    opportunity::post_spoof_override,
 
     profile::delete_profile_access_token,
+
+    health::get_health,
+    health::get_ready,
     ),
     components(
     schemas(
@@ -300,11 +345,23 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
     bid::BidStatusEvm,
     bid::BidStatusSvm,
     bid::BidStatusWithId,
+    bid::BidStatusesQuery,
     bid::BidResult,
     bid::Bid,
     bid::BidEvm,
     bid::BidSvm,
     bid::Bids,
+    bid::BidAuction,
+    bid::BidStatusKind,
+    bid::BidExpansion,
+    bid::Auction,
+    bid::AuctionBid,
+    bid::Auctions,
+    bid::ChainStatus,
+    bid::ChainStatusUpdate,
+    bid::ChainStatusWithId,
+    bid::RelayerStatus,
+    bid::RelayerStatusUpdate,
     SvmChainUpdate,
 
     opportunity::OpportunityBidEvm,
@@ -316,11 +373,14 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
     opportunity::OpportunityCreateV1Evm,
     opportunity::OpportunityCreateV1Svm,
     opportunity::OpportunityCreateProgramParamsV1Svm,
+    opportunity::OpportunityExclusivity,
     opportunity::Opportunity,
     opportunity::OpportunityEvm,
     opportunity::OpportunitySvm,
     opportunity::TokenAmountEvm,
     opportunity::TokenAmountSvm,
+    opportunity::LimoOrderDetails,
+    opportunity::LimoOrderStatus,
     opportunity::OpportunityParamsSvm,
     opportunity::OpportunityParamsEvm,
     opportunity::OpportunityParamsV1Svm,
@@ -332,19 +392,34 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
     opportunity::Quote,
     opportunity::QuoteSvm,
     opportunity::QuoteV1Svm,
+    opportunity::QuoteRequestResult,
+    opportunity::QuoteStatus,
+    opportunity::SpecifiedTokenSide,
+    opportunity::QuoteDetails,
+    opportunity::QuoteUpdate,
     opportunity::OpportunityDelete,
     opportunity::OpportunityDeleteSvm,
     opportunity::OpportunityDeleteEvm,
     opportunity::OpportunityDeleteV1Svm,
     opportunity::OpportunityDeleteV1Evm,
+    opportunity::OpportunityRemovalReason,
     opportunity::ProgramSvm,
+    opportunity::StorageLayout,
+    opportunity::SpoofOverrideCreate,
 
     ErrorBodyResponse,
     ClientRequest,
     ClientMessage,
+    SubscriptionCapability,
+    OpportunitySubscriptionFilter,
     ServerResultMessage,
     ServerUpdateResponse,
     ServerResultResponse,
+
+    health::Health,
+    health::ChainHealth,
+    health::LoopHealth,
+    health::RelayerHealth,
     ),
     responses(
     ErrorBodyResponse,
@@ -388,18 +463,46 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
             login_required!(store, delete(profile::delete_profile_access_token)),
         );
 
+    let integrator_routes = Router::new()
+        .route("/", admin_only!(store, post(integrator::post_integrator)))
+        .route("/", admin_only!(store, get(integrator::get_integrators)));
+
+    let permission_key_namespace_routes = Router::new()
+        .route(
+            "/",
+            admin_only!(
+                store,
+                post(permission_key_namespace::post_permission_key_namespace)
+            ),
+        )
+        .route(
+            "/",
+            admin_only!(
+                store,
+                get(permission_key_namespace::get_permission_key_namespaces)
+            ),
+        );
+
     let v1_routes = Router::new().nest(
         "/v1",
         Router::new()
             .nest("/bids", bid::get_routes(store.clone()))
             .nest("/opportunities", opportunity::get_routes(store.clone()))
             .nest("/profiles", profile_routes)
+            .nest("/integrators", integrator_routes)
+            .nest(
+                "/permission_key_namespaces",
+                permission_key_namespace_routes,
+            )
             .route("/ws", get(ws::ws_route_handler)),
     );
 
     let v1_routes_with_chain_id = Router::new().nest(
         "/v1/:chain_id",
-        Router::new().nest("/bids", bid::get_routes_with_chain_id(store.clone())),
+        Router::new()
+            .nest("/bids", bid::get_routes_with_chain_id(store.clone()))
+            .nest("/auctions", bid::get_auction_routes())
+            .nest("/status", bid::get_chain_status_routes(store.clone())),
     );
 
     let (prometheus_layer, _) = PrometheusMetricLayerBuilder::new()
@@ -422,6 +525,8 @@ pub async fn start_api(run_options: RunOptions, store: Arc<StoreNew>) -> Result<
         .merge(v1_routes_with_chain_id)
         .route("/", get(root))
         .route("/live", get(live))
+        .route("/health", get(health::get_health))
+        .route("/ready", get(health::get_ready))
         .route("/docs/openapi.json", get(original_doc.to_string()))
         .layer(CorsLayer::permissive())
         .layer(middleware::from_extractor_with_state::<Auth, Arc<StoreNew>>(store.clone()))
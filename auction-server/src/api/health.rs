@@ -0,0 +1,221 @@
+use {
+    crate::{
+        auction::{
+            api::RelayerStatus,
+            entities,
+            service::ServiceEnum,
+        },
+        kernel::entities::ChainId,
+        state::StoreNew,
+    },
+    axum::{
+        extract::State,
+        http::StatusCode,
+        response::{
+            IntoResponse,
+            Response,
+        },
+        Json,
+    },
+    serde::Serialize,
+    std::{
+        sync::Arc,
+        time::Duration,
+    },
+    time::OffsetDateTime,
+    utoipa::{
+        ToResponse,
+        ToSchema,
+    },
+};
+
+/// A loop is considered dead if it has not ticked for this long.
+const LOOP_STALE_THRESHOLD: Duration = Duration::from_secs(60);
+/// A loop is considered unhealthy if it has failed recently.
+const LOOP_ERROR_THRESHOLD: Duration = Duration::from_secs(30);
+/// A chain rpc is considered unreachable if it has not answered successfully for this long.
+const RPC_STALE_THRESHOLD: Duration = Duration::from_secs(60);
+/// A chain websocket is considered stalled if no subscription received an event for this long.
+const WS_STALE_THRESHOLD: Duration = Duration::from_secs(60);
+const DATABASE_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct RelayerHealth {
+    /// The chain id of the relayer.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:    ChainId,
+    /// The relayer address, null if the balance is not fetched yet.
+    #[schema(example = "0x5FbDB2315678afecb367f032d93F642f64180aa2", value_type = Option<String>)]
+    pub address:     Option<String>,
+    /// The relayer balance in the native token of the chain.
+    #[schema(example = 1.5, value_type = Option<f64>)]
+    pub balance:     Option<f64>,
+    /// The relayer balance status.
+    pub status:      Option<RelayerStatus>,
+    /// The last time the balance was fetched (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = Option<i64>)]
+    pub update_time: Option<i64>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct ChainHealth {
+    /// The chain id.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:              ChainId,
+    /// Whether the chain rpc answered successfully recently.
+    pub rpc_reachable:         bool,
+    /// The last time a rpc request succeeded (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = Option<i64>)]
+    pub rpc_last_success_time: Option<i64>,
+    /// The last time a rpc request failed (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = Option<i64>)]
+    pub rpc_last_error_time:   Option<i64>,
+    /// The latency of the last successful rpc request in seconds.
+    #[schema(example = 0.1, value_type = Option<f64>)]
+    pub rpc_last_latency:      Option<f64>,
+    /// Whether the chain websocket subscriptions received an event recently.
+    pub ws_connected:          bool,
+    /// The last time an event was received from the chain websocket subscriptions (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = Option<i64>)]
+    pub ws_last_event_time:    Option<i64>,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+pub struct LoopHealth {
+    /// The name of the loop.
+    #[schema(example = "submission loop for chain op_sepolia")]
+    pub name:                    String,
+    /// Whether the loop ticked recently and has not failed recently.
+    pub healthy:                 bool,
+    /// Seconds since the last tick of the loop, null if the loop has never ticked.
+    #[schema(example = 0.5, value_type = Option<f64>)]
+    pub seconds_since_last_tick: Option<f64>,
+    /// The last time the loop failed (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = Option<i64>)]
+    pub last_error_time:         Option<i64>,
+}
+
+#[derive(Serialize, ToSchema, ToResponse, Clone)]
+pub struct Health {
+    /// Whether the server is ready to serve the requests.
+    pub ready:              bool,
+    /// Whether the database is reachable.
+    pub database_connected: bool,
+    pub chains:             Vec<ChainHealth>,
+    pub loops:              Vec<LoopHealth>,
+    pub relayers:           Vec<RelayerHealth>,
+}
+
+fn get_relayer_health(
+    chain_id: ChainId,
+    relayer_balance: Option<entities::RelayerBalance>,
+) -> RelayerHealth {
+    RelayerHealth {
+        chain_id,
+        address: relayer_balance.as_ref().map(|b| b.address.clone()),
+        balance: relayer_balance.as_ref().map(|b| b.balance),
+        status: relayer_balance.as_ref().map(|b| b.status.into()),
+        update_time: relayer_balance.map(|b| b.update_time.unix_timestamp()),
+    }
+}
+
+fn is_recent(time: Option<OffsetDateTime>, now: OffsetDateTime, threshold: Duration) -> bool {
+    time.is_some_and(|time| now - time < threshold)
+}
+
+async fn is_database_connected(store: &StoreNew) -> bool {
+    let query = sqlx::query("SELECT 1").execute(&store.store.db);
+    matches!(
+        tokio::time::timeout(DATABASE_CHECK_TIMEOUT, query).await,
+        Ok(Ok(_))
+    )
+}
+
+async fn get_server_health(store: &StoreNew) -> Health {
+    let now = OffsetDateTime::now_utc();
+    let database_connected = is_database_connected(store).await;
+
+    let health = &store.store.health;
+    let rpc_health = health.get_rpc_health();
+    let ws_events = health.get_ws_events();
+    let mut chains = vec![];
+    let mut relayers = vec![];
+    for (chain_id, service) in store.get_all_auction_services().iter() {
+        let rpc = rpc_health.get(chain_id).cloned().unwrap_or_default();
+        let ws_last_event_time = ws_events.get(chain_id).cloned();
+        chains.push(ChainHealth {
+            chain_id:              chain_id.clone(),
+            rpc_reachable:         is_recent(rpc.last_success_time, now, RPC_STALE_THRESHOLD),
+            rpc_last_success_time: rpc.last_success_time.map(|t| t.unix_timestamp()),
+            rpc_last_error_time:   rpc.last_error_time.map(|t| t.unix_timestamp()),
+            rpc_last_latency:      rpc.last_latency,
+            ws_connected:          is_recent(ws_last_event_time, now, WS_STALE_THRESHOLD),
+            ws_last_event_time:    ws_last_event_time.map(|t| t.unix_timestamp()),
+        });
+
+        let relayer_balance = match service {
+            ServiceEnum::Evm(service) => service.get_relayer_balance().await,
+            ServiceEnum::Svm(service) => service.get_relayer_balance().await,
+        };
+        relayers.push(get_relayer_health(chain_id.clone(), relayer_balance));
+    }
+    chains.sort_by(|a, b| a.chain_id.cmp(&b.chain_id));
+    relayers.sort_by(|a, b| a.chain_id.cmp(&b.chain_id));
+
+    let mut loops: Vec<LoopHealth> = health
+        .get_loops_health()
+        .into_iter()
+        .map(|(name, loop_health)| LoopHealth {
+            name,
+            healthy: is_recent(loop_health.last_tick_time, now, LOOP_STALE_THRESHOLD)
+                && !is_recent(loop_health.last_error_time, now, LOOP_ERROR_THRESHOLD),
+            seconds_since_last_tick: loop_health
+                .last_tick_time
+                .map(|t| (now - t).as_seconds_f64()),
+            last_error_time: loop_health.last_error_time.map(|t| t.unix_timestamp()),
+        })
+        .collect();
+    loops.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let ready = database_connected
+        && chains
+            .iter()
+            .all(|chain| chain.rpc_reachable && chain.ws_connected)
+        && loops.iter().all(|l| l.healthy);
+    Health {
+        ready,
+        database_connected,
+        chains,
+        loops,
+        relayers,
+    }
+}
+
+/// Get the health of the server components.
+///
+/// Reports the database connectivity, the rpc and websocket health of each chain,
+/// the time since each background loop last ticked and the relayer balances.
+#[utoipa::path(get, path = "/health", responses(
+    (status = 200, description = "Health of the server components", body = Health),
+),)]
+pub async fn get_health(State(store): State<Arc<StoreNew>>) -> Json<Health> {
+    Json(get_server_health(&store).await)
+}
+
+/// Check if the server is ready to serve the requests.
+///
+/// The server is ready when the database is reachable, the rpc and the websocket of every chain
+/// received answers recently and all the background loops are ticking without failures.
+#[utoipa::path(get, path = "/ready", responses(
+    (status = 200, description = "The server is ready", body = Health),
+    (status = 503, description = "The server is not ready", body = Health),
+),)]
+pub async fn get_ready(State(store): State<Arc<StoreNew>>) -> Response {
+    let health = get_server_health(&store).await;
+    let status = if health.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(health)).into_response()
+}
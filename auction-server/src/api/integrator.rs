@@ -0,0 +1,183 @@
+use {
+    crate::{
+        api::{
+            ErrorBodyResponse,
+            RestError,
+        },
+        kernel::entities::ChainId,
+        models,
+        opportunity::api::ProgramSvm,
+        state::StoreNew,
+    },
+    axum::{
+        extract::State,
+        Json,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    serde_with::{
+        serde_as,
+        DisplayFromStr,
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::{
+        str::FromStr,
+        sync::Arc,
+    },
+    utoipa::{
+        ToResponse,
+        ToSchema,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, ToResponse, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegratorSubmitter {
+    /// The integrator submits the winning bid transaction itself.
+    Integrator,
+    /// The server submits the winning bid transaction.
+    Server,
+}
+
+impl From<models::IntegratorSubmitter> for IntegratorSubmitter {
+    fn from(submitter: models::IntegratorSubmitter) -> Self {
+        match submitter {
+            models::IntegratorSubmitter::Integrator => IntegratorSubmitter::Integrator,
+            models::IntegratorSubmitter::Server => IntegratorSubmitter::Server,
+        }
+    }
+}
+
+impl From<IntegratorSubmitter> for models::IntegratorSubmitter {
+    fn from(submitter: IntegratorSubmitter) -> Self {
+        match submitter {
+            IntegratorSubmitter::Integrator => models::IntegratorSubmitter::Integrator,
+            IntegratorSubmitter::Server => models::IntegratorSubmitter::Server,
+        }
+    }
+}
+
+impl From<models::IntegratorProgram> for ProgramSvm {
+    fn from(program: models::IntegratorProgram) -> Self {
+        match program {
+            models::IntegratorProgram::Limo => ProgramSvm::Limo,
+            models::IntegratorProgram::Phantom => ProgramSvm::Phantom,
+        }
+    }
+}
+
+impl From<ProgramSvm> for models::IntegratorProgram {
+    fn from(program: ProgramSvm) -> Self {
+        match program {
+            ProgramSvm::Limo => models::IntegratorProgram::Limo,
+            ProgramSvm::Phantom => models::IntegratorProgram::Phantom,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, ToSchema, Clone, ToResponse, Debug)]
+pub struct CreateIntegrator {
+    /// The chain id of the integrator.
+    #[schema(example = "solana", value_type = String)]
+    pub chain_id:       ChainId,
+    /// The unique name of the integrator on the chain.
+    #[schema(example = "phantom")]
+    pub name:           String,
+    /// The program of the opportunities created by the integrator.
+    pub program:        ProgramSvm,
+    /// The router account receiving the fees of the integrator opportunities.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub router_account: Pubkey,
+    /// Who submits the winning bids of the integrator opportunities.
+    pub submitter:      IntegratorSubmitter,
+    /// The profiles allowed to act on behalf of the integrator.
+    #[schema(value_type = Vec<String>)]
+    pub profile_ids:    Vec<models::ProfileId>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, ToResponse)]
+pub struct Integrator {
+    /// The id of the integrator.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:             models::IntegratorId,
+    /// The chain id of the integrator.
+    #[schema(example = "solana", value_type = String)]
+    pub chain_id:       ChainId,
+    /// The unique name of the integrator on the chain.
+    #[schema(example = "phantom")]
+    pub name:           String,
+    /// The program of the opportunities created by the integrator.
+    pub program:        ProgramSvm,
+    /// The router account receiving the fees of the integrator opportunities.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    pub router_account: String,
+    /// Who submits the winning bids of the integrator opportunities.
+    pub submitter:      IntegratorSubmitter,
+    /// The profiles allowed to act on behalf of the integrator.
+    #[schema(value_type = Vec<String>)]
+    pub profile_ids:    Vec<models::ProfileId>,
+}
+
+impl From<models::Integrator> for Integrator {
+    fn from(integrator: models::Integrator) -> Self {
+        Integrator {
+            id:             integrator.id,
+            chain_id:       integrator.chain_id,
+            name:           integrator.name,
+            program:        integrator.program.into(),
+            router_account: integrator.router_account,
+            submitter:      integrator.submitter.into(),
+            profile_ids:    integrator.profile_ids,
+        }
+    }
+}
+
+impl models::Integrator {
+    pub fn get_router_account(&self) -> Pubkey {
+        Pubkey::from_str(&self.router_account).expect("Router account is validated on creation")
+    }
+}
+
+/// Register a new integrator program for the quotes and opportunities.
+///
+/// Returns the created integrator object.
+#[utoipa::path(post, path = "/v1/integrators",
+security(
+("bearerAuth" = []),
+),request_body = CreateIntegrator, responses(
+(status = 200, description = "The created integrator", body = Integrator),
+(status = 400, response = ErrorBodyResponse),
+),)]
+pub async fn post_integrator(
+    State(store): State<Arc<StoreNew>>,
+    Json(params): Json<CreateIntegrator>,
+) -> Result<Json<Integrator>, RestError> {
+    let integrator = store.store.create_integrator(params).await?;
+    Ok(Json(integrator.into()))
+}
+
+/// Get all the registered integrators.
+#[utoipa::path(get, path = "/v1/integrators",
+security(
+("bearerAuth" = []),
+), responses(
+(status = 200, description = "The registered integrators", body = Vec<Integrator>),
+(status = 400, response = ErrorBodyResponse),
+),)]
+pub async fn get_integrators(
+    State(store): State<Arc<StoreNew>>,
+) -> Result<Json<Vec<Integrator>>, RestError> {
+    Ok(Json(
+        store
+            .store
+            .get_integrators()
+            .await
+            .into_iter()
+            .map(|integrator| integrator.into())
+            .collect(),
+    ))
+}
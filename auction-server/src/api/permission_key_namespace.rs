@@ -0,0 +1,105 @@
+use {
+    crate::{
+        api::{
+            ErrorBodyResponse,
+            RestError,
+        },
+        kernel::entities::ChainId,
+        models,
+        state::StoreNew,
+    },
+    axum::{
+        extract::State,
+        Json,
+    },
+    ethers::types::Bytes,
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    std::sync::Arc,
+    utoipa::{
+        ToResponse,
+        ToSchema,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, ToResponse, Debug)]
+pub struct CreatePermissionKeyNamespace {
+    /// The evm chain id of the namespace.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:   ChainId,
+    /// The profile allowed to submit and delete the opportunities of the namespace.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub profile_id: models::ProfileId,
+    /// The prefix of the permission keys in the namespace, usually the address of the protocol contract.
+    #[schema(example = "0xcA11bde05977b3631167028862bE2a173976CA11", value_type = String)]
+    pub prefix:     Bytes,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, ToResponse)]
+pub struct PermissionKeyNamespace {
+    /// The id of the namespace.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:         models::PermissionKeyNamespaceId,
+    /// The evm chain id of the namespace.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:   ChainId,
+    /// The profile allowed to submit and delete the opportunities of the namespace.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub profile_id: models::ProfileId,
+    /// The prefix of the permission keys in the namespace.
+    #[schema(example = "0xcA11bde05977b3631167028862bE2a173976CA11", value_type = String)]
+    pub prefix:     Bytes,
+}
+
+impl From<models::PermissionKeyNamespace> for PermissionKeyNamespace {
+    fn from(namespace: models::PermissionKeyNamespace) -> Self {
+        PermissionKeyNamespace {
+            id:         namespace.id,
+            chain_id:   namespace.chain_id,
+            profile_id: namespace.profile_id,
+            prefix:     namespace.prefix.into(),
+        }
+    }
+}
+
+/// Reserve a permission key prefix on an evm chain for a protocol profile.
+///
+/// Returns the created namespace object.
+#[utoipa::path(post, path = "/v1/permission_key_namespaces",
+security(
+("bearerAuth" = []),
+),request_body = CreatePermissionKeyNamespace, responses(
+(status = 200, description = "The created namespace", body = PermissionKeyNamespace),
+(status = 400, response = ErrorBodyResponse),
+),)]
+pub async fn post_permission_key_namespace(
+    State(store): State<Arc<StoreNew>>,
+    Json(params): Json<CreatePermissionKeyNamespace>,
+) -> Result<Json<PermissionKeyNamespace>, RestError> {
+    let namespace = store.store.create_permission_key_namespace(params).await?;
+    Ok(Json(namespace.into()))
+}
+
+/// Get all the reserved permission key namespaces.
+#[utoipa::path(get, path = "/v1/permission_key_namespaces",
+security(
+("bearerAuth" = []),
+), responses(
+(status = 200, description = "The reserved namespaces", body = Vec<PermissionKeyNamespace>),
+(status = 400, response = ErrorBodyResponse),
+),)]
+pub async fn get_permission_key_namespaces(
+    State(store): State<Arc<StoreNew>>,
+) -> Result<Json<Vec<PermissionKeyNamespace>>, RestError> {
+    Ok(Json(
+        store
+            .store
+            .get_permission_key_namespaces()
+            .await
+            .into_iter()
+            .map(|namespace| namespace.into())
+            .collect(),
+    ))
+}
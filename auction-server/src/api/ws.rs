@@ -3,23 +3,40 @@ use {
     crate::{
         auction::{
             api::{
+                get_bid_statuses,
                 process_bid,
                 BidCreate,
                 BidResult,
                 BidStatusWithId,
+                RelayerStatusUpdate,
                 SvmChainUpdate,
             },
             entities::BidId,
         },
         config::ChainId,
+        kernel::entities::PermissionKeySvm,
         opportunity::{
             api::{
+                get_quote_input,
                 Opportunity,
                 OpportunityBidEvm,
                 OpportunityDelete,
                 OpportunityId,
+                ProgramSvm,
+                Quote,
+                QuoteCreate,
+                QuoteRequestResult,
+                QuoteUpdate,
+            },
+            entities::{
+                self,
+                QuoteRequest,
+            },
+            service::{
+                accept_quote::AcceptQuoteInput,
+                cancel_quote::CancelQuoteInput,
+                handle_opportunity_bid::HandleOpportunityBidInput,
             },
-            service::handle_opportunity_bid::HandleOpportunityBidInput,
         },
         server::{
             EXIT_CHECK_INTERVAL,
@@ -42,6 +59,11 @@ use {
         },
         response::IntoResponse,
     },
+    ethers::types::{
+        Address,
+        Bytes,
+        U256,
+    },
     futures::{
         stream::{
             SplitSink,
@@ -54,8 +76,18 @@ use {
         Deserialize,
         Serialize,
     },
+    serde_with::{
+        serde_as,
+        DisplayFromStr,
+    },
+    solana_sdk::pubkey::Pubkey,
     std::{
-        collections::HashSet,
+        cmp::Ordering,
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        str::FromStr,
         sync::{
             atomic::{
                 AtomicUsize,
@@ -72,6 +104,7 @@ use {
         Instrument,
     },
     utoipa::ToSchema,
+    uuid::Uuid,
 };
 
 pub struct WsState {
@@ -80,28 +113,111 @@ pub struct WsState {
     pub broadcast_receiver: broadcast::Receiver<UpdateEvent>,
 }
 
+/// Optional updates a subscriber has to opt in to, so that older clients keep receiving the same messages.
+#[derive(Deserialize, Clone, Copy, ToSchema, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionCapability {
+    /// Receive the `remove_opportunities` updates of the evm chains.
+    EvmOpportunityRemovals,
+}
+
+pub type SubscriptionId = Uuid;
+
+/// A subscription to the new opportunities of a chain which match all the given fields.
+#[serde_as]
+#[derive(Deserialize, Clone, ToSchema, Debug)]
+pub struct OpportunitySubscriptionFilter {
+    /// The chain id to receive the opportunities of.
+    #[schema(example = "solana", value_type = String)]
+    pub chain_id:       ChainId,
+    /// The program of the svm opportunities.
+    #[schema(example = "limo", value_type = Option<ProgramSvm>)]
+    #[serde(default)]
+    pub program:        Option<ProgramSvm>,
+    /// The router account of the svm opportunities.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub router:         Option<Pubkey>,
+    /// The permission key of the opportunities, in hex format for evm chains and base64 format for svm chains.
+    #[schema(example = "0xdeadbeefcafe", value_type = Option<String>)]
+    #[serde(default)]
+    pub permission_key: Option<String>,
+    /// The token addresses or mints of which at least one should be on either side of the opportunity.
+    #[schema(example = json!(["0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"]), value_type = Vec<String>)]
+    #[serde(default)]
+    pub tokens:         Vec<String>,
+    /// The minimum amount of one of the filter tokens in the opportunity, in the smallest unit of the token.
+    /// Can only be set together with the tokens.
+    #[schema(example = "1000", value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub min_amount:     Option<u128>,
+}
+
 #[derive(Deserialize, Clone, ToSchema)]
 #[serde(tag = "method", content = "params")]
 pub enum ClientMessage {
+    /// Receive all the new opportunities of the chains, or only the ones matching the filters.
+    /// The response contains the subscription id of each filter, in the same order as the filters.
     #[serde(rename = "subscribe")]
     Subscribe {
         #[schema(value_type = Vec<String>)]
-        chain_ids: Vec<ChainId>,
+        #[serde(default)]
+        chain_ids:    Vec<ChainId>,
+        #[serde(default)]
+        capabilities: Vec<SubscriptionCapability>,
+        #[serde(default)]
+        filters:      Vec<OpportunitySubscriptionFilter>,
     },
+    /// Stop receiving the updates of the chains or of the filter subscriptions.
     #[serde(rename = "unsubscribe")]
     Unsubscribe {
         #[schema(value_type = Vec<String>)]
-        chain_ids: Vec<ChainId>,
+        #[serde(default)]
+        chain_ids:        Vec<ChainId>,
+        #[schema(value_type = Vec<String>)]
+        #[serde(default)]
+        subscription_ids: Vec<SubscriptionId>,
     },
     #[serde(rename = "post_bid")]
     PostBid { bid: BidCreate },
 
+    /// Receive the status updates of existing bids, e.g. the bids posted before reconnecting.
+    /// The response contains the current statuses of the bids which are found and owned by the searcher.
+    #[serde(rename = "subscribe_bids")]
+    SubscribeBids {
+        #[schema(value_type = String)]
+        chain_id: ChainId,
+        #[schema(value_type = Vec<String>)]
+        bid_ids:  Vec<BidId>,
+    },
+
     #[serde(rename = "post_opportunity_bid")]
     PostOpportunityBid {
         #[schema(value_type = String)]
         opportunity_id:  OpportunityId,
         opportunity_bid: OpportunityBidEvm,
     },
+
+    /// Open a quote which receives the best searcher bid as `quote_update` messages until it is accepted or cancelled.
+    #[serde(rename = "request_quote")]
+    RequestQuote { quote: QuoteCreate },
+
+    /// Accept a bid of an open quote. The response contains the signed transaction of the bid.
+    #[serde(rename = "accept_quote")]
+    AcceptQuote {
+        #[schema(value_type = String)]
+        quote_id: OpportunityId,
+        #[schema(value_type = String)]
+        bid_id:   BidId,
+    },
+
+    #[serde(rename = "cancel_quote")]
+    CancelQuote {
+        #[schema(value_type = String)]
+        quote_id: OpportunityId,
+    },
 }
 
 #[derive(Deserialize, Clone, ToSchema)]
@@ -125,12 +241,26 @@ pub enum ServerUpdateResponse {
     RemoveOpportunities {
         opportunity_delete: OpportunityDelete,
     },
+    #[serde(rename = "relayer_status_update")]
+    RelayerStatusUpdate { update: RelayerStatusUpdate },
+    #[serde(rename = "quote_update")]
+    QuoteUpdate { update: QuoteUpdate },
+    #[serde(rename = "quote_expired")]
+    QuoteExpired {
+        #[schema(value_type = String)]
+        quote_id: OpportunityId,
+    },
 }
 
 #[derive(Serialize, Clone, ToSchema)]
 #[serde(untagged)]
 pub enum APIResponse {
     BidResult(BidResult),
+    BidStatuses(Vec<BidStatusWithId>),
+    QuoteRequestResult(QuoteRequestResult),
+    Quote(Quote),
+    #[schema(value_type = Vec<String>)]
+    SubscriptionIds(Vec<SubscriptionId>),
 }
 #[derive(Serialize, Clone, ToSchema)]
 #[serde(tag = "status", content = "result")]
@@ -169,14 +299,24 @@ async fn websocket_handler(stream: WebSocket, state: Arc<StoreNew>, auth: Auth)
 
 #[derive(Clone)]
 pub enum UpdateEvent {
-    NewOpportunity(Opportunity),
+    NewOpportunity(Opportunity, entities::OpportunitySubscriptionFields),
+    /// The opportunity is sent to the searchers which were left out during its exclusivity window.
+    OpportunityExclusivityEnded(Opportunity, entities::OpportunitySubscriptionFields),
     BidStatusUpdate(BidStatusWithId),
     SvmChainUpdate(SvmChainUpdate),
     RemoveOpportunities(OpportunityDelete),
+    RelayerStatusUpdate(RelayerStatusUpdate),
 }
 
 pub type SubscriberId = usize;
 
+/// A quote opened by the subscriber which is streamed until it is accepted, cancelled or expired.
+struct QuoteSession {
+    request:         QuoteRequest,
+    best_bid:        Option<(BidId, u64)>,
+    expiration_time: OffsetDateTime,
+}
+
 /// Subscriber is an actor that handles a single websocket connection.
 /// It listens to the store for updates and sends them to the client.
 pub struct Subscriber {
@@ -187,14 +327,22 @@ pub struct Subscriber {
     receiver:            SplitStream<WebSocket>,
     sender:              SplitSink<WebSocket, Message>,
     chain_ids:           HashSet<ChainId>,
+    capabilities:        HashSet<SubscriptionCapability>,
+    opportunity_filters: HashMap<SubscriptionId, entities::OpportunitySubscriptionFilter>,
     bid_ids:             HashSet<BidId>,
+    quote_sessions:      HashMap<OpportunityId, QuoteSession>,
     ping_interval:       tokio::time::Interval,
+    quote_interval:      tokio::time::Interval,
     exit_check_interval: tokio::time::Interval,
     responded_to_ping:   bool,
     auth:                Auth,
 }
 
 const PING_INTERVAL_DURATION: Duration = Duration::from_secs(30);
+/// How often the open quotes are checked for better bids.
+const QUOTE_UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a quote stays open if it is not accepted or cancelled.
+const QUOTE_SESSION_DURATION: Duration = Duration::from_secs(30);
 
 fn ok_response(id: String) -> ServerResultResponse {
     ServerResultResponse {
@@ -220,8 +368,12 @@ impl Subscriber {
             receiver,
             sender,
             chain_ids: HashSet::new(),
+            capabilities: HashSet::new(),
+            opportunity_filters: HashMap::new(),
             bid_ids: HashSet::new(),
+            quote_sessions: HashMap::new(),
             ping_interval: tokio::time::interval(PING_INTERVAL_DURATION),
+            quote_interval: tokio::time::interval(QUOTE_UPDATE_INTERVAL),
             exit_check_interval: tokio::time::interval(EXIT_CHECK_INTERVAL),
             responded_to_ping: true, // We start with true so we don't close the connection immediately
             auth,
@@ -235,6 +387,19 @@ impl Subscriber {
                 break;
             }
         }
+        // Nobody can accept the open quotes anymore
+        for (_, session) in self.quote_sessions.drain() {
+            if let Err(e) = self
+                .store
+                .opportunity_service_svm
+                .cancel_quote(CancelQuoteInput {
+                    quote_request: session.request,
+                })
+                .await
+            {
+                tracing::error!(subscriber = self.id, error = ?e, "Failed to cancel quote of closed subscriber");
+            }
+        }
     }
 
     async fn handle_next(&mut self) -> Result<()> {
@@ -251,6 +416,9 @@ impl Subscriber {
                     maybe_message_or_err.ok_or(anyhow!("Client channel is closed"))??
                 ).await
             },
+            _ = self.quote_interval.tick() => {
+                self.handle_quote_sessions().await
+            },
             _  = self.ping_interval.tick() => {
                 if let Auth::Authorized(token, _) = self.auth.clone() {
                     if self.store.store.get_profile_by_token(&token).await.is_err() {
@@ -275,11 +443,45 @@ impl Subscriber {
         }
     }
 
-    async fn handle_new_opportunity(&mut self, opportunity: Opportunity) -> Result<()> {
-        if !self.chain_ids.contains(opportunity.get_chain_id()) {
+    /// Whether the subscriber receives the updates of the chain, either for all or some of its opportunities.
+    fn is_subscribed_to_chain(&self, chain_id: &ChainId) -> bool {
+        self.chain_ids.contains(chain_id)
+            || self
+                .opportunity_filters
+                .values()
+                .any(|filter| filter.chain_id == *chain_id)
+    }
+
+    async fn handle_new_opportunity(
+        &mut self,
+        opportunity: Opportunity,
+        fields: entities::OpportunitySubscriptionFields,
+        is_exclusivity_ended: bool,
+    ) -> Result<()> {
+        if !self.chain_ids.contains(&fields.chain_id)
+            && !self
+                .opportunity_filters
+                .values()
+                .any(|filter| filter.matches(&fields))
+        {
             // Irrelevant update
             return Ok(());
         }
+        let profile_id = match &self.auth {
+            Auth::Authorized(_, profile) => Some(profile.id),
+            _ => None,
+        };
+        if let Some(exclusivity) = &fields.exclusivity {
+            if is_exclusivity_ended {
+                if exclusivity.is_allowlisted(profile_id) {
+                    // The opportunity was already sent during its exclusivity window
+                    return Ok(());
+                }
+            } else if !exclusivity.is_open_to(profile_id) {
+                // The opportunity is sent again when its exclusivity window ends
+                return Ok(());
+            }
+        }
         let message = serde_json::to_string(&ServerUpdateResponse::NewOpportunity { opportunity })?;
         self.sender.send(message.into()).await?;
         Ok(())
@@ -296,7 +498,7 @@ impl Subscriber {
     }
 
     async fn handle_svm_chain_update(&mut self, svm_chain_update: SvmChainUpdate) -> Result<()> {
-        if !self.chain_ids.contains(&svm_chain_update.chain_id) {
+        if !self.is_subscribed_to_chain(&svm_chain_update.chain_id) {
             // Irrelevant update
             return Ok(());
         }
@@ -311,10 +513,18 @@ impl Subscriber {
         &mut self,
         opportunity_delete: OpportunityDelete,
     ) -> Result<()> {
-        if !self.chain_ids.contains(opportunity_delete.get_chain_id()) {
+        if !self.is_subscribed_to_chain(opportunity_delete.get_chain_id()) {
             // Irrelevant update
             return Ok(());
         }
+        if matches!(opportunity_delete, OpportunityDelete::Evm(_))
+            && !self
+                .capabilities
+                .contains(&SubscriptionCapability::EvmOpportunityRemovals)
+        {
+            // Older clients do not expect removals on evm chains
+            return Ok(());
+        }
         let message = serde_json::to_string(&ServerUpdateResponse::RemoveOpportunities {
             opportunity_delete,
         })?;
@@ -322,6 +532,99 @@ impl Subscriber {
         Ok(())
     }
 
+    async fn handle_relayer_status_update(
+        &mut self,
+        relayer_status_update: RelayerStatusUpdate,
+    ) -> Result<()> {
+        if !self.is_subscribed_to_chain(&relayer_status_update.chain_id) {
+            // Irrelevant update
+            return Ok(());
+        }
+        let message = serde_json::to_string(&ServerUpdateResponse::RelayerStatusUpdate {
+            update: relayer_status_update,
+        })?;
+        self.sender.send(message.into()).await?;
+        Ok(())
+    }
+
+    async fn send_update(&mut self, update: ServerUpdateResponse) -> Result<()> {
+        let message = serde_json::to_string(&update)?;
+        self.sender.send(message.into()).await?;
+        Ok(())
+    }
+
+    /// Sends the best bid of the open quotes whenever it improves and closes the expired quotes.
+    async fn handle_quote_sessions(&mut self) -> Result<()> {
+        if self.quote_sessions.is_empty() {
+            return Ok(());
+        }
+        let now = OffsetDateTime::now_utc();
+        let expired_ids: Vec<OpportunityId> = self
+            .quote_sessions
+            .iter()
+            .filter(|(_, session)| session.expiration_time <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        for quote_id in expired_ids {
+            if let Some(session) = self.quote_sessions.remove(&quote_id) {
+                if let Err(e) = self
+                    .store
+                    .opportunity_service_svm
+                    .cancel_quote(CancelQuoteInput {
+                        quote_request: session.request,
+                    })
+                    .await
+                {
+                    tracing::error!(subscriber = self.id, error = ?e, "Failed to cancel expired quote");
+                }
+                self.send_update(ServerUpdateResponse::QuoteExpired { quote_id })
+                    .await?;
+            }
+        }
+
+        let mut updates = vec![];
+        for (quote_id, session) in self.quote_sessions.iter_mut() {
+            let best_bid = match self
+                .store
+                .opportunity_service_svm
+                .get_quote_bids(&session.request)
+                .await
+            {
+                Ok(bids) => bids.first().map(|bid| (bid.id, bid.amount)),
+                Err(e) => {
+                    tracing::error!(subscriber = self.id, error = ?e, "Failed to get quote bids");
+                    continue;
+                }
+            };
+            if let Some((bid_id, bid_amount)) = best_bid {
+                let improved = match session.best_bid {
+                    Some((_, amount)) => {
+                        session
+                            .request
+                            .quote_create
+                            .tokens
+                            .compare_bid_amounts(bid_amount, amount)
+                            == Ordering::Less
+                    }
+                    None => true,
+                };
+                if improved {
+                    session.best_bid = best_bid;
+                    updates.push(QuoteUpdate {
+                        quote_id: *quote_id,
+                        bid_id,
+                        bid_amount,
+                    });
+                }
+            }
+        }
+        for update in updates {
+            self.send_update(ServerUpdateResponse::QuoteUpdate { update })
+                .await?;
+        }
+        Ok(())
+    }
+
     #[instrument(
         target = "metrics",
         fields(category = "ws_update", result = "success", name),
@@ -329,9 +632,14 @@ impl Subscriber {
     )]
     async fn handle_update(&mut self, event: UpdateEvent) -> Result<()> {
         let result = match event.clone() {
-            UpdateEvent::NewOpportunity(opportunity) => {
+            UpdateEvent::NewOpportunity(opportunity, fields) => {
                 tracing::Span::current().record("name", "new_opportunity");
-                self.handle_new_opportunity(opportunity).await
+                self.handle_new_opportunity(opportunity, fields, false)
+                    .await
+            }
+            UpdateEvent::OpportunityExclusivityEnded(opportunity, fields) => {
+                tracing::Span::current().record("name", "opportunity_exclusivity_ended");
+                self.handle_new_opportunity(opportunity, fields, true).await
             }
             UpdateEvent::BidStatusUpdate(status) => {
                 tracing::Span::current().record("name", "bid_status_update");
@@ -345,6 +653,11 @@ impl Subscriber {
                 tracing::Span::current().record("name", "remove_opportunity");
                 self.handle_remove_opportunities(opportunity_delete).await
             }
+            UpdateEvent::RelayerStatusUpdate(relayer_status_update) => {
+                tracing::Span::current().record("name", "relayer_status_update");
+                self.handle_relayer_status_update(relayer_status_update)
+                    .await
+            }
         };
         if result.is_err() {
             tracing::Span::current().record("result", "error");
@@ -352,10 +665,69 @@ impl Subscriber {
         result
     }
 
+    /// Parses the tokens and the permission key of the filter in the format of its chain.
+    fn get_opportunity_filter(
+        &self,
+        filter: OpportunitySubscriptionFilter,
+    ) -> Result<entities::OpportunitySubscriptionFilter, String> {
+        if filter.min_amount.is_some() && filter.tokens.is_empty() {
+            return Err("Minimum amount can only be set together with the tokens".to_string());
+        }
+        let (permission_key, tokens) = if self.store.store.chains_evm.contains_key(&filter.chain_id)
+        {
+            let permission_key = filter
+                .permission_key
+                .map(|permission_key| {
+                    Bytes::from_str(&permission_key)
+                        .map_err(|_| format!("Invalid permission key {}", permission_key))
+                })
+                .transpose()?;
+            let tokens = filter
+                .tokens
+                .iter()
+                .map(|token| {
+                    Address::from_str(token)
+                        .map(|address| format!("{:?}", address))
+                        .map_err(|_| format!("Invalid token address {}", token))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (permission_key, tokens)
+        } else {
+            let permission_key = filter
+                .permission_key
+                .map(|permission_key| {
+                    PermissionKeySvm::from_str(&permission_key)
+                        .map(Into::into)
+                        .map_err(|_| format!("Invalid permission key {}", permission_key))
+                })
+                .transpose()?;
+            let tokens = filter
+                .tokens
+                .iter()
+                .map(|token| {
+                    Pubkey::from_str(token)
+                        .map(|mint| mint.to_string())
+                        .map_err(|_| format!("Invalid token mint {}", token))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (permission_key, tokens)
+        };
+        Ok(entities::OpportunitySubscriptionFilter {
+            chain_id: filter.chain_id,
+            program: filter.program,
+            router: filter.router,
+            permission_key,
+            tokens,
+            min_amount: filter.min_amount.map(U256::from),
+        })
+    }
+
     async fn handle_subscribe(
         &mut self,
         id: String,
         chain_ids: Vec<String>,
+        capabilities: Vec<SubscriptionCapability>,
+        filters: Vec<OpportunitySubscriptionFilter>,
     ) -> Result<ServerResultResponse, ServerResultResponse> {
         tracing::Span::current().record("name", "handle_subscribe");
         let available_chain_ids: Vec<&ChainId> = self
@@ -367,20 +739,47 @@ impl Subscriber {
             .collect();
         let not_found_chain_ids: Vec<&ChainId> = chain_ids
             .iter()
+            .chain(filters.iter().map(|filter| &filter.chain_id))
             .filter(|chain_id| !available_chain_ids.contains(chain_id))
             .collect();
         // If there is a single chain id that is not found, we don't subscribe to any of the
         // asked correct chain ids and return an error to be more explicit and clear.
         if !not_found_chain_ids.is_empty() {
-            Err(ServerResultResponse {
+            return Err(ServerResultResponse {
                 id:     Some(id),
                 result: ServerResultMessage::Err(format!(
                     "Chain id(s) with id(s) {:?} not found",
                     not_found_chain_ids
                 )),
+            });
+        }
+        let has_filters = !filters.is_empty();
+        let filters = filters
+            .into_iter()
+            .map(|filter| self.get_opportunity_filter(filter))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ServerResultResponse {
+                id:     Some(id.clone()),
+                result: ServerResultMessage::Err(e),
+            })?;
+        let subscription_ids: Vec<SubscriptionId> = filters
+            .into_iter()
+            .map(|filter| {
+                let subscription_id = Uuid::new_v4();
+                self.opportunity_filters.insert(subscription_id, filter);
+                subscription_id
+            })
+            .collect();
+        self.chain_ids.extend(chain_ids);
+        self.capabilities.extend(capabilities);
+        if has_filters {
+            Ok(ServerResultResponse {
+                id:     Some(id),
+                result: ServerResultMessage::Success(Some(APIResponse::SubscriptionIds(
+                    subscription_ids,
+                ))),
             })
         } else {
-            self.chain_ids.extend(chain_ids);
             Ok(ok_response(id))
         }
     }
@@ -389,10 +788,13 @@ impl Subscriber {
         &mut self,
         id: String,
         chain_ids: Vec<String>,
+        subscription_ids: Vec<SubscriptionId>,
     ) -> Result<ServerResultResponse, ServerResultResponse> {
         tracing::Span::current().record("name", "unsubscribe");
         self.chain_ids
             .retain(|chain_id| !chain_ids.contains(chain_id));
+        self.opportunity_filters
+            .retain(|subscription_id, _| !subscription_ids.contains(subscription_id));
         Ok(ok_response(id))
     }
 
@@ -419,6 +821,43 @@ impl Subscriber {
         }
     }
 
+    async fn handle_subscribe_bids(
+        &mut self,
+        id: String,
+        chain_id: ChainId,
+        bid_ids: Vec<BidId>,
+    ) -> Result<ServerResultResponse, ServerResultResponse> {
+        // The bids are tracked before their statuses are read, so no update is missed in between
+        let new_bid_ids: Vec<BidId> = bid_ids
+            .iter()
+            .filter(|bid_id| self.bid_ids.insert(**bid_id))
+            .cloned()
+            .collect();
+        match get_bid_statuses(&self.auth, &self.store, &chain_id, bid_ids).await {
+            Ok(statuses) => {
+                // The bids which are not found or are not owned by the subscriber are not tracked
+                for bid_id in new_bid_ids {
+                    if !statuses.iter().any(|status| status.id == bid_id) {
+                        self.bid_ids.remove(&bid_id);
+                    }
+                }
+                Ok(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Success(Some(APIResponse::BidStatuses(statuses))),
+                })
+            }
+            Err(e) => {
+                for bid_id in new_bid_ids {
+                    self.bid_ids.remove(&bid_id);
+                }
+                Err(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Err(e.to_status_and_message().1),
+                })
+            }
+        }
+    }
+
     #[instrument(skip_all)]
     async fn handle_post_opportunity_bid(
         &mut self,
@@ -455,6 +894,121 @@ impl Subscriber {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn handle_request_quote(
+        &mut self,
+        id: String,
+        quote: QuoteCreate,
+    ) -> Result<ServerResultResponse, ServerResultResponse> {
+        let result = async {
+            let input = get_quote_input(&self.store, &self.auth, quote).await?;
+            self.store
+                .opportunity_service_svm
+                .create_quote_request(input)
+                .await
+        }
+        .await;
+        match result {
+            Ok(request) => {
+                let quote_id = request.opportunity.id;
+                let response = QuoteRequestResult {
+                    quote_id,
+                    input_token: request.price_estimate.input_token.clone().into(),
+                    output_token: request.price_estimate.output_token.clone().into(),
+                };
+                self.quote_sessions.insert(
+                    quote_id,
+                    QuoteSession {
+                        request,
+                        best_bid: None,
+                        expiration_time: OffsetDateTime::now_utc() + QUOTE_SESSION_DURATION,
+                    },
+                );
+                Ok(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Success(Some(APIResponse::QuoteRequestResult(
+                        response,
+                    ))),
+                })
+            }
+            Err(e) => Err(ServerResultResponse {
+                id:     Some(id),
+                result: ServerResultMessage::Err(e.to_status_and_message().1),
+            }),
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_accept_quote(
+        &mut self,
+        id: String,
+        quote_id: OpportunityId,
+        bid_id: BidId,
+    ) -> Result<ServerResultResponse, ServerResultResponse> {
+        let session = match self.quote_sessions.remove(&quote_id) {
+            Some(session) => session,
+            None => {
+                return Err(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Err("Quote not found".to_string()),
+                })
+            }
+        };
+        match self
+            .store
+            .opportunity_service_svm
+            .accept_quote(AcceptQuoteInput {
+                quote_request: session.request.clone(),
+                bid_id,
+            })
+            .await
+        {
+            Ok(quote) => Ok(ServerResultResponse {
+                id:     Some(id),
+                result: ServerResultMessage::Success(Some(APIResponse::Quote(quote.into()))),
+            }),
+            Err(e) => {
+                // The quote stays open so that another bid can be accepted
+                self.quote_sessions.insert(quote_id, session);
+                Err(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Err(e.to_status_and_message().1),
+                })
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_cancel_quote(
+        &mut self,
+        id: String,
+        quote_id: OpportunityId,
+    ) -> Result<ServerResultResponse, ServerResultResponse> {
+        let session = match self.quote_sessions.remove(&quote_id) {
+            Some(session) => session,
+            None => {
+                return Err(ServerResultResponse {
+                    id:     Some(id),
+                    result: ServerResultMessage::Err("Quote not found".to_string()),
+                })
+            }
+        };
+        match self
+            .store
+            .opportunity_service_svm
+            .cancel_quote(CancelQuoteInput {
+                quote_request: session.request,
+            })
+            .await
+        {
+            Ok(_) => Ok(ok_response(id)),
+            Err(e) => Err(ServerResultResponse {
+                id:     Some(id),
+                result: ServerResultMessage::Err(e.to_status_and_message().1),
+            }),
+        }
+    }
+
     #[instrument(
         target = "metrics",
         fields(category = "ws_client_message", result = "success", name),
@@ -498,18 +1052,31 @@ impl Subscriber {
                 result: ServerResultMessage::Err(e.to_string()),
             }),
             Ok(ClientRequest { msg, id }) => match msg {
-                ClientMessage::Subscribe { chain_ids } => {
+                ClientMessage::Subscribe {
+                    chain_ids,
+                    capabilities,
+                    filters,
+                } => {
                     tracing::Span::current().record("name", "subscribe");
-                    self.handle_subscribe(id, chain_ids).await
+                    self.handle_subscribe(id, chain_ids, capabilities, filters)
+                        .await
                 }
-                ClientMessage::Unsubscribe { chain_ids } => {
+                ClientMessage::Unsubscribe {
+                    chain_ids,
+                    subscription_ids,
+                } => {
                     tracing::Span::current().record("name", "unsubscribe");
-                    self.handle_unsubscribe(id, chain_ids).await
+                    self.handle_unsubscribe(id, chain_ids, subscription_ids)
+                        .await
                 }
                 ClientMessage::PostBid { bid } => {
                     tracing::Span::current().record("name", "post_bid");
                     self.handle_post_bid(id, bid).await
                 }
+                ClientMessage::SubscribeBids { chain_id, bid_ids } => {
+                    tracing::Span::current().record("name", "subscribe_bids");
+                    self.handle_subscribe_bids(id, chain_id, bid_ids).await
+                }
                 ClientMessage::PostOpportunityBid {
                     opportunity_bid,
                     opportunity_id,
@@ -519,6 +1086,22 @@ impl Subscriber {
                         .in_current_span()
                         .await
                 }
+                ClientMessage::RequestQuote { quote } => {
+                    tracing::Span::current().record("name", "request_quote");
+                    self.handle_request_quote(id, quote).in_current_span().await
+                }
+                ClientMessage::AcceptQuote { quote_id, bid_id } => {
+                    tracing::Span::current().record("name", "accept_quote");
+                    self.handle_accept_quote(id, quote_id, bid_id)
+                        .in_current_span()
+                        .await
+                }
+                ClientMessage::CancelQuote { quote_id } => {
+                    tracing::Span::current().record("name", "cancel_quote");
+                    self.handle_cancel_quote(id, quote_id)
+                        .in_current_span()
+                        .await
+                }
             },
         };
 
@@ -4,11 +4,20 @@ use {
             self,
             BidChainData,
         },
-        repository::MicroLamports,
+        repository::{
+            MicroLamports,
+            AUCTION_PAGE_SIZE_CAP,
+            BID_PAGE_SIZE_CAP,
+        },
         service::{
+            get_auction::GetAuctionInput,
+            get_auction_summaries::GetAuctionSummariesInput,
+            get_auctions::GetAuctionsInput,
             get_bid::GetBidInput,
             get_bids::GetBidsInput,
+            get_bids_by_ids::GetBidsByIdsInput,
             handle_bid::HandleBidInput,
+            update_chain_status::UpdateChainStatusInput,
             verification::Verification,
             ChainTrait,
             Service,
@@ -16,7 +25,9 @@ use {
         },
     },
     crate::{
+        admin_only,
         api::{
+            admin_middleware,
             require_login_middleware,
             Auth,
             ErrorBodyResponse,
@@ -44,10 +55,15 @@ use {
         routing::{
             get,
             post,
+            put,
         },
         Json,
         Router,
     },
+    base64::{
+        engine::general_purpose::URL_SAFE_NO_PAD,
+        Engine,
+    },
     ethers::types::{
         Address,
         Bytes,
@@ -69,7 +85,12 @@ use {
     },
     sqlx::types::time::OffsetDateTime,
     std::{
-        fmt::Debug,
+        fmt::{
+            Debug,
+            Display,
+            Formatter,
+        },
+        str::FromStr,
         sync::Arc,
     },
     utoipa::{
@@ -95,6 +116,51 @@ pub struct SvmChainUpdate {
     pub latest_prioritization_fee: MicroLamports,
 }
 
+/// The relayer balance status of a chain, sent to the searchers whenever it changes.
+#[derive(Serialize, Clone, ToSchema, ToResponse)]
+pub struct RelayerStatusUpdate {
+    #[schema(example = "solana", value_type = String)]
+    pub chain_id: ChainId,
+    pub status:   RelayerStatus,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayerStatus {
+    /// The relayer has enough funds to submit the auctions.
+    Healthy,
+    /// The relayer is running low on funds, auctions are still submitted.
+    Low,
+    /// The relayer is out of funds, auctions are not submitted and new bids are rejected.
+    Critical,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainStatus {
+    /// The chain is fully operational.
+    Active,
+    /// New bids and opportunities are refused, but the existing auctions are submitted and concluded.
+    Draining,
+    /// New bids and opportunities are refused and no auction is submitted or concluded.
+    Paused,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug)]
+pub struct ChainStatusUpdate {
+    /// The new status of the chain.
+    pub status: ChainStatus,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, ToResponse, Clone, Debug)]
+pub struct ChainStatusWithId {
+    /// The chain id.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id: ChainId,
+    /// The current status of the chain.
+    pub status:   ChainStatus,
+}
+
 pub type BidId = Uuid;
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
@@ -132,6 +198,21 @@ pub enum BidStatusEvm {
         #[schema(example = 1, value_type = u32)]
         index:  u32,
     },
+    /// The transaction with the given hash is included in the given block, but the block is not deep enough to be final yet.
+    /// This state is provisional and will be updated to either lost or won once the block has enough confirmations,
+    /// or back to submitted if the block is reorged out.
+    #[schema(title = "Included")]
+    Included {
+        #[schema(example = "0x103d4fbd777a36311b5161f2062490f761f25b67406badb2bace62bb170aa4e3", value_type = String)]
+        result:       H256,
+        #[schema(example = 1, value_type = u32)]
+        index:        u32,
+        /// Whether the bid is executed successfully in the included transaction.
+        #[schema(example = true)]
+        won:          bool,
+        #[schema(example = 1_000_000, value_type = u64)]
+        block_number: u64,
+    },
 }
 
 #[serde_as]
@@ -214,6 +295,11 @@ pub struct BidCoreFields {
     /// The profile id for the bid owner.
     #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
     pub profile_id:      Option<models::ProfileId>,
+    /// The time the bid result was settled at the finalized commitment level formatted in rfc3339.
+    /// Only set for svm chains with the settlement check enabled.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub finalized_at:    Option<OffsetDateTime>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize, Deserialize)]
@@ -363,6 +449,69 @@ pub async fn process_bid(
     }
 }
 
+/// The maximum number of bids whose status can be queried at once.
+pub const BID_STATUS_BATCH_SIZE_CAP: usize = 100;
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug)]
+pub struct BidStatusesQuery {
+    /// The ids of the bids to query, at most 100.
+    #[schema(example = json!(["obo3ee3e-58cc-4372-a567-0e02b2c3d479"]), value_type = Vec<String>)]
+    pub bid_ids: Vec<BidId>,
+}
+
+/// Get the statuses of bids on a chain, skipping the bids which are not found or not owned by the caller.
+pub async fn get_bid_statuses(
+    auth: &Auth,
+    store: &StoreNew,
+    chain_id: &ChainId,
+    bid_ids: Vec<BidId>,
+) -> Result<Vec<BidStatusWithId>, RestError> {
+    if bid_ids.len() > BID_STATUS_BATCH_SIZE_CAP {
+        return Err(RestError::BadParameters(format!(
+            "At most {} bid ids can be queried at once",
+            BID_STATUS_BATCH_SIZE_CAP
+        )));
+    }
+    let profile_id = match auth {
+        Auth::Admin => None,
+        Auth::Authorized(_, profile) => Some(profile.id),
+        Auth::Unauthorized => return Err(RestError::Unauthorized),
+    };
+    let input = GetBidsByIdsInput {
+        bid_ids,
+        profile_id,
+    };
+    match store.get_auction_service(chain_id)? {
+        ServiceEnum::Evm(service) => Evm::get_bid_statuses(&service, input).await,
+        ServiceEnum::Svm(service) => Svm::get_bid_statuses(&service, input).await,
+    }
+}
+
+/// Query the statuses of multiple bids on a chain.
+///
+/// The bids which are not found or are not owned by the caller are not returned.
+#[utoipa::path(post, path = "/v1/{chain_id}/bids/status", request_body = BidStatusesQuery,
+    security(
+        ("bearerAuth" = []),
+    ),
+    responses(
+    (status = 200, description = "The statuses of the found bids", body = Vec<BidStatusWithId>),
+    (status = 400, response = ErrorBodyResponse),
+    (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
+),
+    params(("chain_id"=String, Path, description = "The chain id to query for", example = "op_sepolia")),
+)]
+pub async fn post_bid_statuses(
+    auth: Auth,
+    State(store): State<Arc<StoreNew>>,
+    Path(chain_id): Path<ChainId>,
+    Json(query): Json<BidStatusesQuery>,
+) -> Result<Json<Vec<BidStatusWithId>>, RestError> {
+    Ok(Json(
+        get_bid_statuses(&auth, &store, &chain_id, query.bid_ids).await?,
+    ))
+}
+
 #[derive(Serialize, Deserialize, IntoParams, Clone)]
 pub struct GetBidStatusParams {
     #[param(example="op_sepolia", value_type = String)]
@@ -421,9 +570,30 @@ pub async fn get_bid_status_deprecated(
     Err(RestError::BidNotFound)
 }
 
+/// The auction a bid took part in.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug)]
+pub struct BidAuction {
+    /// The id of the auction.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:        entities::AuctionId,
+    /// The hash or signature of the transaction submitted for the auction, if any.
+    #[schema(example = "0x103d4fbd777a36311b5161f2062490f761f25b67406badb2bace62bb170aa4e3", value_type = Option<String>)]
+    pub tx_hash:   Option<String>,
+    /// The number of bids competing in the auction, including the searcher's own bids.
+    #[schema(example = 3)]
+    pub bid_count: u64,
+}
+
 #[derive(Serialize, Deserialize, ToResponse, ToSchema, Clone)]
 pub struct Bids {
-    pub items: Vec<Bid>,
+    pub items:       Vec<Bid>,
+    /// The cursor to pass to get the next page of bids. Only set if the page is full.
+    #[schema(example = "MTcxNjQ5OTYxNzMyOTk1NDpiZWVkYmVlZC01OGNjLTQzNzItYTU2Ny0wZTAyYjJjM2Q0Nzk", value_type = Option<String>)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// The auctions of the returned bids. Only set if the auction expansion is requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auctions:    Option<Vec<BidAuction>>,
 }
 
 #[derive(Serialize, Deserialize, IntoParams)]
@@ -433,8 +603,132 @@ pub struct GetBidsByTimeQueryParams {
     pub from_time: Option<OffsetDateTime>,
 }
 
-/// Returns at most 20 bids which were submitted after a specific time and chain.
-/// If no time is provided, the server will return the first bids.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BidStatusKind {
+    Pending,
+    Submitted,
+    Lost,
+    Won,
+    Failed,
+    Expired,
+}
+
+/// The related objects which can be returned along with the bids.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BidExpansion {
+    /// The auction of each bid, with its transaction hash and the number of competing bids.
+    Auction,
+}
+
+/// An opaque cursor to continue listing the bids after a returned page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BidCursor(pub entities::BidCursor);
+
+/// The cursors are the time and id of the last returned item, with the time in microseconds.
+fn encode_cursor(time: OffsetDateTime, id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}:{}", time.unix_timestamp_nanos() / 1000, id))
+}
+
+fn decode_cursor(s: &str) -> Result<(OffsetDateTime, Uuid), RestError> {
+    let invalid_cursor = || RestError::BadParameters("Invalid cursor".to_string());
+    let cursor = URL_SAFE_NO_PAD
+        .decode(s)
+        .ok()
+        .and_then(|cursor| String::from_utf8(cursor).ok())
+        .ok_or_else(invalid_cursor)?;
+    let (time, id) = cursor.split_once(':').ok_or_else(invalid_cursor)?;
+    let time: i128 = time.parse().map_err(|_| invalid_cursor())?;
+    Ok((
+        OffsetDateTime::from_unix_timestamp_nanos(time * 1000).map_err(|_| invalid_cursor())?,
+        id.parse().map_err(|_| invalid_cursor())?,
+    ))
+}
+
+impl Display for BidCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", encode_cursor(self.0.initiation_time, self.0.id))
+    }
+}
+
+impl FromStr for BidCursor {
+    type Err = RestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (initiation_time, id) = decode_cursor(s)?;
+        Ok(BidCursor(entities::BidCursor {
+            initiation_time,
+            id,
+        }))
+    }
+}
+
+/// An opaque cursor to continue listing the auctions after a returned page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AuctionCursor(pub entities::AuctionCursor);
+
+impl Display for AuctionCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", encode_cursor(self.0.creation_time, self.0.id))
+    }
+}
+
+impl FromStr for AuctionCursor {
+    type Err = RestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (creation_time, id) = decode_cursor(s)?;
+        Ok(AuctionCursor(entities::AuctionCursor { creation_time, id }))
+    }
+}
+
+fn default_bids_limit() -> usize {
+    20
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, IntoParams)]
+pub struct GetBidsQueryParams {
+    /// The status of the bids to return.
+    #[param(example = "won", value_type = Option<BidStatusKind>)]
+    #[serde(default)]
+    pub status:         Option<BidStatusKind>,
+    /// The permission key of the bids to return, in hex format for evm chains and base64 format for svm chains.
+    #[param(example = "0xdeadbeef", value_type = Option<String>)]
+    #[serde(default)]
+    pub permission_key: Option<String>,
+    /// The auction of the bids to return.
+    #[param(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = Option<String>)]
+    #[serde(default)]
+    pub auction_id:     Option<entities::AuctionId>,
+    /// Only return the bids submitted at or after this time.
+    #[param(example="2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(default, with = "crate::serde::nullable_datetime")]
+    pub from_time:      Option<OffsetDateTime>,
+    /// Only return the bids submitted before this time.
+    #[param(example="2024-05-24T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(default, with = "crate::serde::nullable_datetime")]
+    pub to_time:        Option<OffsetDateTime>,
+    /// The cursor returned with the previous page of bids.
+    #[param(value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cursor:         Option<BidCursor>,
+    /// The maximum number of bids to return. Capped at 100.
+    #[param(example = "20", value_type = usize, maximum = 100)]
+    #[serde(default = "default_bids_limit")]
+    pub limit:          usize,
+    /// The related objects to return along with the bids.
+    #[param(example = "auction", value_type = Option<BidExpansion>)]
+    #[serde(default)]
+    pub include:        Option<BidExpansion>,
+}
+
+/// Returns the bids of the searcher on a specific chain, sorted by initiation time and id.
+///
+/// The bids can be filtered by status, permission key, auction and time range.
+/// If the page is full, the cursor of the next page is returned with the bids.
 #[utoipa::path(get, path = "/v1/{chain_id}/bids",
     security(
         ("bearerAuth" = []),
@@ -444,23 +738,19 @@ pub struct GetBidsByTimeQueryParams {
     (status = 400, response = ErrorBodyResponse),
 ),  params(
         ("chain_id"=String, Path, description = "The chain id to query for", example = "op_sepolia"),
-        GetBidsByTimeQueryParams
+        GetBidsQueryParams
     ),
 )]
 pub async fn get_bids_by_time(
     auth: Auth,
     State(store): State<Arc<StoreNew>>,
     Path(chain_id): Path<ChainId>,
-    query: Query<GetBidsByTimeQueryParams>,
+    query: Query<GetBidsQueryParams>,
 ) -> Result<Json<Bids>, RestError> {
     match auth {
         Auth::Authorized(_, profile) => match store.get_auction_service(&chain_id)? {
-            ServiceEnum::Evm(service) => {
-                Evm::get_bids_by_time(&service, profile, query.from_time).await
-            }
-            ServiceEnum::Svm(service) => {
-                Svm::get_bids_by_time(&service, profile, query.from_time).await
-            }
+            ServiceEnum::Evm(service) => Evm::get_bids(&service, profile, &query).await,
+            ServiceEnum::Svm(service) => Svm::get_bids(&service, profile, &query).await,
         },
         _ => {
             tracing::error!("Unauthorized access to get_bids_by_time");
@@ -504,7 +794,11 @@ pub async fn get_bids_by_time_deprecated(
             }
             bids.sort_by_key(|a| a.get_initiation_time());
             bids.truncate(20);
-            Ok(Json(Bids { items: bids }))
+            Ok(Json(Bids {
+                items:       bids,
+                next_cursor: None,
+                auctions:    None,
+            }))
         }
         _ => {
             tracing::error!("Unauthorized access to get_bids_by_time");
@@ -513,6 +807,226 @@ pub async fn get_bids_by_time_deprecated(
     }
 }
 
+/// A bid of an auction as seen by the caller.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug)]
+pub struct AuctionBid {
+    /// The unique id for bid.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:              BidId,
+    /// The time server received the bid formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = String)]
+    #[serde(with = "time::serde::rfc3339")]
+    pub initiation_time: OffsetDateTime,
+    /// The profile id for the bid owner. Only shown to the bid owner and the admins.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = Option<String>)]
+    pub profile_id:      Option<models::ProfileId>,
+    /// The amount of the bid in wei or lamports. Only shown to the bid owner and the admins.
+    #[schema(example = "1000", value_type = Option<String>)]
+    pub amount:          Option<String>,
+    /// The latest status for bid.
+    pub status:          BidStatus,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, ToResponse, Clone, Debug)]
+pub struct Auction {
+    /// The unique id for auction.
+    #[schema(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:                  entities::AuctionId,
+    /// The chain id for auction.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:            ChainId,
+    /// The permission key of the auction, in hex format for evm chains and base64 format for svm chains.
+    #[schema(example = "0xdeadbeef", value_type = String)]
+    pub permission_key:      String,
+    /// The time the auction was created formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = String)]
+    #[serde(with = "time::serde::rfc3339")]
+    pub creation_time:       OffsetDateTime,
+    /// The time the bids of the auction were collected formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = String)]
+    #[serde(with = "time::serde::rfc3339")]
+    pub bid_collection_time: OffsetDateTime,
+    /// The time the auction was submitted to the chain formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub submission_time:     Option<OffsetDateTime>,
+    /// The time the result of the auction was known formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub conclusion_time:     Option<OffsetDateTime>,
+    /// The hash or signature of the transaction submitted for the auction, if any.
+    #[schema(example = "0x103d4fbd777a36311b5161f2062490f761f25b67406badb2bace62bb170aa4e3", value_type = Option<String>)]
+    pub tx_hash:             Option<String>,
+    /// The bids of the auction sorted by initiation time.
+    pub bids:                Vec<AuctionBid>,
+    /// The ids of the bids selected to be submitted for the auction.
+    #[schema(example = json!(["obo3ee3e-58cc-4372-a567-0e02b2c3d479"]), value_type = Vec<String>)]
+    pub winner_bid_ids:      Vec<BidId>,
+}
+
+#[derive(Serialize, Deserialize, ToResponse, ToSchema, Clone)]
+pub struct Auctions {
+    pub items:       Vec<Auction>,
+    /// The cursor to pass to get the next page of auctions. Only set if the page is full.
+    #[schema(example = "MTcxNjQ5OTYxNzMyOTk1NDpiZWVkYmVlZC01OGNjLTQzNzItYTU2Ny0wZTAyYjJjM2Q0Nzk", value_type = Option<String>)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, IntoParams, Clone)]
+pub struct GetAuctionParams {
+    #[param(example="op_sepolia", value_type = String)]
+    pub chain_id:   ChainId,
+    #[param(example="obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub auction_id: entities::AuctionId,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, IntoParams)]
+pub struct GetAuctionsQueryParams {
+    /// The permission key of the auctions to return, in hex format for evm chains and base64 format for svm chains.
+    #[param(example = "0xdeadbeef", value_type = Option<String>)]
+    #[serde(default)]
+    pub permission_key: Option<String>,
+    /// Only return the auctions created at or after this time.
+    #[param(example="2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(default, with = "crate::serde::nullable_datetime")]
+    pub from_time:      Option<OffsetDateTime>,
+    /// Only return the auctions created before this time.
+    #[param(example="2024-05-24T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(default, with = "crate::serde::nullable_datetime")]
+    pub to_time:        Option<OffsetDateTime>,
+    /// The cursor returned with the previous page of auctions.
+    #[param(value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cursor:         Option<AuctionCursor>,
+    /// The maximum number of auctions to return. Capped at 100.
+    #[param(example = "20", value_type = usize, maximum = 100)]
+    #[serde(default = "default_bids_limit")]
+    pub limit:          usize,
+}
+
+/// Query an auction with its timeline, bids and result.
+///
+/// The amounts and owners of the bids are only shown to the bid owners and the admins.
+#[utoipa::path(get, path = "/v1/{chain_id}/auctions/{auction_id}",
+    security(
+        ("bearerAuth" = []),
+    ),
+    responses(
+    (status = 200, body = Auction),
+    (status = 400, response = ErrorBodyResponse),
+    (status = 404, description = "Auction was not found", body = ErrorBodyResponse),
+),
+    params(GetAuctionParams),
+)]
+pub async fn get_auction(
+    auth: Auth,
+    State(store): State<Arc<StoreNew>>,
+    Path(params): Path<GetAuctionParams>,
+) -> Result<Json<Auction>, RestError> {
+    if let Auth::Unauthorized = auth {
+        return Err(RestError::Unauthorized);
+    }
+    let input = GetAuctionInput {
+        auction_id: params.auction_id,
+    };
+    let auction = match store.get_auction_service(&params.chain_id)? {
+        ServiceEnum::Evm(service) => {
+            Evm::get_auction_response(service.get_auction(input).await?, &auth)
+        }
+        ServiceEnum::Svm(service) => {
+            Svm::get_auction_response(service.get_auction(input).await?, &auth)
+        }
+    };
+    Ok(Json(auction))
+}
+
+/// Returns the auctions of a chain with their timeline, bids and result, sorted by creation time and id.
+///
+/// The amounts and owners of the bids are only shown to the bid owners and the admins.
+/// If the page is full, the cursor of the next page is returned with the auctions.
+#[utoipa::path(get, path = "/v1/{chain_id}/auctions",
+    security(
+        ("bearerAuth" = []),
+    ),
+    responses(
+    (status = 200, description = "Paginated list of auctions for the specified query", body = Auctions),
+    (status = 400, response = ErrorBodyResponse),
+),  params(
+        ("chain_id"=String, Path, description = "The chain id to query for", example = "op_sepolia"),
+        GetAuctionsQueryParams
+    ),
+)]
+pub async fn get_auctions(
+    auth: Auth,
+    State(store): State<Arc<StoreNew>>,
+    Path(chain_id): Path<ChainId>,
+    query: Query<GetAuctionsQueryParams>,
+) -> Result<Json<Auctions>, RestError> {
+    if let Auth::Unauthorized = auth {
+        return Err(RestError::Unauthorized);
+    }
+    match store.get_auction_service(&chain_id)? {
+        ServiceEnum::Evm(service) => Evm::get_auctions(&service, &auth, &query).await,
+        ServiceEnum::Svm(service) => Svm::get_auctions(&service, &auth, &query).await,
+    }
+}
+
+/// Query the maintenance status of a chain.
+#[utoipa::path(get, path = "/v1/{chain_id}/status",
+    params(("chain_id"=String, description = "The chain id to query for")),
+    responses(
+    (status = 200, body = ChainStatusWithId),
+    (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
+),)]
+pub async fn get_chain_status(
+    State(store): State<Arc<StoreNew>>,
+    Path(chain_id): Path<ChainId>,
+) -> Result<Json<ChainStatusWithId>, RestError> {
+    let status = match store.get_auction_service(&chain_id)? {
+        ServiceEnum::Evm(service) => service.get_chain_status().await,
+        ServiceEnum::Svm(service) => service.get_chain_status().await,
+    };
+    Ok(Json(ChainStatusWithId {
+        chain_id,
+        status: status.into(),
+    }))
+}
+
+/// Update the maintenance status of a chain.
+///
+/// Paused chains refuse new bids and opportunities and stop submitting auctions.
+/// Draining chains refuse new bids and opportunities but keep submitting and concluding the existing auctions.
+/// The status is persisted and survives restarts.
+#[utoipa::path(put, path = "/v1/{chain_id}/status", request_body = ChainStatusUpdate,
+    security(
+        ("bearerAuth" = []),
+    ),
+    params(("chain_id"=String, description = "The chain id to update")),
+    responses(
+    (status = 200, body = ChainStatusWithId),
+    (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
+),)]
+pub async fn put_chain_status(
+    State(store): State<Arc<StoreNew>>,
+    Path(chain_id): Path<ChainId>,
+    Json(chain_status_update): Json<ChainStatusUpdate>,
+) -> Result<Json<ChainStatusWithId>, RestError> {
+    let input = UpdateChainStatusInput {
+        status: chain_status_update.status.into(),
+    };
+    match store.get_auction_service(&chain_id)? {
+        ServiceEnum::Evm(service) => service.update_chain_status(input).await?,
+        ServiceEnum::Svm(service) => service.update_chain_status(input).await?,
+    };
+    Ok(Json(ChainStatusWithId {
+        chain_id,
+        status: chain_status_update.status,
+    }))
+}
+
 pub fn get_routes(store: Arc<StoreNew>) -> Router<Arc<StoreNew>> {
     #[allow(deprecated)]
     Router::new()
@@ -527,9 +1041,65 @@ pub fn get_routes(store: Arc<StoreNew>) -> Router<Arc<StoreNew>> {
 pub fn get_routes_with_chain_id(store: Arc<StoreNew>) -> Router<Arc<StoreNew>> {
     Router::new()
         .route("/", login_required!(store, get(get_bids_by_time)))
+        .route("/status", post(post_bid_statuses))
         .route("/:bid_id", get(get_bid_status))
 }
 
+pub fn get_auction_routes() -> Router<Arc<StoreNew>> {
+    Router::new()
+        .route("/", get(get_auctions))
+        .route("/:auction_id", get(get_auction))
+}
+
+pub fn get_chain_status_routes(store: Arc<StoreNew>) -> Router<Arc<StoreNew>> {
+    Router::new()
+        .route("/", get(get_chain_status))
+        .route("/", admin_only!(store, put(put_chain_status)))
+}
+
+impl From<BidStatusKind> for entities::BidStatusKind {
+    fn from(status: BidStatusKind) -> Self {
+        match status {
+            BidStatusKind::Pending => entities::BidStatusKind::Pending,
+            BidStatusKind::Submitted => entities::BidStatusKind::Submitted,
+            BidStatusKind::Lost => entities::BidStatusKind::Lost,
+            BidStatusKind::Won => entities::BidStatusKind::Won,
+            BidStatusKind::Failed => entities::BidStatusKind::Failed,
+            BidStatusKind::Expired => entities::BidStatusKind::Expired,
+        }
+    }
+}
+
+impl From<entities::ChainStatus> for ChainStatus {
+    fn from(status: entities::ChainStatus) -> Self {
+        match status {
+            entities::ChainStatus::Active => ChainStatus::Active,
+            entities::ChainStatus::Draining => ChainStatus::Draining,
+            entities::ChainStatus::Paused => ChainStatus::Paused,
+        }
+    }
+}
+
+impl From<ChainStatus> for entities::ChainStatus {
+    fn from(status: ChainStatus) -> Self {
+        match status {
+            ChainStatus::Active => entities::ChainStatus::Active,
+            ChainStatus::Draining => entities::ChainStatus::Draining,
+            ChainStatus::Paused => entities::ChainStatus::Paused,
+        }
+    }
+}
+
+impl From<entities::RelayerBalanceStatus> for RelayerStatus {
+    fn from(status: entities::RelayerBalanceStatus) -> Self {
+        match status {
+            entities::RelayerBalanceStatus::Healthy => RelayerStatus::Healthy,
+            entities::RelayerBalanceStatus::Low => RelayerStatus::Low,
+            entities::RelayerBalanceStatus::Critical => RelayerStatus::Critical,
+        }
+    }
+}
+
 impl From<entities::BidStatusEvm> for BidStatusEvm {
     fn from(status: entities::BidStatusEvm) -> Self {
         match status {
@@ -546,6 +1116,17 @@ impl From<entities::BidStatusEvm> for BidStatusEvm {
                 result: auction.tx_hash,
                 index,
             },
+            entities::BidStatusEvm::Included {
+                auction,
+                index,
+                won,
+                block_number,
+            } => BidStatusEvm::Included {
+                result: auction.tx_hash,
+                index,
+                won,
+                block_number,
+            },
         }
     }
 }
@@ -581,6 +1162,7 @@ impl BidCoreFields {
             chain_id:        bid.chain_id.clone(),
             initiation_time: bid.initiation_time,
             profile_id:      bid.profile_id,
+            finalized_at:    bid.finalized_at,
         }
     }
 }
@@ -663,19 +1245,185 @@ where
         Ok(Json(bid.get_status()))
     }
 
+    async fn get_bid_statuses(
+        service: &Service<T>,
+        input: GetBidsByIdsInput,
+    ) -> Result<Vec<BidStatusWithId>, RestError> {
+        Ok(service
+            .get_bids_by_ids(input)
+            .await?
+            .into_iter()
+            .map(|bid| BidStatusWithId {
+                id:         bid.id,
+                bid_status: bid.status.into(),
+            })
+            .collect())
+    }
+
     async fn get_bids_by_time(
         service: &Service<T>,
         profile: models::Profile,
         from_time: Option<OffsetDateTime>,
     ) -> Result<Json<Bids>, RestError> {
         let bids = service
-            .get_bids(GetBidsInput { profile, from_time })
+            .get_bids(GetBidsInput {
+                filter: entities::BidFilter {
+                    profile_id: profile.id,
+                    status: None,
+                    permission_key: None,
+                    auction_id: None,
+                    from_time,
+                    to_time: None,
+                    cursor: None,
+                },
+                limit:  default_bids_limit(),
+            })
+            .await?
+            .bids;
+        Ok(Json(Bids {
+            items:       bids.into_iter().map(|b| b.into()).collect(),
+            next_cursor: None,
+            auctions:    None,
+        }))
+    }
+
+    async fn get_bids(
+        service: &Service<T>,
+        profile: models::Profile,
+        query: &GetBidsQueryParams,
+    ) -> Result<Json<Bids>, RestError> {
+        let permission_key = Self::parse_permission_key(query.permission_key.as_deref())?;
+        let limit = std::cmp::min(query.limit, BID_PAGE_SIZE_CAP);
+        let page = service
+            .get_bids(GetBidsInput {
+                filter: entities::BidFilter {
+                    profile_id: profile.id,
+                    status: query.status.map(|status| status.into()),
+                    permission_key,
+                    auction_id: query.auction_id,
+                    from_time: query.from_time,
+                    to_time: query.to_time,
+                    cursor: query.cursor.map(|cursor| cursor.0),
+                },
+                limit,
+            })
             .await?;
+
+        let bids = page.bids;
+        let next_cursor = page.next_cursor.map(|cursor| BidCursor(cursor).to_string());
+        let auctions = match query.include {
+            Some(BidExpansion::Auction) => Some(
+                service
+                    .get_auction_summaries(GetAuctionSummariesInput {
+                        bid_ids: bids.iter().map(|bid| bid.id).collect(),
+                    })
+                    .await?
+                    .into_iter()
+                    .map(|auction| BidAuction {
+                        id:        auction.id,
+                        tx_hash:   auction.tx_hash.as_ref().map(Self::format_tx_hash),
+                        bid_count: auction.bid_count,
+                    })
+                    .collect(),
+            ),
+            None => None,
+        };
         Ok(Json(Bids {
             items: bids.into_iter().map(|b| b.into()).collect(),
+            next_cursor,
+            auctions,
+        }))
+    }
+
+    fn parse_permission_key(
+        permission_key: Option<&str>,
+    ) -> Result<Option<entities::PermissionKey<T>>, RestError> {
+        permission_key
+            .map(|permission_key| {
+                <entities::PermissionKey<T> as FromStr>::from_str(permission_key).map_err(|_| {
+                    RestError::BadParameters(format!("Invalid permission key: {}", permission_key))
+                })
+            })
+            .transpose()
+    }
+
+    async fn get_auctions(
+        service: &Service<T>,
+        auth: &Auth,
+        query: &GetAuctionsQueryParams,
+    ) -> Result<Json<Auctions>, RestError> {
+        let limit = std::cmp::min(query.limit, AUCTION_PAGE_SIZE_CAP);
+        let auctions = service
+            .get_auctions(GetAuctionsInput {
+                filter: entities::AuctionFilter {
+                    permission_key: Self::parse_permission_key(query.permission_key.as_deref())?,
+                    from_time:      query.from_time,
+                    to_time:        query.to_time,
+                    cursor:         query.cursor.map(|cursor| cursor.0),
+                },
+                limit,
+            })
+            .await?;
+
+        let next_cursor = match auctions.last() {
+            Some(auction) if auctions.len() == limit => Some(
+                AuctionCursor(entities::AuctionCursor {
+                    creation_time: auction.creation_time,
+                    id:            auction.id,
+                })
+                .to_string(),
+            ),
+            _ => None,
+        };
+        Ok(Json(Auctions {
+            items: auctions
+                .into_iter()
+                .map(|auction| Self::get_auction_response(auction, auth))
+                .collect(),
+            next_cursor,
         }))
     }
 
+    fn get_auction_response(auction: entities::Auction<T>, auth: &Auth) -> Auction {
+        let is_visible = |bid: &entities::Bid<T>| match auth {
+            Auth::Admin => true,
+            Auth::Authorized(_, profile) => bid.profile_id == Some(profile.id),
+            Auth::Unauthorized => false,
+        };
+        Auction {
+            id:                  auction.id,
+            chain_id:            auction.chain_id,
+            permission_key:      auction.permission_key.to_string(),
+            creation_time:       auction.creation_time,
+            bid_collection_time: auction.bid_collection_time,
+            submission_time:     auction.submission_time,
+            conclusion_time:     auction.conclusion_time,
+            tx_hash:             auction.tx_hash.as_ref().map(Self::format_tx_hash),
+            winner_bid_ids:      auction
+                .bids
+                .iter()
+                .filter(|bid| entities::BidStatus::is_winner(&bid.status))
+                .map(|bid| bid.id)
+                .collect(),
+            bids:                auction
+                .bids
+                .iter()
+                .map(|bid| {
+                    let is_visible = is_visible(bid);
+                    AuctionBid {
+                        id:              bid.id,
+                        initiation_time: bid.initiation_time,
+                        profile_id:      bid.profile_id.filter(|_| is_visible),
+                        amount:          is_visible.then(|| bid.amount.to_string()),
+                        status:          bid.status.clone().into(),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn format_tx_hash(tx_hash: &entities::TxHash<T>) -> String;
+
     fn get_bid_create_entity(
         bid: &BidCreate,
         profile: Option<models::Profile>,
@@ -685,6 +1433,10 @@ where
 impl ApiTrait<Evm> for Evm {
     type BidCreateType = BidCreateEvm;
 
+    fn format_tx_hash(tx_hash: &H256) -> String {
+        format!("{:?}", tx_hash)
+    }
+
     fn get_bid_create_entity(
         bid: &BidCreate,
         profile: Option<models::Profile>,
@@ -713,6 +1465,10 @@ impl ApiTrait<Evm> for Evm {
 impl ApiTrait<Svm> for Svm {
     type BidCreateType = BidCreateSvm;
 
+    fn format_tx_hash(tx_hash: &Signature) -> String {
+        tx_hash.to_string()
+    }
+
     fn get_bid_create_entity(
         bid: &BidCreate,
         profile: Option<models::Profile>,
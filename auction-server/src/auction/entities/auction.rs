@@ -34,9 +34,27 @@ pub struct Auction<T: ChainTrait> {
     pub bids: Vec<Bid<T>>,
 }
 
+/// A position in the list of auctions sorted by creation time and id.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AuctionCursor {
+    pub creation_time: OffsetDateTime,
+    pub id:            AuctionId,
+}
+
+#[derive(Clone, Debug)]
+pub struct AuctionFilter<T: ChainTrait> {
+    pub permission_key: Option<super::PermissionKey<T>>,
+    pub from_time:      Option<OffsetDateTime>,
+    pub to_time:        Option<OffsetDateTime>,
+    pub cursor:         Option<AuctionCursor>,
+}
+
 pub enum SubmitType {
     ByServer,
     ByOther,
+    /// The auction of the permission key is run by someone else and its result is not known yet.
+    /// New bids are not accepted and the pending bids wait for the result.
+    InAuction,
     Invalid,
 }
 
@@ -38,6 +38,7 @@ use {
             Formatter,
         },
         hash::Hash,
+        str::FromStr,
     },
     time::OffsetDateTime,
     uuid::Uuid,
@@ -62,6 +63,8 @@ pub trait BidStatus:
     fn is_pending(&self) -> bool;
     fn is_submitted(&self) -> bool;
     fn is_finalized(&self) -> bool;
+    /// Whether the bid was selected to be submitted for its auction.
+    fn is_winner(&self) -> bool;
 
     fn new_lost() -> Self;
 }
@@ -107,6 +110,14 @@ pub enum BidStatusEvm {
         auction: BidStatusAuction<Self>,
         index:   u32,
     },
+    /// The auction transaction is included in a block which is not deep enough to be final yet.
+    /// This status is not persisted and is reverted to submitted if the block is reorged out.
+    Included {
+        auction:      BidStatusAuction<Self>,
+        index:        u32,
+        won:          bool,
+        block_number: u64,
+    },
 }
 
 impl BidStatus for BidStatusSvm {
@@ -130,6 +141,16 @@ impl BidStatus for BidStatusSvm {
         )
     }
 
+    fn is_winner(&self) -> bool {
+        matches!(
+            self,
+            BidStatusSvm::Submitted { .. }
+                | BidStatusSvm::Won { .. }
+                | BidStatusSvm::Failed { .. }
+                | BidStatusSvm::Expired { .. }
+        )
+    }
+
     fn new_lost() -> Self {
         BidStatusSvm::Lost { auction: None }
     }
@@ -143,13 +164,25 @@ impl BidStatus for BidStatusEvm {
     }
 
     fn is_submitted(&self) -> bool {
-        matches!(self, BidStatusEvm::Submitted { .. })
+        matches!(
+            self,
+            BidStatusEvm::Submitted { .. } | BidStatusEvm::Included { .. }
+        )
     }
 
     fn is_finalized(&self) -> bool {
         matches!(self, BidStatusEvm::Lost { .. } | BidStatusEvm::Won { .. })
     }
 
+    fn is_winner(&self) -> bool {
+        matches!(
+            self,
+            BidStatusEvm::Submitted { .. }
+                | BidStatusEvm::Included { .. }
+                | BidStatusEvm::Won { .. }
+        )
+    }
+
     fn new_lost() -> Self {
         BidStatusEvm::Lost {
             auction: None,
@@ -164,17 +197,36 @@ pub struct Bid<T: ChainTrait> {
     pub chain_id:        ChainId,
     pub initiation_time: OffsetDateTime,
     pub profile_id:      Option<ProfileId>,
+    /// The time the bid result is seen at the settlement commitment level. Not set if the bid never settles.
+    pub finalized_at:    Option<OffsetDateTime>,
 
     pub amount:     T::BidAmountType,
     pub status:     T::BidStatusType,
     pub chain_data: T::BidChainDataType,
 }
 
+/// A concluded svm bid which is not yet checked at the settlement commitment level.
+#[derive(Clone, Debug)]
+pub struct UnfinalizedBidSvm {
+    pub id:              BidId,
+    pub signature:       Signature,
+    pub won:             bool,
+    pub conclusion_time: OffsetDateTime,
+}
+
 pub type PermissionKey<T> = <<T as ChainTrait>::BidChainDataType as BidChainData>::PermissionKey;
 pub type TxHash<T> = <<T as ChainTrait>::BidStatusType as BidStatus>::TxHash;
 
 pub trait BidChainData: Send + Sync + Clone + Debug + PartialEq {
-    type PermissionKey: Send + Sync + Debug + Hash + Eq + Clone + Display;
+    type PermissionKey: Send
+        + Sync
+        + Debug
+        + Hash
+        + Eq
+        + Clone
+        + Display
+        + FromStr
+        + Into<PermissionKeyEvm>;
 
     fn get_permission_key(&self) -> Self::PermissionKey;
 }
@@ -274,6 +326,52 @@ impl From<(Bid<Evm>, bool)> for MulticallData {
     }
 }
 
+/// The status of a bid without its chain specific details.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BidStatusKind {
+    Pending,
+    Submitted,
+    Lost,
+    Won,
+    Failed,
+    Expired,
+}
+
+/// A position in the list of bids sorted by initiation time and id.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BidCursor {
+    pub initiation_time: OffsetDateTime,
+    pub id:              BidId,
+}
+
+/// A page of the bids matching a filter.
+#[derive(Clone, Debug)]
+pub struct BidPage<T: ChainTrait> {
+    pub bids:        Vec<Bid<T>>,
+    /// The cursor of the next page, set when the page is full.
+    pub next_cursor: Option<BidCursor>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BidFilter<T: ChainTrait> {
+    pub profile_id:     ProfileId,
+    pub status:         Option<BidStatusKind>,
+    pub permission_key: Option<PermissionKey<T>>,
+    pub auction_id:     Option<AuctionId>,
+    pub from_time:      Option<OffsetDateTime>,
+    pub to_time:        Option<OffsetDateTime>,
+    pub cursor:         Option<BidCursor>,
+}
+
+/// The auction a bid took part in, as seen by the searchers.
+#[derive(Clone, Debug)]
+pub struct BidAuctionSummary<T: ChainTrait> {
+    pub id:        AuctionId,
+    pub tx_hash:   Option<TxHash<T>>,
+    /// The number of bids competing in the auction, including the bid itself.
+    pub bid_count: u64,
+}
+
 pub struct BidContainerTracing<'a, T: ChainTrait>(pub &'a [Bid<T>]);
 impl<T: ChainTrait> Display for BidContainerTracing<'_, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
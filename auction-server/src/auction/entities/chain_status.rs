@@ -0,0 +1,25 @@
+/// The maintenance status of a chain, controlled by the admin.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ChainStatus {
+    /// The chain is fully operational.
+    #[default]
+    Active,
+    /// New bids and opportunities are refused, but the existing auctions are submitted and concluded.
+    Draining,
+    /// New bids and opportunities are refused and no auction is submitted or concluded.
+    Paused,
+}
+
+impl ChainStatus {
+    pub fn accepts_bids(&self) -> bool {
+        matches!(self, ChainStatus::Active)
+    }
+
+    pub fn accepts_opportunities(&self) -> bool {
+        matches!(self, ChainStatus::Active)
+    }
+
+    pub fn processes_auctions(&self) -> bool {
+        matches!(self, ChainStatus::Active | ChainStatus::Draining)
+    }
+}
@@ -1,7 +1,11 @@
 mod auction;
 mod bid;
+mod chain_status;
+mod relayer;
 
 pub use {
     auction::*,
     bid::*,
+    chain_status::*,
+    relayer::*,
 };
@@ -0,0 +1,17 @@
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelayerBalanceStatus {
+    Healthy,
+    Low,
+    Critical,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelayerBalance {
+    pub address:     String,
+    /// The balance in the native token of the chain.
+    pub balance:     f64,
+    pub status:      RelayerBalanceStatus,
+    pub update_time: OffsetDateTime,
+}
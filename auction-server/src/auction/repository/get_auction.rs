@@ -0,0 +1,40 @@
+use {
+    super::{
+        models,
+        ChainTrait,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+impl<T: ChainTrait> Repository<T> {
+    pub async fn get_auction(
+        &self,
+        auction_id: entities::AuctionId,
+    ) -> Result<entities::Auction<T>, RestError> {
+        let auction: models::Auction =
+            sqlx::query_as("SELECT * FROM auction WHERE id = $1 AND chain_id = $2")
+                .bind(auction_id)
+                .bind(self.chain_id.clone())
+                .fetch_one(&self.db)
+                .await
+                .map_err(|e| match e {
+                    sqlx::Error::RowNotFound => RestError::AuctionNotFound,
+                    _ => {
+                        tracing::error!(
+                            error = e.to_string(),
+                            auction_id = auction_id.to_string(),
+                            "Failed to get auction from db"
+                        );
+                        RestError::TemporarilyUnavailable
+                    }
+                })?;
+        self.get_auction_entities(vec![auction])
+            .await?
+            .pop()
+            .ok_or(RestError::AuctionNotFound)
+    }
+}
@@ -0,0 +1,52 @@
+use {
+    super::{
+        models,
+        ChainTrait,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+impl<T: ChainTrait> Repository<T> {
+    /// Load the bids of the stored auctions and convert them to entities.
+    pub(super) async fn get_auction_entities(
+        &self,
+        auctions: Vec<models::Auction>,
+    ) -> Result<Vec<entities::Auction<T>>, RestError> {
+        let auction_ids: Vec<entities::AuctionId> =
+            auctions.iter().map(|auction| auction.id).collect();
+        let bids: Vec<models::Bid<T>> = sqlx::query_as(
+            "SELECT * FROM bid WHERE auction_id = ANY($1) ORDER BY initiation_time ASC, id ASC",
+        )
+        .bind(auction_ids)
+        .fetch_all(&self.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("DB: Failed to fetch bids of auctions: {}", e);
+            RestError::TemporarilyUnavailable
+        })?;
+
+        auctions
+            .into_iter()
+            .map(|auction| {
+                let bids = bids
+                    .iter()
+                    .filter(|bid| bid.auction_id == Some(auction.id))
+                    .map(|bid| bid.get_bid_entity(Some(auction.clone())))
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .and_then(|bids| auction.get_auction_entity(bids));
+                bids.map_err(|e| {
+                    tracing::error!(
+                        error = e.to_string(),
+                        auction = ?auction,
+                        "Failed to convert auction to entity"
+                    );
+                    RestError::TemporarilyUnavailable
+                })
+            })
+            .collect()
+    }
+}
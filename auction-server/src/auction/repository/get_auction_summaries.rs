@@ -0,0 +1,65 @@
+use {
+    super::{
+        models,
+        ChainTrait,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+    std::collections::HashMap,
+};
+
+impl<T: ChainTrait> Repository<T> {
+    /// Get the auctions the given bids took part in.
+    pub async fn get_auction_summaries(
+        &self,
+        bid_ids: Vec<entities::BidId>,
+    ) -> Result<Vec<entities::BidAuctionSummary<T>>, RestError> {
+        let auctions: Vec<models::Auction> = sqlx::query_as(
+            "SELECT * FROM auction WHERE id IN (SELECT auction_id FROM bid WHERE id = ANY($1))",
+        )
+        .bind(&bid_ids)
+        .fetch_all(&self.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("DB: Failed to fetch auctions: {}", e);
+            RestError::TemporarilyUnavailable
+        })?;
+        let bid_counts: HashMap<entities::AuctionId, i64> = sqlx::query_as::<_, (entities::AuctionId, i64)>(
+            "SELECT auction_id, COUNT(*) FROM bid WHERE auction_id IN (SELECT auction_id FROM bid WHERE id = ANY($1)) GROUP BY auction_id",
+        )
+        .bind(&bid_ids)
+        .fetch_all(&self.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("DB: Failed to count the bids of auctions: {}", e);
+            RestError::TemporarilyUnavailable
+        })?
+        .into_iter()
+        .collect();
+
+        Ok(auctions
+            .into_iter()
+            .filter_map(|auction| {
+                let id = auction.id;
+                let tx_hash = T::get_bid_status_auction_entity(Some(auction))
+                    .map_err(|e| {
+                        tracing::error!(
+                            error = e.to_string(),
+                            auction_id = %id,
+                            "Failed to convert auction transaction hash"
+                        );
+                    })
+                    .ok()?
+                    .map(|auction| auction.tx_hash);
+                Some(entities::BidAuctionSummary {
+                    id,
+                    tx_hash,
+                    bid_count: bid_counts.get(&id).copied().unwrap_or_default() as u64,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,57 @@
+use {
+    super::{
+        models,
+        ChainTrait,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+    sqlx::QueryBuilder,
+    time::PrimitiveDateTime,
+};
+
+impl<T: ChainTrait> Repository<T> {
+    pub async fn get_auctions(
+        &self,
+        filter: entities::AuctionFilter<T>,
+        limit: usize,
+    ) -> Result<Vec<entities::Auction<T>>, RestError> {
+        let mut query = QueryBuilder::new("SELECT * from auction WHERE chain_id = ");
+        query.push_bind(self.chain_id.clone());
+        if let Some(permission_key) = &filter.permission_key {
+            query.push(" AND permission_key = ");
+            query.push_bind(T::convert_permission_key(permission_key));
+        }
+        if let Some(from_time) = filter.from_time {
+            query.push(" AND creation_time >= ");
+            query.push_bind(from_time);
+        }
+        if let Some(to_time) = filter.to_time {
+            query.push(" AND creation_time < ");
+            query.push_bind(to_time);
+        }
+        if let Some(cursor) = filter.cursor {
+            query.push(" AND (creation_time, id) > (");
+            query.push_bind(PrimitiveDateTime::new(
+                cursor.creation_time.date(),
+                cursor.creation_time.time(),
+            ));
+            query.push(", ");
+            query.push_bind(cursor.id);
+            query.push(")");
+        }
+        query.push(" ORDER BY creation_time ASC, id ASC LIMIT ");
+        query.push_bind(std::cmp::min(limit, super::AUCTION_PAGE_SIZE_CAP) as i64);
+        let auctions: Vec<models::Auction> = query
+            .build_query_as()
+            .fetch_all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("DB: Failed to fetch auctions: {} - filter: {:?}", e, filter);
+                RestError::TemporarilyUnavailable
+            })?;
+        self.get_auction_entities(auctions).await
+    }
+}
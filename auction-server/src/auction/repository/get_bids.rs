@@ -8,15 +8,16 @@ use {
     crate::{
         api::RestError,
         auction::entities,
-        kernel::entities::ChainId,
-        models::ProfileId,
     },
     sqlx::QueryBuilder,
-    time::OffsetDateTime,
+    time::{
+        PrimitiveDateTime,
+        UtcOffset,
+    },
 };
 
 impl<T: ChainTrait> Repository<T> {
-    async fn get_auctions_by_bids_model(
+    pub(super) async fn get_auctions_by_bids_model(
         &self,
         bids: &[models::Bid<T>],
     ) -> Result<Vec<models::Auction>, RestError> {
@@ -34,20 +35,46 @@ impl<T: ChainTrait> Repository<T> {
 
     async fn get_bids_model(
         &self,
-        chain_id: ChainId,
-        profile_id: ProfileId,
-        from_time: Option<OffsetDateTime>,
+        filter: &entities::BidFilter<T>,
+        limit: usize,
     ) -> Result<Vec<Bid<T>>, RestError> {
         let mut query = QueryBuilder::new("SELECT * from bid where profile_id = ");
         query
-            .push_bind(profile_id)
+            .push_bind(filter.profile_id)
             .push(" AND chain_id = ")
-            .push_bind(chain_id);
-        if let Some(from_time) = from_time {
+            .push_bind(self.chain_id.clone());
+        if let Some(status) = filter.status {
+            query.push(" AND status = ");
+            query.push_bind(models::BidStatus::from(status));
+        }
+        if let Some(permission_key) = &filter.permission_key {
+            query.push(" AND permission_key = ");
+            query.push_bind(T::convert_permission_key(permission_key));
+        }
+        if let Some(auction_id) = filter.auction_id {
+            query.push(" AND auction_id = ");
+            query.push_bind(auction_id);
+        }
+        if let Some(from_time) = filter.from_time {
             query.push(" AND initiation_time >= ");
             query.push_bind(from_time);
         }
-        query.push(" ORDER BY initiation_time ASC LIMIT 20");
+        if let Some(to_time) = filter.to_time {
+            query.push(" AND initiation_time < ");
+            query.push_bind(to_time);
+        }
+        if let Some(cursor) = filter.cursor {
+            query.push(" AND (initiation_time, id) > (");
+            query.push_bind(PrimitiveDateTime::new(
+                cursor.initiation_time.date(),
+                cursor.initiation_time.time(),
+            ));
+            query.push(", ");
+            query.push_bind(cursor.id);
+            query.push(")");
+        }
+        query.push(" ORDER BY initiation_time ASC, id ASC LIMIT ");
+        query.push_bind(std::cmp::min(limit, super::BID_PAGE_SIZE_CAP) as i64);
         query
             .build_query_as()
             .fetch_all(&self.db)
@@ -60,15 +87,23 @@ impl<T: ChainTrait> Repository<T> {
 
     pub async fn get_bids(
         &self,
-        profile_id: ProfileId,
-        from_time: Option<OffsetDateTime>,
-    ) -> Result<Vec<entities::Bid<T>>, RestError> {
-        let bids = self
-            .get_bids_model(self.chain_id.clone(), profile_id, from_time)
-            .await?;
+        filter: entities::BidFilter<T>,
+        limit: usize,
+    ) -> Result<entities::BidPage<T>, RestError> {
+        let bids = self.get_bids_model(&filter, limit).await?;
         let auctions = self.get_auctions_by_bids_model(&bids).await?;
 
-        Ok(bids
+        // The cursor is taken from the rows, so the bids which fail to convert do not end the pages early
+        let next_cursor = match bids.last() {
+            Some(bid) if bids.len() == std::cmp::min(limit, super::BID_PAGE_SIZE_CAP) => {
+                Some(entities::BidCursor {
+                    initiation_time: bid.initiation_time.assume_offset(UtcOffset::UTC),
+                    id:              bid.id,
+                })
+            }
+            _ => None,
+        };
+        let bids = bids
             .into_iter()
             .filter_map(|b| {
                 let auction = match b.auction_id {
@@ -86,6 +121,7 @@ impl<T: ChainTrait> Repository<T> {
                     })
                     .ok()
             })
-            .collect())
+            .collect();
+        Ok(entities::BidPage { bids, next_cursor })
     }
 }
@@ -0,0 +1,54 @@
+use {
+    super::{
+        models,
+        ChainTrait,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+        models::ProfileId,
+    },
+    sqlx::QueryBuilder,
+};
+
+impl<T: ChainTrait> Repository<T> {
+    /// Get the bids with the given ids. The bids of other profiles are skipped if a profile is given.
+    pub async fn get_bids_by_ids(
+        &self,
+        bid_ids: Vec<entities::BidId>,
+        profile_id: Option<ProfileId>,
+    ) -> Result<Vec<entities::Bid<T>>, RestError> {
+        let mut query = QueryBuilder::new("SELECT * from bid WHERE chain_id = ");
+        query.push_bind(self.chain_id.clone());
+        query.push(" AND id = ANY(");
+        query.push_bind(bid_ids);
+        query.push(")");
+        if let Some(profile_id) = profile_id {
+            query.push(" AND profile_id = ");
+            query.push_bind(profile_id);
+        }
+        let bids: Vec<models::Bid<T>> =
+            query
+                .build_query_as()
+                .fetch_all(&self.db)
+                .await
+                .map_err(|e| {
+                    tracing::error!("DB: Failed to fetch bids by ids: {}", e);
+                    RestError::TemporarilyUnavailable
+                })?;
+        let auctions = self.get_auctions_by_bids_model(&bids).await?;
+
+        bids.into_iter()
+            .map(|bid| {
+                let auction = bid
+                    .auction_id
+                    .and_then(|auction_id| auctions.iter().find(|a| a.id == auction_id).cloned());
+                bid.get_bid_entity(auction).map_err(|e| {
+                    tracing::error!(error = e.to_string(), bid = ?bid, "Failed to convert bid to entity");
+                    RestError::TemporarilyUnavailable
+                })
+            })
+            .collect()
+    }
+}
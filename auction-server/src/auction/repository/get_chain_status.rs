@@ -0,0 +1,13 @@
+use {
+    super::Repository,
+    crate::auction::{
+        entities,
+        service::ChainTrait,
+    },
+};
+
+impl<T: ChainTrait> Repository<T> {
+    pub async fn get_chain_status(&self) -> entities::ChainStatus {
+        *self.in_memory_store.chain_status.read().await
+    }
+}
@@ -0,0 +1,51 @@
+use {
+    super::{
+        models::{
+            self,
+            ModelTrait,
+        },
+        Repository,
+    },
+    crate::{
+        auction::entities,
+        kernel::entities::Svm,
+    },
+    time::UtcOffset,
+};
+
+impl Repository<Svm> {
+    /// Get the oldest won and failed bids which are not checked at the settlement commitment level yet.
+    /// The bids which timed out before settling are flagged as mismatched and not returned again.
+    pub async fn get_unfinalized_bids(
+        &self,
+        limit: i64,
+    ) -> anyhow::Result<Vec<entities::UnfinalizedBidSvm>> {
+        let bids: Vec<models::Bid<Svm>> = sqlx::query_as(
+            "SELECT * FROM bid WHERE chain_id = $1 AND status IN ('won', 'failed') AND finalized_at IS NULL AND NOT finalization_mismatch AND conclusion_time IS NOT NULL ORDER BY conclusion_time LIMIT $2",
+        )
+        .bind(self.chain_id.clone())
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        bids.into_iter()
+            .map(|bid| {
+                let chain_data = Svm::get_chain_data_entity(&bid)?;
+                let signature = *chain_data
+                    .transaction
+                    .signatures
+                    .first()
+                    .ok_or_else(|| anyhow::anyhow!("Bid {} has no signature", bid.id))?;
+                Ok(entities::UnfinalizedBidSvm {
+                    id: bid.id,
+                    signature,
+                    won: bid.status == models::BidStatus::Won,
+                    conclusion_time: bid
+                        .conclusion_time
+                        .expect("conclusion time is filtered to be not null")
+                        .assume_offset(UtcOffset::UTC),
+                })
+            })
+            .collect()
+    }
+}
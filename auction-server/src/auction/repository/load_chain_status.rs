@@ -0,0 +1,24 @@
+use {
+    super::{
+        models,
+        Repository,
+    },
+    crate::auction::{
+        entities,
+        service::ChainTrait,
+    },
+};
+
+impl<T: ChainTrait> Repository<T> {
+    /// Loads the persisted chain status into the memory, chains without a stored status are active.
+    pub async fn load_chain_status(&self) -> anyhow::Result<entities::ChainStatus> {
+        let status: Option<models::ChainStatus> =
+            sqlx::query_scalar("SELECT status FROM chain_status WHERE chain_id = $1")
+                .bind(&self.chain_id)
+                .fetch_optional(&self.db)
+                .await?;
+        let status = status.map(|status| status.into()).unwrap_or_default();
+        *self.in_memory_store.chain_status.write().await = status;
+        Ok(status)
+    }
+}
@@ -24,8 +24,14 @@ mod add_bid;
 mod add_lookup_table;
 mod add_recent_priotization_fee;
 mod conclude_auction;
+mod get_auction;
+mod get_auction_entities;
+mod get_auction_summaries;
+mod get_auctions;
 mod get_bid;
 mod get_bids;
+mod get_bids_by_ids;
+mod get_chain_status;
 mod get_in_memory_bids;
 mod get_in_memory_bids_by_permission_key;
 mod get_in_memory_submitted_auctions;
@@ -33,13 +39,19 @@ mod get_in_memory_submitted_bids_for_auction;
 mod get_lookup_table;
 mod get_or_create_in_memory_auction_lock;
 mod get_priority_fees;
+mod get_unfinalized_bids;
+mod load_chain_status;
 mod models;
 mod remove_in_memory_auction_lock;
 mod remove_in_memory_submitted_auction;
 mod submit_auction;
+mod update_bid_finalization;
 mod update_bid_status;
+mod update_chain_status;
 
 pub use models::*;
+pub const BID_PAGE_SIZE_CAP: usize = 100;
+pub const AUCTION_PAGE_SIZE_CAP: usize = 100;
 
 #[derive(Debug, Default)]
 pub struct ChainStoreSvm {
@@ -63,6 +75,7 @@ pub struct InMemoryStore<T: ChainTrait> {
     pub bids:               RwLock<HashMap<entities::PermissionKey<T>, Vec<entities::Bid<T>>>>,
     pub auction_lock:       Mutex<HashMap<entities::PermissionKey<T>, entities::AuctionLock>>,
     pub submitted_auctions: RwLock<Vec<entities::Auction<T>>>,
+    pub chain_status:       RwLock<entities::ChainStatus>,
 
     pub chain_store: T::ChainStore,
 }
@@ -73,6 +86,7 @@ impl<T: ChainTrait> Default for InMemoryStore<T> {
             bids:               RwLock::new(HashMap::new()),
             auction_lock:       Mutex::new(HashMap::new()),
             submitted_auctions: RwLock::new(Vec::new()),
+            chain_status:       RwLock::new(entities::ChainStatus::default()),
             chain_store:        T::ChainStore::default(),
         }
     }
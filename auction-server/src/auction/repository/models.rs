@@ -58,6 +58,34 @@ pub enum ChainType {
     Svm,
 }
 
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "chain_status", rename_all = "lowercase")]
+pub enum ChainStatus {
+    Active,
+    Draining,
+    Paused,
+}
+
+impl From<ChainStatus> for entities::ChainStatus {
+    fn from(status: ChainStatus) -> Self {
+        match status {
+            ChainStatus::Active => entities::ChainStatus::Active,
+            ChainStatus::Draining => entities::ChainStatus::Draining,
+            ChainStatus::Paused => entities::ChainStatus::Paused,
+        }
+    }
+}
+
+impl From<entities::ChainStatus> for ChainStatus {
+    fn from(status: entities::ChainStatus) -> Self {
+        match status {
+            entities::ChainStatus::Active => ChainStatus::Active,
+            entities::ChainStatus::Draining => ChainStatus::Draining,
+            entities::ChainStatus::Paused => ChainStatus::Paused,
+        }
+    }
+}
+
 #[derive(Clone, FromRow, Debug)]
 #[allow(dead_code)]
 pub struct Auction {
@@ -72,6 +100,34 @@ pub struct Auction {
     pub submission_time:     Option<PrimitiveDateTime>,
 }
 
+impl Auction {
+    /// Auctions created before the bid collection time was stored collected their bids on creation.
+    pub fn get_auction_entity<T: ChainTrait>(
+        &self,
+        bids: Vec<entities::Bid<T>>,
+    ) -> anyhow::Result<entities::Auction<T>> {
+        let creation_time = self.creation_time.assume_offset(UtcOffset::UTC);
+        Ok(entities::Auction {
+            id: self.id,
+            chain_id: self.chain_id.clone(),
+            permission_key: T::get_permission_key_entity(&self.permission_key)?,
+            creation_time,
+            conclusion_time: self
+                .conclusion_time
+                .map(|time| time.assume_offset(UtcOffset::UTC)),
+            bid_collection_time: self
+                .bid_collection_time
+                .map_or(creation_time, |time| time.assume_offset(UtcOffset::UTC)),
+            submission_time: self
+                .submission_time
+                .map(|time| time.assume_offset(UtcOffset::UTC)),
+            tx_hash: T::get_bid_status_auction_entity(Some(self.clone()))?
+                .map(|auction| auction.tx_hash),
+            bids,
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, PartialOrd, sqlx::Type)]
 #[sqlx(type_name = "bid_status", rename_all = "lowercase")]
 pub enum BidStatus {
@@ -83,6 +139,19 @@ pub enum BidStatus {
     Expired,
 }
 
+impl From<entities::BidStatusKind> for BidStatus {
+    fn from(status: entities::BidStatusKind) -> Self {
+        match status {
+            entities::BidStatusKind::Pending => BidStatus::Pending,
+            entities::BidStatusKind::Submitted => BidStatus::Submitted,
+            entities::BidStatusKind::Lost => BidStatus::Lost,
+            entities::BidStatusKind::Won => BidStatus::Won,
+            entities::BidStatusKind::Failed => BidStatus::Failed,
+            entities::BidStatusKind::Expired => BidStatus::Expired,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BundleIndex(pub Option<u32>);
 impl Deref for BundleIndex {
@@ -132,6 +201,9 @@ pub trait ModelTrait<T: ChainTrait> {
     fn get_chain_data_entity(bid: &Bid<T>) -> anyhow::Result<T::BidChainDataType>;
 
     fn convert_permission_key(permission_key: &entities::PermissionKey<T>) -> Vec<u8>;
+    fn get_permission_key_entity(
+        permission_key: &[u8],
+    ) -> anyhow::Result<entities::PermissionKey<T>>;
     fn convert_amount(amount: &T::BidAmountType) -> BigDecimal;
 
     fn get_metadata(chain_data: &T::BidChainDataType) -> Self::BidMetadataType;
@@ -217,7 +289,9 @@ impl ModelTrait<Evm> for Evm {
     fn convert_bid_status(status: &entities::BidStatusEvm) -> BidStatus {
         match status {
             entities::BidStatusEvm::Pending => BidStatus::Pending,
-            entities::BidStatusEvm::Submitted { .. } => BidStatus::Submitted,
+            entities::BidStatusEvm::Submitted { .. } | entities::BidStatusEvm::Included { .. } => {
+                BidStatus::Submitted
+            }
             entities::BidStatusEvm::Lost { .. } => BidStatus::Lost,
             entities::BidStatusEvm::Won { .. } => BidStatus::Won,
         }
@@ -238,6 +312,12 @@ impl ModelTrait<Evm> for Evm {
         permission_key.to_vec()
     }
 
+    fn get_permission_key_entity(
+        permission_key: &[u8],
+    ) -> anyhow::Result<entities::PermissionKey<Evm>> {
+        Ok(Bytes::from(permission_key.to_vec()))
+    }
+
     fn convert_amount(amount: &entities::BidAmountEvm) -> BigDecimal {
         BigDecimal::from_str(&amount.to_string()).expect("Failed to convert amount to BigDecimal")
     }
@@ -257,6 +337,7 @@ impl ModelTrait<Evm> for Evm {
     ) -> anyhow::Result<Query<'_, Postgres, PgArguments>> {
         match new_status {
             entities::BidStatusEvm::Pending => Err(anyhow::anyhow!("Cannot update bid status to pending")),
+            entities::BidStatusEvm::Included { .. } => Err(anyhow::anyhow!("Cannot persist the provisional included bid status")),
             entities::BidStatusEvm::Submitted { index, auction } => {
                 Ok(sqlx::query!(
                     "UPDATE bid SET status = $1, auction_id = $2, metadata = jsonb_set(metadata, '{bundle_index}', $3) WHERE id = $4 AND status = $5",
@@ -420,6 +501,14 @@ impl ModelTrait<Svm> for Svm {
         permission_key.0.to_vec()
     }
 
+    fn get_permission_key_entity(
+        permission_key: &[u8],
+    ) -> anyhow::Result<entities::PermissionKey<Svm>> {
+        Ok(PermissionKeySvm(permission_key.try_into().map_err(
+            |e| anyhow::anyhow!("Failed to convert svm permission key to slice {:?}", e),
+        )?))
+    }
+
     fn convert_amount(amount: &entities::BidAmountSvm) -> BigDecimal {
         (*amount).into()
     }
@@ -485,6 +574,7 @@ pub struct Bid<T: ChainTrait + ModelTrait<T>> {
     pub initiation_time: PrimitiveDateTime,
     #[allow(dead_code)]
     pub conclusion_time: Option<PrimitiveDateTime>,
+    pub finalized_at:    Option<PrimitiveDateTime>,
     pub profile_id:      Option<ProfileId>,
     pub metadata:        Json<T::BidMetadataType>,
 }
@@ -510,6 +600,7 @@ impl<T: ChainTrait + ModelTrait<T>> Bid<T> {
                 bid.initiation_time.time(),
             ),
             conclusion_time: None,
+            finalized_at:    None,
             profile_id:      bid.profile.map(|p| p.id),
             metadata:        Json(T::get_metadata(chain_data)),
         }
@@ -521,6 +612,9 @@ impl<T: ChainTrait + ModelTrait<T>> Bid<T> {
             chain_id:        self.chain_id.clone(),
             initiation_time: self.initiation_time.assume_offset(UtcOffset::UTC),
             profile_id:      self.profile_id,
+            finalized_at:    self
+                .finalized_at
+                .map(|finalized_at| finalized_at.assume_offset(UtcOffset::UTC)),
 
             amount:     T::get_bid_amount_entity(self)?,
             status:     T::get_bid_status_entity(self, auction)?,
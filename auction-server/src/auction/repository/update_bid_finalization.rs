@@ -0,0 +1,36 @@
+use {
+    super::Repository,
+    crate::{
+        auction::entities,
+        kernel::entities::Svm,
+    },
+    time::{
+        OffsetDateTime,
+        PrimitiveDateTime,
+    },
+};
+
+impl Repository<Svm> {
+    /// Record whether the settled result differs from the concluded one.
+    /// The finalization time is only set if the bid is seen at the settlement commitment level.
+    pub async fn update_bid_finalization(
+        &self,
+        bid_id: entities::BidId,
+        settled: bool,
+        mismatch: bool,
+    ) -> anyhow::Result<()> {
+        let finalized_at = settled.then(|| {
+            let now = OffsetDateTime::now_utc();
+            PrimitiveDateTime::new(now.date(), now.time())
+        });
+        sqlx::query(
+            "UPDATE bid SET finalized_at = $1, finalization_mismatch = $2 WHERE id = $3 AND finalized_at IS NULL",
+        )
+        .bind(finalized_at)
+        .bind(mismatch)
+        .bind(bid_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+}
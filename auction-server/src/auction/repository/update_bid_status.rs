@@ -52,6 +52,15 @@ impl<T: ChainTrait> Repository<T> {
         bid: entities::Bid<T>,
         new_status: T::BidStatusType,
     ) -> anyhow::Result<bool> {
+        // Changes between the submitted states are provisional and only kept in memory
+        if bid.status.is_submitted() && new_status.is_submitted() {
+            let is_updated = bid.status != new_status;
+            if is_updated {
+                self.update_in_memory_bid(&bid, new_status).await;
+            }
+            return Ok(is_updated);
+        }
+
         let update_query = T::get_update_bid_query(&bid, new_status.clone())?;
         let query_result = update_query.execute(&self.db).await?;
 
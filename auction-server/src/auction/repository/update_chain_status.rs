@@ -0,0 +1,26 @@
+use {
+    super::{
+        models,
+        Repository,
+    },
+    crate::auction::{
+        entities,
+        service::ChainTrait,
+    },
+};
+
+impl<T: ChainTrait> Repository<T> {
+    #[tracing::instrument(skip_all, name = "update_chain_status_repo")]
+    pub async fn update_chain_status(&self, status: entities::ChainStatus) -> anyhow::Result<()> {
+        let mut write_guard = self.in_memory_store.chain_status.write().await;
+        sqlx::query(
+            "INSERT INTO chain_status (chain_id, status) VALUES ($1, $2) ON CONFLICT (chain_id) DO UPDATE SET status = EXCLUDED.status, update_time = CURRENT_TIMESTAMP",
+        )
+        .bind(&self.chain_id)
+        .bind(models::ChainStatus::from(status))
+        .execute(&self.db)
+        .await?;
+        *write_guard = status;
+        Ok(())
+    }
+}
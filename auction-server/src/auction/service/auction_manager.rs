@@ -14,27 +14,32 @@ use {
                 Evm,
                 Svm,
             },
+            health::LoopHealthRecorder,
+            traced_client::TracedClient,
         },
+        models,
         opportunity::{
             self,
-            service::get_live_opportunities::GetLiveOpportunitiesInput,
+            service::{
+                get_integrator::GetIntegratorByRouterInput,
+                get_live_opportunities::GetLiveOpportunitiesInput,
+                update_quote_status::UpdateQuoteStatusInput,
+            },
         },
     },
     anyhow::Result,
     axum::async_trait,
+    axum_prometheus::metrics,
     ethers::{
         contract::EthEvent,
         providers::{
             Middleware,
             Provider,
-            SubscriptionStream,
             Ws,
         },
         types::{
-            Block,
             Bytes,
             TransactionReceipt,
-            H256,
             U256,
         },
     },
@@ -47,11 +52,8 @@ use {
         rpc_config::RpcSendTransactionConfig,
     },
     solana_sdk::{
-        commitment_config::CommitmentConfig,
-        signature::{
-            Signature,
-            Signer,
-        },
+        pubkey::Pubkey,
+        signature::Signature,
         transaction::TransactionError,
     },
     std::{
@@ -65,9 +67,16 @@ use {
         time::Duration,
     },
     time::OffsetDateTime,
-    tokio::time::{
-        interval,
-        Interval,
+    tokio::{
+        sync::mpsc,
+        time::{
+            interval,
+            Interval,
+        },
+    },
+    tokio_stream::{
+        wrappers::ReceiverStream,
+        StreamExt,
     },
     uuid::Uuid,
 };
@@ -89,7 +98,11 @@ pub trait AuctionManager<T: ChainTrait> {
     /// Get the ws client for the chain.
     async fn get_ws_client(&self) -> Result<Self::WsClient>;
     /// Get the trigger stream for the ws client to subscribe to new triggers.
-    async fn get_trigger_stream<'a>(client: &'a Self::WsClient) -> Result<Self::TriggerStream<'a>>;
+    /// The events received from the chain websocket are recorded in the loop health.
+    async fn get_trigger_stream<'a>(
+        client: &'a Self::WsClient,
+        health: &LoopHealthRecorder,
+    ) -> Result<Self::TriggerStream<'a>>;
     /// Check if the auction is ready to be concluded based on the trigger.
     fn is_ready_to_conclude(trigger: Self::Trigger) -> bool;
 
@@ -125,6 +138,13 @@ pub trait AuctionManager<T: ChainTrait> {
         submitted_bids: &[entities::Bid<T>],
         bid_status_auction: entities::BidStatusAuction<T::BidStatusType>,
     ) -> T::BidStatusType;
+
+    /// Update the entities which follow the bid after the status of the bid is changed.
+    async fn handle_bid_status_update(
+        &self,
+        bid: &entities::Bid<T>,
+        new_status: &T::BidStatusType,
+    ) -> Result<()>;
 }
 
 
@@ -135,23 +155,198 @@ pub trait AuctionManager<T: ChainTrait> {
 pub const TOTAL_BIDS_PER_AUCTION_EVM: usize = 3;
 const EXTRA_GAS_FOR_SUBMISSION: u32 = 500 * 1000;
 
+const TRIGGER_RECONNECT_BACKOFF_MIN_EVM: Duration = Duration::from_secs(1);
+const TRIGGER_RECONNECT_BACKOFF_MAX_EVM: Duration = Duration::from_secs(60);
+/// The websocket subscription is considered stalled if no block is received for a few block times.
+const TRIGGER_STALL_TIMEOUT_EVM: Duration = Duration::from_secs(30);
+/// The websocket connection and the block subscription are considered failed if they do not complete in this duration.
+const TRIGGER_CONNECT_TIMEOUT_EVM: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TriggerSourceKind {
+    Ws,
+    Polling,
+}
+
+impl TriggerSourceKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TriggerSourceKind::Ws => "ws",
+            TriggerSourceKind::Polling => "polling",
+        }
+    }
+}
+
+/// Source of the new block triggers for evm chains.
+///
+/// Blocks are received from the websocket subscription when it is available.
+/// When the websocket fails or stalls, the block number is polled from the http provider at the provider interval
+/// while the websocket is reconnected with an exponential backoff.
+#[derive(Clone)]
+pub struct TriggerSourceEvm {
+    chain_id:   String,
+    ws_address: String,
+    provider:   Provider<TracedClient>,
+}
+
+impl TriggerSourceEvm {
+    fn record_source(&self, current: &mut Option<TriggerSourceKind>, source: TriggerSourceKind) {
+        if *current == Some(source) {
+            return;
+        }
+        tracing::info!(
+            chain_id = self.chain_id,
+            source = source.as_str(),
+            "Switching submission trigger source"
+        );
+        metrics::counter!(
+            "submission_trigger_source_switches_total",
+            &[
+                ("chain_id", self.chain_id.clone()),
+                ("source", source.as_str().to_string()),
+            ]
+        )
+        .increment(1);
+        metrics::gauge!(
+            "submission_trigger_polling",
+            &[("chain_id", self.chain_id.clone())]
+        )
+        .set(if source == TriggerSourceKind::Polling {
+            1.0
+        } else {
+            0.0
+        });
+        *current = Some(source);
+    }
+
+    /// Forwards the new block numbers from the websocket subscription until it fails or stalls.
+    /// Returns Ok if the receiver is dropped.
+    async fn forward_ws_blocks(
+        &self,
+        sender: &mpsc::Sender<u64>,
+        current: &mut Option<TriggerSourceKind>,
+        health: &LoopHealthRecorder,
+    ) -> Result<()> {
+        let timeout_error = || {
+            anyhow::anyhow!(
+                "Websocket connection timed out after {:?}",
+                TRIGGER_CONNECT_TIMEOUT_EVM
+            )
+        };
+        let ws = tokio::time::timeout(
+            TRIGGER_CONNECT_TIMEOUT_EVM,
+            Ws::connect(self.ws_address.clone()),
+        )
+        .await
+        .map_err(|_| timeout_error())??;
+        let provider = Provider::new(ws);
+        let mut stream =
+            tokio::time::timeout(TRIGGER_CONNECT_TIMEOUT_EVM, provider.subscribe_blocks())
+                .await
+                .map_err(|_| timeout_error())??;
+        self.record_source(current, TriggerSourceKind::Ws);
+        loop {
+            let block = match tokio::time::timeout(TRIGGER_STALL_TIMEOUT_EVM, stream.next()).await {
+                Ok(Some(block)) => block,
+                Ok(None) => return Err(anyhow::anyhow!("Block subscription ended")),
+                Err(_) => {
+                    return Err(anyhow::anyhow!(
+                        "No block received from the subscription for {:?}",
+                        TRIGGER_STALL_TIMEOUT_EVM
+                    ))
+                }
+            };
+            health.record_ws_event(&self.chain_id);
+            let number = block.number.unwrap_or_default().as_u64();
+            if sender.send(number).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Forwards the new block numbers polled from the http provider for the given duration.
+    async fn forward_polled_blocks(
+        &self,
+        sender: &mpsc::Sender<u64>,
+        current: &mut Option<TriggerSourceKind>,
+        duration: Duration,
+    ) {
+        self.record_source(current, TriggerSourceKind::Polling);
+        let deadline = tokio::time::Instant::now() + duration;
+        let mut poll_interval = interval(self.provider.get_interval());
+        let mut last_block = None;
+        while tokio::time::Instant::now() < deadline && !sender.is_closed() {
+            poll_interval.tick().await;
+            match self.provider.get_block_number().await {
+                Ok(number) => {
+                    let number = number.as_u64();
+                    if last_block != Some(number) {
+                        last_block = Some(number);
+                        if sender.send(number).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(chain_id = self.chain_id, error = ?err, "Failed to poll block number");
+                }
+            }
+        }
+    }
+
+    async fn run(self, sender: mpsc::Sender<u64>, health: LoopHealthRecorder) {
+        let mut current = None;
+        let mut backoff = TRIGGER_RECONNECT_BACKOFF_MIN_EVM;
+        while !sender.is_closed() {
+            let connected_at = tokio::time::Instant::now();
+            match self.forward_ws_blocks(&sender, &mut current, &health).await {
+                Ok(()) => return,
+                Err(err) => {
+                    tracing::warn!(
+                        chain_id = self.chain_id,
+                        error = ?err,
+                        backoff = ?backoff,
+                        "Websocket block subscription failed or stalled, falling back to polling"
+                    );
+                }
+            }
+            // The subscription was healthy for a while, so this is a new failure.
+            if connected_at.elapsed() > TRIGGER_RECONNECT_BACKOFF_MAX_EVM {
+                backoff = TRIGGER_RECONNECT_BACKOFF_MIN_EVM;
+            }
+            self.forward_polled_blocks(&sender, &mut current, backoff)
+                .await;
+            backoff = (backoff * 2).min(TRIGGER_RECONNECT_BACKOFF_MAX_EVM);
+        }
+    }
+}
+
 #[async_trait]
 impl AuctionManager<Evm> for Service<Evm> {
-    type Trigger = Block<H256>;
-    type TriggerStream<'a> = SubscriptionStream<'a, Ws, Block<H256>>;
-    type WsClient = Provider<Ws>;
+    /// The new block number.
+    type Trigger = u64;
+    type TriggerStream<'a> = ReceiverStream<u64>;
+    type WsClient = TriggerSourceEvm;
     type ConclusionResult = TransactionReceipt;
 
     const AUCTION_MINIMUM_LIFETIME: Duration = Duration::from_secs(1);
 
     async fn get_ws_client(&self) -> Result<Self::WsClient> {
-        let ws = Ws::connect(self.config.chain_config.ws_address.clone()).await?;
-        Ok(Provider::new(ws))
+        Ok(TriggerSourceEvm {
+            chain_id:   self.config.chain_id.clone(),
+            ws_address: self.config.chain_config.ws_address.clone(),
+            provider:   self.config.chain_config.provider.clone(),
+        })
     }
 
-    async fn get_trigger_stream<'a>(client: &'a Self::WsClient) -> Result<Self::TriggerStream<'a>> {
-        let block_stream = client.subscribe_blocks().await?;
-        Ok(block_stream)
+    async fn get_trigger_stream<'a>(
+        client: &'a Self::WsClient,
+        health: &LoopHealthRecorder,
+    ) -> Result<Self::TriggerStream<'a>> {
+        let (sender, receiver) = mpsc::channel(1);
+        // The task stops when the stream is dropped.
+        tokio::spawn(client.clone().run(sender, health.clone()));
+        Ok(ReceiverStream::new(receiver))
     }
 
     fn is_ready_to_conclude(_trigger: Self::Trigger) -> bool {
@@ -246,52 +441,128 @@ impl AuctionManager<Evm> for Service<Evm> {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to get transaction receipt: {:?}", e))?;
 
-        match receipt {
-            Some(receipt) => {
-                let decoded_logs = Self::decode_logs_for_receipt(&receipt);
-                tracing::Span::current().record("result", format!("{:?}", decoded_logs));
-                Ok(bids
+        let receipt = match receipt {
+            Some(receipt) => receipt,
+            None => {
+                // The included bids are reverted to submitted if their block is reorged out
+                let reverted_statuses: Vec<Option<entities::BidStatusEvm>> = bids
+                    .iter()
+                    .map(|b| match &b.status {
+                        entities::BidStatusEvm::Included { auction, index, .. } => {
+                            Some(entities::BidStatusEvm::Submitted {
+                                auction: auction.clone(),
+                                index:   *index,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if reverted_statuses.iter().any(|status| status.is_some()) {
+                    tracing::warn!(
+                        chain_id = self.config.chain_id,
+                        auction_id = ?bid_status_auction.id,
+                        "Auction transaction is reorged out, tracking it again"
+                    );
+                    metrics::counter!(
+                        "auction_reorgs_total",
+                        &[("chain_id", self.config.chain_id.clone())]
+                    )
+                    .increment(1);
+                }
+                return Ok(reverted_statuses);
+            }
+        };
+
+        let decoded_logs = Self::decode_logs_for_receipt(&receipt);
+        tracing::Span::current().record("result", format!("{:?}", decoded_logs));
+        let statuses = bids.iter().map(|b| {
+            match decoded_logs
+                .iter()
+                .find(|decoded_log| Uuid::from_bytes(decoded_log.bid_id) == b.id)
+            {
+                Some(decoded_log) => {
+                    match decoded_log.multicall_status.external_success {
+                        true => entities::BidStatusEvm::Won {
+                            index:   decoded_log.multicall_index.as_u32(),
+                            auction: bid_status_auction.clone(),
+                        },
+                        false =>
+                        // TODO: add BidStatusEvm::Failed for when the bid gets submitted but fails on-chain
+                        {
+                            entities::BidStatusEvm::Lost {
+                                index:   Some(decoded_log.multicall_index.as_u32()),
+                                auction: Some(bid_status_auction.clone()),
+                            }
+                        }
+                    }
+                }
+                None => entities::BidStatusEvm::Lost {
+                    auction: Some(bid_status_auction.clone()),
+                    index:   None,
+                },
+            }
+        });
+
+        let block_number = receipt.block_number.unwrap_or_default().as_u64();
+        if self.config.chain_config.confirmations > 1 {
+            let latest_block_number = self
+                .config
+                .chain_config
+                .provider
+                .get_block_number()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to get block number: {:?}", e))?
+                .as_u64();
+            let confirmations = latest_block_number.saturating_sub(block_number) + 1;
+            if confirmations < self.config.chain_config.confirmations {
+                return Ok(bids
                     .iter()
-                    .map(|b| {
-                        Some(
-                            match decoded_logs
-                                .iter()
-                                .find(|decoded_log| Uuid::from_bytes(decoded_log.bid_id) == b.id)
-                            {
-                                Some(decoded_log) => {
-                                    match decoded_log.multicall_status.external_success {
-                                        true => entities::BidStatusEvm::Won {
-                                            index:   decoded_log.multicall_index.as_u32(),
-                                            auction: bid_status_auction.clone(),
-                                        },
-                                        false =>
-                                        // TODO: add BidStatusEvm::Failed for when the bid gets submitted but fails on-chain
-                                        {
-                                            entities::BidStatusEvm::Lost {
-                                                index:   Some(decoded_log.multicall_index.as_u32()),
-                                                auction: Some(bid_status_auction.clone()),
-                                            }
-                                        }
-                                    }
-                                }
-                                None => entities::BidStatusEvm::Lost {
-                                    auction: Some(bid_status_auction.clone()),
-                                    index:   None,
-                                },
-                            },
-                        )
+                    .zip(statuses)
+                    .map(|(b, status)| {
+                        let index = match (&b.status, &status) {
+                            (entities::BidStatusEvm::Submitted { index, .. }, _)
+                            | (entities::BidStatusEvm::Included { index, .. }, _)
+                            | (_, entities::BidStatusEvm::Won { index, .. }) => *index,
+                            (_, entities::BidStatusEvm::Lost { index, .. }) => {
+                                index.unwrap_or_default()
+                            }
+                            _ => 0,
+                        };
+                        Some(entities::BidStatusEvm::Included {
+                            auction: bid_status_auction.clone(),
+                            index,
+                            won: matches!(status, entities::BidStatusEvm::Won { .. }),
+                            block_number,
+                        })
                     })
-                    .collect())
+                    .collect());
             }
-            None => Ok(vec![None; bids.len()]),
         }
+        Ok(statuses.map(Some).collect())
     }
 
+    /// The bids of a permission key wait while the submitted auction of its opportunities is not concluded.
     async fn get_submission_state(
         &self,
-        _permission_key: &entities::PermissionKey<Evm>,
+        permission_key: &entities::PermissionKey<Evm>,
     ) -> entities::SubmitType {
-        entities::SubmitType::ByServer
+        let opportunities = self
+            .opportunity_service
+            .get_live_opportunities(GetLiveOpportunitiesInput {
+                key: opportunity::entities::OpportunityKey(
+                    self.config.chain_id.clone(),
+                    permission_key.clone(),
+                ),
+            })
+            .await;
+        if opportunities
+            .iter()
+            .any(|o| o.state == opportunity::entities::OpportunityState::InAuction)
+        {
+            entities::SubmitType::InAuction
+        } else {
+            entities::SubmitType::ByServer
+        }
     }
 
     fn get_new_status(
@@ -311,6 +582,14 @@ impl AuctionManager<Evm> for Service<Evm> {
             },
         }
     }
+
+    async fn handle_bid_status_update(
+        &self,
+        _bid: &entities::Bid<Evm>,
+        _new_status: &entities::BidStatusEvm,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// This is to make sure we are not missing any transaction.
@@ -365,8 +644,10 @@ impl AuctionManager<Svm> for Service<Svm> {
             })
     }
 
+    /// The triggers are generated locally, the websocket liveness is recorded by the log listener.
     async fn get_trigger_stream<'a>(
         _client: &'a Self::WsClient,
+        _health: &LoopHealthRecorder,
     ) -> Result<Self::TriggerStream<'a>> {
         Ok(TriggerStreamSvm::new(interval(TRIGGER_DURATION_SVM)))
     }
@@ -415,9 +696,11 @@ impl AuctionManager<Svm> for Service<Svm> {
 
         let send_futures: Vec<_> = bids
             .into_iter()
-            .map(|mut bid| {
-                self.add_relayer_signature(&mut bid);
-                async move { self.send_transaction(&bid).await }
+            .map(|mut bid| async move {
+                self.add_relayer_signature(&mut bid).await?;
+                self.send_transaction(&bid)
+                    .await
+                    .map_err(|e| anyhow::anyhow!(e))
             })
             .collect();
 
@@ -431,7 +714,7 @@ impl AuctionManager<Svm> for Service<Svm> {
                 Err(e) => {
                     tracing::error!(error = ?e, "Error while submitting bid");
                     if result.is_none() {
-                        result = Some(Err(anyhow::anyhow!(e)));
+                        result = Some(Err(e));
                     }
                 }
             }
@@ -476,7 +759,9 @@ impl AuctionManager<Svm> for Service<Svm> {
             .value
             .into_iter()
             .map(|status| {
-                status.filter(|status| status.satisfies_commitment(CommitmentConfig::confirmed()))
+                status.filter(|status| {
+                    status.satisfies_commitment(self.config.chain_config.conclusion_commitment)
+                })
             })
             .collect();
 
@@ -545,30 +830,42 @@ impl AuctionManager<Svm> for Service<Svm> {
         &self,
         permission_key: &entities::PermissionKey<Svm>,
     ) -> entities::SubmitType {
-        if permission_key.0.starts_with(
-            &self
-                .config
-                .chain_config
-                .wallet_program_router_account
-                .to_bytes(),
-        ) {
-            if self
-                .opportunity_service
-                .get_live_opportunities(GetLiveOpportunitiesInput {
-                    key: opportunity::entities::OpportunityKey(
-                        self.config.chain_id.clone(),
-                        Bytes::from(permission_key.0),
-                    ),
-                })
-                .await
-                .is_empty()
-            {
-                entities::SubmitType::Invalid
-            } else {
-                entities::SubmitType::ByOther
+        let router = Pubkey::new_from_array(
+            permission_key.0[..32]
+                .try_into()
+                .expect("Permission key is 64 bytes"),
+        );
+        let integrator = self
+            .opportunity_service
+            .get_integrator_by_router(GetIntegratorByRouterInput {
+                chain_id: self.config.chain_id.clone(),
+                router,
+            })
+            .await;
+        match integrator {
+            Some(integrator) if integrator.submitter == models::IntegratorSubmitter::Integrator => {
+                let opportunities = self
+                    .opportunity_service
+                    .get_live_opportunities(GetLiveOpportunitiesInput {
+                        key: opportunity::entities::OpportunityKey(
+                            self.config.chain_id.clone(),
+                            Bytes::from(permission_key.0),
+                        ),
+                    })
+                    .await;
+                if opportunities.is_empty() {
+                    entities::SubmitType::Invalid
+                } else if opportunities
+                    .iter()
+                    .any(|o| o.state == opportunity::entities::OpportunityState::Live)
+                {
+                    entities::SubmitType::ByOther
+                } else {
+                    // The opportunity is removed by the verification loop after its auction is concluded
+                    entities::SubmitType::InAuction
+                }
             }
-        } else {
-            entities::SubmitType::ByServer
+            _ => entities::SubmitType::ByServer,
         }
     }
 
@@ -595,12 +892,38 @@ impl AuctionManager<Svm> for Service<Svm> {
             }
         }
     }
+
+    async fn handle_bid_status_update(
+        &self,
+        bid: &entities::Bid<Svm>,
+        new_status: &entities::BidStatusSvm,
+    ) -> Result<()> {
+        let status = match new_status {
+            // The quote lands when the bid result is settled if the settlement check is enabled, see finalize_bids
+            entities::BidStatusSvm::Won { .. }
+                if self.config.chain_config.settlement_commitment.is_none() =>
+            {
+                opportunity::entities::QuoteStatus::Landed
+            }
+            entities::BidStatusSvm::Won { .. } => opportunity::entities::QuoteStatus::Submitted,
+            entities::BidStatusSvm::Failed { .. } => opportunity::entities::QuoteStatus::Failed,
+            entities::BidStatusSvm::Expired { .. } => opportunity::entities::QuoteStatus::Expired,
+            _ => return Ok(()),
+        };
+        self.opportunity_service
+            .update_quote_status(UpdateQuoteStatusInput {
+                chain_id: self.config.chain_id.clone(),
+                bid_id: bid.id,
+                status,
+            })
+            .await
+    }
 }
 
 const SEND_TRANSACTION_RETRY_COUNT_SVM: i32 = 30;
 
 impl Service<Svm> {
-    pub fn add_relayer_signature(&self, bid: &mut entities::Bid<Svm>) {
+    pub async fn add_relayer_signature(&self, bid: &mut entities::Bid<Svm>) -> Result<()> {
         let relayer = &self.config.chain_config.express_relay.relayer;
         let serialized_message = bid.chain_data.transaction.message.serialize();
         let relayer_signature_pos = bid
@@ -612,7 +935,8 @@ impl Service<Svm> {
             .position(|p| p.eq(&relayer.pubkey()))
             .expect("Relayer not found in static account keys");
         bid.chain_data.transaction.signatures[relayer_signature_pos] =
-            relayer.sign_message(&serialized_message);
+            relayer.sign_message(&serialized_message).await?;
+        Ok(())
     }
 
     fn get_send_transaction_config(&self) -> RpcSendTransactionConfig {
@@ -5,8 +5,17 @@ use {
         ChainTrait,
         Service,
     },
-    crate::auction::entities::{
-        self,
+    crate::{
+        auction::entities::{
+            self,
+        },
+        opportunity::{
+            entities::{
+                OpportunityKey,
+                OpportunityState,
+            },
+            service::update_opportunities_state::UpdateOpportunitiesStateInput,
+        },
     },
     futures::future::join_all,
 };
@@ -72,6 +81,21 @@ where
                     .conclude_auction(&mut auction)
                     .await
                     .map_err(|e| anyhow::anyhow!("Failed to conclude auction: {:?}", e))?;
+                // The auction result is known, so the opportunities can be removed or auctioned again
+                let opportunity_key = OpportunityKey(
+                    self.config.chain_id.clone(),
+                    auction.permission_key.clone().into(),
+                );
+                if let Err(e) = self
+                    .opportunity_service
+                    .update_opportunities_state(UpdateOpportunitiesStateInput {
+                        key:   opportunity_key,
+                        state: OpportunityState::AuctionConcluded,
+                    })
+                    .await
+                {
+                    tracing::error!(error = ?e, "Failed to conclude the auction of the opportunities");
+                }
                 self.repo.remove_in_memory_submitted_auction(auction).await;
             }
         }
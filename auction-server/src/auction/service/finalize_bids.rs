@@ -0,0 +1,93 @@
+use {
+    super::Service,
+    crate::{
+        kernel::entities::Svm,
+        opportunity::{
+            entities::QuoteStatus,
+            service::update_quote_status::UpdateQuoteStatusInput,
+        },
+    },
+    axum_prometheus::metrics,
+    solana_sdk::commitment_config::CommitmentConfig,
+    std::time::Duration,
+    time::OffsetDateTime,
+};
+
+/// Bids which are not settled after this duration are considered dropped by a fork.
+/// They are flagged as mismatched without a finalization time.
+const SETTLEMENT_TIMEOUT: Duration = Duration::from_secs(120);
+const SETTLEMENT_BATCH_SIZE: i64 = 256;
+
+impl Service<Svm> {
+    /// Checks the won and failed bids again at the settlement commitment level.
+    /// The bids are flagged if the settled result differs from the concluded one.
+    #[tracing::instrument(skip_all)]
+    pub async fn finalize_bids(
+        &self,
+        settlement_commitment: CommitmentConfig,
+    ) -> anyhow::Result<()> {
+        let bids = self
+            .repo
+            .get_unfinalized_bids(SETTLEMENT_BATCH_SIZE)
+            .await?;
+        if bids.is_empty() {
+            return Ok(());
+        }
+
+        let signatures: Vec<_> = bids.iter().map(|bid| bid.signature).collect();
+        let statuses = self
+            .config
+            .chain_config
+            .client
+            .get_signature_statuses_with_history(&signatures)
+            .await?
+            .value;
+
+        let now = OffsetDateTime::now_utc();
+        for (bid, status) in bids.iter().zip(statuses) {
+            let settled_failed = status
+                .filter(|status| status.satisfies_commitment(settlement_commitment))
+                .map(|status| status.err.is_some());
+            let mismatch = match settled_failed {
+                Some(failed) => failed == bid.won,
+                None => {
+                    if bid.conclusion_time + SETTLEMENT_TIMEOUT > now {
+                        continue;
+                    }
+                    true
+                }
+            };
+            if mismatch {
+                tracing::error!(
+                    chain_id = self.config.chain_id,
+                    bid_id = bid.id.to_string(),
+                    signature = bid.signature.to_string(),
+                    won = bid.won,
+                    "Settled bid result differs from the concluded result"
+                );
+                metrics::counter!(
+                    "bid_settlement_mismatches_total",
+                    &[("chain_id", self.config.chain_id.clone())]
+                )
+                .increment(1);
+            }
+            self.repo
+                .update_bid_finalization(bid.id, settled_failed.is_some(), mismatch)
+                .await?;
+            if let (true, Some(failed)) = (bid.won, settled_failed) {
+                self.opportunity_service
+                    .update_quote_status(UpdateQuoteStatusInput {
+                        chain_id: self.config.chain_id.clone(),
+                        bid_id:   bid.id,
+                        status:   if failed {
+                            QuoteStatus::Failed
+                        } else {
+                            QuoteStatus::Landed
+                        },
+                    })
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
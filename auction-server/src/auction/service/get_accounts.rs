@@ -0,0 +1,60 @@
+use {
+    super::{
+        simulator::PendingAccounts,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::Svm,
+    },
+    solana_sdk::{
+        account::Account,
+        pubkey::Pubkey,
+    },
+    std::sync::Arc,
+};
+
+pub struct GetAccountsInput {
+    pub keys:             Vec<Pubkey>,
+    /// The pending accounts to reuse between the lookups. The pending transactions are applied again if not set.
+    pub pending_accounts: Option<Arc<PendingAccounts>>,
+}
+
+impl Service<Svm> {
+    /// Returns the accounts touched by the pending auction transactions as they will be after the transactions land.
+    pub async fn get_pending_accounts(&self) -> Result<Arc<PendingAccounts>, RestError> {
+        self.config
+            .chain_config
+            .simulator
+            .get_pending_accounts()
+            .await
+            .map(|response| Arc::new(response.value))
+            .map_err(|e| {
+                tracing::error!(error = ?e, "Failed to get pending accounts");
+                RestError::TemporarilyUnavailable
+            })
+    }
+
+    /// Returns the accounts as they will be after the pending auction transactions land.
+    pub async fn get_accounts(
+        &self,
+        input: GetAccountsInput,
+    ) -> Result<Vec<Option<Account>>, RestError> {
+        let simulator = &self.config.chain_config.simulator;
+        let accounts = match input.pending_accounts {
+            Some(pending_accounts) => {
+                simulator
+                    .get_accounts_with_pending_accounts(&pending_accounts, &input.keys)
+                    .await
+            }
+            None => simulator
+                .get_accounts_with_pending_txs(&input.keys)
+                .await
+                .map(|response| response.value),
+        };
+        accounts.map_err(|e| {
+            tracing::error!(error = ?e, "Failed to get accounts");
+            RestError::TemporarilyUnavailable
+        })
+    }
+}
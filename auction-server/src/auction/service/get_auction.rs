@@ -0,0 +1,23 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+pub struct GetAuctionInput {
+    pub auction_id: entities::AuctionId,
+}
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn get_auction(
+        &self,
+        input: GetAuctionInput,
+    ) -> Result<entities::Auction<T>, RestError> {
+        self.repo.get_auction(input.auction_id).await
+    }
+}
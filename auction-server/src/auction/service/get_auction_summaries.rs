@@ -0,0 +1,26 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+pub struct GetAuctionSummariesInput {
+    pub bid_ids: Vec<entities::BidId>,
+}
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn get_auction_summaries(
+        &self,
+        input: GetAuctionSummariesInput,
+    ) -> Result<Vec<entities::BidAuctionSummary<T>>, RestError> {
+        if input.bid_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        self.repo.get_auction_summaries(input.bid_ids).await
+    }
+}
@@ -0,0 +1,24 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+pub struct GetAuctionsInput<T: ChainTrait> {
+    pub filter: entities::AuctionFilter<T>,
+    pub limit:  usize,
+}
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn get_auctions(
+        &self,
+        input: GetAuctionsInput<T>,
+    ) -> Result<Vec<entities::Auction<T>>, RestError> {
+        self.repo.get_auctions(input.filter, input.limit).await
+    }
+}
@@ -6,18 +6,19 @@ use {
     crate::{
         api::RestError,
         auction::entities,
-        models::Profile,
     },
-    time::OffsetDateTime,
 };
 
-pub struct GetBidsInput {
-    pub profile:   Profile,
-    pub from_time: Option<OffsetDateTime>,
+pub struct GetBidsInput<T: ChainTrait> {
+    pub filter: entities::BidFilter<T>,
+    pub limit:  usize,
 }
 
 impl<T: ChainTrait> Service<T> {
-    pub async fn get_bids(&self, input: GetBidsInput) -> Result<Vec<entities::Bid<T>>, RestError> {
-        self.repo.get_bids(input.profile.id, input.from_time).await
+    pub async fn get_bids(
+        &self,
+        input: GetBidsInput<T>,
+    ) -> Result<entities::BidPage<T>, RestError> {
+        self.repo.get_bids(input.filter, input.limit).await
     }
 }
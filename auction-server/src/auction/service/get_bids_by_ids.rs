@@ -0,0 +1,31 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+        models::ProfileId,
+    },
+};
+
+pub struct GetBidsByIdsInput {
+    pub bid_ids:    Vec<entities::BidId>,
+    /// The owner of the bids, if the bids of other profiles should not be returned.
+    pub profile_id: Option<ProfileId>,
+}
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn get_bids_by_ids(
+        &self,
+        input: GetBidsByIdsInput,
+    ) -> Result<Vec<entities::Bid<T>>, RestError> {
+        if input.bid_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        self.repo
+            .get_bids_by_ids(input.bid_ids, input.profile_id)
+            .await
+    }
+}
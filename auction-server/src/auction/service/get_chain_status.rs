@@ -0,0 +1,13 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::auction::entities,
+};
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn get_chain_status(&self) -> entities::ChainStatus {
+        self.repo.get_chain_status().await
+    }
+}
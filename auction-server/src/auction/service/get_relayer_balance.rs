@@ -0,0 +1,24 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::auction::entities,
+};
+
+impl<T: ChainTrait> Service<T> {
+    /// Returns the latest tracked relayer balance, None if the balance is not fetched yet.
+    pub async fn get_relayer_balance(&self) -> Option<entities::RelayerBalance> {
+        self.relayer_balance.read().await.clone()
+    }
+
+    /// The relayer is considered available until its balance is known to be critical.
+    pub async fn is_relayer_available(&self) -> bool {
+        self.get_relayer_balance()
+            .await
+            .map(|relayer_balance| {
+                relayer_balance.status != entities::RelayerBalanceStatus::Critical
+            })
+            .unwrap_or(true)
+    }
+}
@@ -4,12 +4,21 @@ use {
         ChainTrait,
         Service,
     },
-    crate::auction::{
-        entities::{
-            self,
-            BidStatus,
+    crate::{
+        auction::{
+            entities::{
+                self,
+                BidStatus,
+            },
+            service::update_bid_status::UpdateBidStatusInput,
+        },
+        opportunity::{
+            entities::{
+                OpportunityKey,
+                OpportunityState,
+            },
+            service::update_opportunities_state::UpdateOpportunitiesStateInput,
         },
-        service::update_bid_status::UpdateBidStatusInput,
     },
     futures::future::join_all,
     time::OffsetDateTime,
@@ -61,6 +70,40 @@ where
             return Ok(());
         }
 
+        // The opportunities of the permission key can not be removed until the auction is concluded
+        let opportunity_key = OpportunityKey(self.config.chain_id.clone(), permission_key.into());
+        self.opportunity_service
+            .update_opportunities_state(UpdateOpportunitiesStateInput {
+                key:   opportunity_key.clone(),
+                state: OpportunityState::InAuction,
+            })
+            .await?;
+
+        let result = self.submit_winner_bids(auction, winner_bids).await;
+        // The opportunities stay in the auction until the submitted transaction is concluded,
+        // and can be auctioned again right away if the submission failed
+        if !matches!(result, Ok(true)) {
+            if let Err(e) = self
+                .opportunity_service
+                .update_opportunities_state(UpdateOpportunitiesStateInput {
+                    key:   opportunity_key,
+                    state: OpportunityState::Live,
+                })
+                .await
+            {
+                tracing::error!(error = ?e, "Failed to move the opportunities back to live");
+            }
+        }
+        result.map(|_| ())
+    }
+
+    /// Submits the winner bids of the auction and returns whether the submission succeeded.
+    async fn submit_winner_bids(
+        &self,
+        auction: entities::Auction<T>,
+        winner_bids: Vec<entities::Bid<T>>,
+    ) -> anyhow::Result<bool> {
+        let permission_key = auction.permission_key.clone();
         let auction = self.repo.add_auction(auction).await?;
         tracing::info!(
             auction = ?auction,
@@ -89,12 +132,13 @@ where
                     })
                 }))
                 .await;
+                Ok(true)
             }
             Err(err) => {
                 tracing::error!("Transaction failed to submit: {:?}", err);
+                Ok(false)
             }
-        };
-        Ok(())
+        }
     }
 
     #[tracing::instrument(skip_all, fields(bid_ids, auction_id))]
@@ -133,7 +177,7 @@ where
         );
         let permission_key = input.permission_key;
         match self.get_submission_state(&permission_key).await {
-            entities::SubmitType::ByOther => Ok(()),
+            entities::SubmitType::ByOther | entities::SubmitType::InAuction => Ok(()),
             entities::SubmitType::ByServer => {
                 let auction_lock = self
                     .repo
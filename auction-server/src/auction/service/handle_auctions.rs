@@ -12,6 +12,14 @@ where
     Service<T>: AuctionManager<T>,
 {
     pub async fn handle_auctions(&self) {
+        if !self.is_relayer_available().await {
+            tracing::warn!(
+                chain_id = self.config.chain_id,
+                "Skipping auctions submission as the relayer balance is critical",
+            );
+            return;
+        }
+
         let permission_keys = self.get_permission_keys_for_auction().await;
 
         for permission_key in permission_keys.into_iter() {
@@ -26,6 +26,12 @@ where
         &self,
         input: HandleBidInput<T>,
     ) -> Result<entities::Bid<T>, RestError> {
+        if !self.get_chain_status().await.accepts_bids() {
+            return Err(RestError::ChainUnderMaintenance);
+        }
+        if !self.is_relayer_available().await {
+            return Err(RestError::RelayerUnavailable);
+        }
         let (chain_data, amount) = self
             .verify_bid(VerifyBidInput {
                 bid_create: input.bid_create.clone(),
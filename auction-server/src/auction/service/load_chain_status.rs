@@ -0,0 +1,21 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::auction::entities,
+};
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn load_chain_status(&self) -> anyhow::Result<()> {
+        let status = self.repo.load_chain_status().await?;
+        if status != entities::ChainStatus::Active {
+            tracing::warn!(
+                chain_id = self.config.chain_id,
+                status = ?status,
+                "Chain is not active since the last restart",
+            );
+        }
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ use {
     crate::{
         api::ws::UpdateEvent,
         auction::service::simulator::Simulator,
+        config::RelayerBalanceThresholds,
         kernel::{
             contracts::{
                 LegacyTxTransformer,
@@ -20,12 +21,15 @@ use {
                 Evm,
                 Svm,
             },
+            signer::{
+                RelayerSignerEvm,
+                RelayerSignerSvm,
+            },
             traced_client::TracedClient,
         },
         opportunity::service as opportunity_service,
     },
     ethers::{
-        core::k256::ecdsa::SigningKey,
         middleware::{
             gas_oracle::GasOracleMiddleware,
             NonceManagerMiddleware,
@@ -33,11 +37,7 @@ use {
             TransformerMiddleware,
         },
         providers::Provider,
-        signers::{
-            LocalWallet,
-            Signer,
-            Wallet,
-        },
+        signers::Signer,
         types::{
             Address,
             U256,
@@ -52,16 +52,22 @@ use {
         },
     },
     solana_sdk::{
+        commitment_config::CommitmentConfig,
         pubkey::Pubkey,
-        signature::Keypair,
     },
     std::{
-        fmt::Debug,
+        fmt::{
+            Debug,
+            Display,
+        },
         sync::Arc,
     },
-    tokio::sync::broadcast::{
-        self,
-        Sender,
+    tokio::sync::{
+        broadcast::{
+            self,
+            Sender,
+        },
+        RwLock,
     },
     tokio_util::task::TaskTracker,
 };
@@ -70,41 +76,55 @@ pub mod add_auction;
 pub mod auction_manager;
 pub mod conclude_auction;
 pub mod conclude_auctions;
+pub mod finalize_bids;
+pub mod get_accounts;
+pub mod get_auction;
+pub mod get_auction_summaries;
+pub mod get_auctions;
 pub mod get_bid;
 pub mod get_bids;
+pub mod get_bids_by_ids;
+pub mod get_chain_status;
 pub mod get_live_bids;
 pub mod get_permission_keys_for_auction;
+pub mod get_relayer_balance;
 pub mod handle_auction;
 pub mod handle_auctions;
 pub mod handle_bid;
+pub mod load_chain_status;
 pub mod simulator;
 pub mod update_bid_status;
+pub mod update_chain_status;
 pub mod update_recent_prioritization_fee;
+pub mod update_relayer_balance;
 pub mod update_submitted_auction;
 pub mod verification;
 pub mod workers;
 
 pub struct ExpressRelaySvm {
     pub program_id:                  Pubkey,
-    pub relayer:                     Keypair,
+    pub relayer:                     RelayerSignerSvm,
     pub permission_account_position: usize,
     pub router_account_position:     usize,
 }
 
 pub struct ConfigSvm {
     pub client:                        RpcClient,
-    pub wallet_program_router_account: Pubkey,
     pub express_relay:                 ExpressRelaySvm,
     pub simulator:                     Simulator,
     pub ws_address:                    String,
     pub tx_broadcaster_client:         RpcClient,
     pub log_sender:                    Sender<Response<RpcLogsResponse>>,
     pub prioritization_fee_percentile: Option<u64>,
+    /// Commitment level at which the bid results are concluded.
+    pub conclusion_commitment:         CommitmentConfig,
+    /// Commitment level at which the concluded bid results are checked again, if enabled.
+    pub settlement_commitment:         Option<CommitmentConfig>,
 }
 
 pub struct ExpressRelayEvm {
     pub contract_address: Address,
-    pub relayer:          Wallet<SigningKey>,
+    pub relayer:          RelayerSignerEvm,
     pub contract:         SignableExpressRelayContract,
 }
 
@@ -115,12 +135,14 @@ pub struct ConfigEvm {
     pub block_gas_limit: U256,
     pub oracle:          GasOracleType,
     pub ws_address:      String,
+    /// Number of blocks the auction transaction should be deep in before the bid results are final.
+    pub confirmations:   u64,
 }
 
 pub fn get_express_relay_contract(
     address: Address,
     provider: Provider<TracedClient>,
-    relayer: LocalWallet,
+    relayer: RelayerSignerEvm,
     use_legacy_tx: bool,
     network_id: u64,
 ) -> SignableExpressRelayContract {
@@ -140,12 +162,13 @@ pub fn get_express_relay_contract(
 
 impl ConfigEvm {
     pub fn new(
-        relayer: Wallet<SigningKey>,
+        relayer: RelayerSignerEvm,
         contract_address: Address,
         provider: Provider<TracedClient>,
         block_gas_limit: U256,
         ws_address: String,
         network_id: u64,
+        confirmations: u64,
     ) -> Self {
         Self {
             express_relay: ExpressRelayEvm {
@@ -163,12 +186,14 @@ impl ConfigEvm {
             oracle: GasOracleType::new(provider.clone()),
             provider,
             ws_address,
+            confirmations,
         }
     }
 }
 
 pub struct Config<T> {
-    pub chain_id: ChainId,
+    pub chain_id:                   ChainId,
+    pub relayer_balance_thresholds: RelayerBalanceThresholds,
 
     pub chain_config: T,
 }
@@ -181,7 +206,7 @@ pub trait ChainTrait:
 
     type BidStatusType: entities::BidStatus;
     type BidChainDataType: entities::BidChainData;
-    type BidAmountType: Send + Sync + Debug + Clone + PartialEq;
+    type BidAmountType: Send + Sync + Debug + Display + Clone + PartialEq;
     type BidChainDataCreateType: Clone + Debug + Send + Sync;
 
     type ChainStore: Send + Sync + Default + Debug;
@@ -217,6 +242,7 @@ pub struct ServiceInner<T: ChainTrait> {
     repo:                Arc<Repository<T>>,
     task_tracker:        TaskTracker,
     event_sender:        broadcast::Sender<UpdateEvent>,
+    relayer_balance:     RwLock<Option<entities::RelayerBalance>>,
 }
 
 #[derive(Clone)]
@@ -242,6 +268,7 @@ impl<T: ChainTrait> Service<T> {
             opportunity_service,
             task_tracker,
             event_sender,
+            relayer_balance: RwLock::new(None),
         }))
     }
 }
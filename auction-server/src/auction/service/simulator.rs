@@ -46,10 +46,18 @@ use {
 
 pub struct Simulator {
     receiver:      RpcClient,
+    /// Commitment level of the accounts loaded for the simulations.
+    commitment:    CommitmentConfig,
     pending_txs:   RwLock<Vec<(VersionedTransaction, Instant)>>,
     account_cache: RwLock<HashMap<Pubkey, (Account, Instant)>>,
 }
 
+/// The accounts touched by the pending transactions, as they will be after the transactions land.
+/// The accounts which do not exist after applying the pending transactions are kept as None.
+pub struct PendingAccounts {
+    accounts: HashMap<Pubkey, Option<Account>>,
+}
+
 struct AccountsConfig {
     accounts:            HashMap<Pubkey, Account>,
     programs:            HashMap<Pubkey, Account>,
@@ -94,9 +102,10 @@ const MAX_PENDING_DURATION: Duration = Duration::seconds(15);
 const ACCOUNT_CACHE_DURATION: Duration = Duration::hours(1);
 
 impl Simulator {
-    pub fn new(receiver: RpcClient) -> Self {
+    pub fn new(receiver: RpcClient, commitment: CommitmentConfig) -> Self {
         Self {
             receiver,
+            commitment,
             pending_txs: Default::default(),
             account_cache: Default::default(),
         }
@@ -184,7 +193,7 @@ impl Simulator {
         // Process chunks in parallel
         let chunk_results = join_all(key_chunks.into_iter().map(|chunk| {
             self.receiver
-                .get_multiple_accounts_with_commitment(chunk, CommitmentConfig::processed())
+                .get_multiple_accounts_with_commitment(chunk, self.commitment)
         }))
         .await;
         for chunk_result in chunk_results {
@@ -353,6 +362,85 @@ impl Simulator {
         })
     }
 
+    /// Applies the pending transactions on top of the current state of the chain
+    /// and keeps the accounts they touch, so that it can be reused for multiple account lookups.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_pending_accounts(&self) -> RpcResult<PendingAccounts> {
+        let pending_txs = self.fetch_pending_and_remove_old_txs().await;
+        let accounts_config_with_context = self.fetch_tx_accounts_via_rpc(&pending_txs).await?;
+        let mut keys = pending_txs
+            .iter()
+            .flat_map(|tx| tx.message.static_account_keys())
+            .cloned()
+            .collect::<HashSet<_>>();
+        keys.extend(self.resolve_lookup_addresses(&pending_txs).await?);
+
+        let mut svm = self.setup_lite_svm(&accounts_config_with_context);
+        pending_txs.into_iter().for_each(|tx| {
+            let _ = svm.send_transaction(tx);
+        });
+        Ok(Response {
+            value:   PendingAccounts {
+                accounts: keys
+                    .into_iter()
+                    .map(|key| {
+                        (
+                            key,
+                            svm.get_account(&key).filter(|account| account.lamports > 0),
+                        )
+                    })
+                    .collect(),
+            },
+            context: accounts_config_with_context.context,
+        })
+    }
+
+    /// Gets the accounts as they will be after the pending transactions land.
+    /// The accounts which are not touched by the pending transactions are fetched from the chain.
+    /// The accounts which do not exist after applying the pending transactions are returned as None.
+    pub async fn get_accounts_with_pending_accounts(
+        &self,
+        pending_accounts: &PendingAccounts,
+        keys: &[Pubkey],
+    ) -> client_error::Result<Vec<Option<Account>>> {
+        let missing_keys = keys
+            .iter()
+            .filter(|key| !pending_accounts.accounts.contains_key(key))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut missing_accounts = HashMap::new();
+        if !missing_keys.is_empty() {
+            let accounts = self.get_multiple_accounts_chunked(&missing_keys).await?;
+            missing_accounts.extend(missing_keys.into_iter().zip(accounts.value));
+        }
+        Ok(keys
+            .iter()
+            .map(|key| {
+                pending_accounts
+                    .accounts
+                    .get(key)
+                    .or_else(|| missing_accounts.get(key))
+                    .cloned()
+                    .flatten()
+            })
+            .collect())
+    }
+
+    /// Gets the accounts with the pending transactions applied on top of the current state of the chain.
+    /// The accounts which do not exist after applying the pending transactions are returned as None.
+    pub async fn get_accounts_with_pending_txs(
+        &self,
+        keys: &[Pubkey],
+    ) -> RpcResult<Vec<Option<Account>>> {
+        let pending_accounts = self.get_pending_accounts().await?;
+        Ok(Response {
+            value:   self
+                .get_accounts_with_pending_accounts(&pending_accounts.value, keys)
+                .await?,
+            context: pending_accounts.context,
+        })
+    }
+
     /// Given a list of bids, tries to find the optimal set of bids that can be submitted to the chain
     /// considering the current state of the chain and the pending transactions.
     /// Right now, for simplicity, the method assume the bids are sorted, and tries to submit them in order
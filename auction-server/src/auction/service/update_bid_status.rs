@@ -1,5 +1,6 @@
 use {
     super::{
+        auction_manager::AuctionManager,
         ChainTrait,
         Service,
     },
@@ -20,7 +21,10 @@ pub struct UpdateBidStatusInput<T: ChainTrait> {
     pub new_status: T::BidStatusType,
 }
 
-impl<T: ChainTrait> Service<T> {
+impl<T: ChainTrait> Service<T>
+where
+    Service<T>: AuctionManager<T>,
+{
     #[tracing::instrument(skip_all, fields(bid_id, status))]
     pub async fn update_bid_status(&self, input: UpdateBidStatusInput<T>) -> Result<(), RestError> {
         tracing::Span::current().record("bid_id", input.bid.id.to_string());
@@ -42,6 +46,12 @@ impl<T: ChainTrait> Service<T> {
         // Or the new block is mined faster than the bid status is updated.
         // To ensure we do not broadcast the update more than once, we need to check the below "if"
         if is_updated {
+            if let Err(e) = self
+                .handle_bid_status_update(&input.bid, &input.new_status)
+                .await
+            {
+                tracing::error!(error = ?e, "Failed to handle bid status update");
+            }
             // TODO remove this line and move BidStatusWithId somewhere else
             if let Err(e) = self
                 .event_sender
@@ -0,0 +1,35 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::entities,
+    },
+};
+
+pub struct UpdateChainStatusInput {
+    pub status: entities::ChainStatus,
+}
+
+impl<T: ChainTrait> Service<T> {
+    pub async fn update_chain_status(
+        &self,
+        input: UpdateChainStatusInput,
+    ) -> Result<(), RestError> {
+        self.repo
+            .update_chain_status(input.status)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "Failed to update chain status");
+                RestError::TemporarilyUnavailable
+            })?;
+        tracing::info!(
+            chain_id = self.config.chain_id,
+            status = ?input.status,
+            "Chain status updated",
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use {
+    super::{
+        ChainTrait,
+        Service,
+    },
+    crate::{
+        api::ws::UpdateEvent,
+        auction::{
+            api::RelayerStatusUpdate,
+            entities,
+        },
+    },
+    axum_prometheus::metrics,
+    time::OffsetDateTime,
+};
+
+pub struct UpdateRelayerBalanceInput {
+    pub address: String,
+    /// The balance in the native token of the chain.
+    pub balance: f64,
+}
+
+impl<T: ChainTrait> Service<T> {
+    fn get_relayer_balance_status(&self, balance: f64) -> entities::RelayerBalanceStatus {
+        let thresholds = &self.config.relayer_balance_thresholds;
+        if thresholds
+            .critical
+            .is_some_and(|critical| balance < critical)
+        {
+            entities::RelayerBalanceStatus::Critical
+        } else if thresholds.low.is_some_and(|low| balance < low) {
+            entities::RelayerBalanceStatus::Low
+        } else {
+            entities::RelayerBalanceStatus::Healthy
+        }
+    }
+
+    pub async fn update_relayer_balance(&self, input: UpdateRelayerBalanceInput) {
+        let status = self.get_relayer_balance_status(input.balance);
+        let label = [
+            ("chain_id", self.config.chain_id.clone()),
+            ("address", input.address.clone()),
+        ];
+        metrics::gauge!("relayer_balance", &label).set(input.balance);
+        metrics::gauge!("relayer_balance_critical", &label).set(
+            if status == entities::RelayerBalanceStatus::Critical {
+                1.0
+            } else {
+                0.0
+            },
+        );
+
+        let previous_status = self
+            .relayer_balance
+            .write()
+            .await
+            .replace(entities::RelayerBalance {
+                address: input.address.clone(),
+                balance: input.balance,
+                status,
+                update_time: OffsetDateTime::now_utc(),
+            })
+            .map(|relayer_balance| relayer_balance.status)
+            .unwrap_or(entities::RelayerBalanceStatus::Healthy);
+        if previous_status == status {
+            return;
+        }
+
+        match status {
+            entities::RelayerBalanceStatus::Critical => tracing::error!(
+                chain_id = self.config.chain_id,
+                address = input.address,
+                balance = input.balance,
+                "Relayer balance is critical, auction submission is paused"
+            ),
+            entities::RelayerBalanceStatus::Low => tracing::warn!(
+                chain_id = self.config.chain_id,
+                address = input.address,
+                balance = input.balance,
+                "Relayer balance is low"
+            ),
+            entities::RelayerBalanceStatus::Healthy => tracing::info!(
+                chain_id = self.config.chain_id,
+                address = input.address,
+                balance = input.balance,
+                "Relayer balance is healthy again"
+            ),
+        }
+
+        if let Err(e) =
+            self.event_sender
+                .send(UpdateEvent::RelayerStatusUpdate(RelayerStatusUpdate {
+                    chain_id: self.config.chain_id.clone(),
+                    status:   status.into(),
+                }))
+        {
+            tracing::error!(error = ?e, "Failed to send relayer status update");
+        }
+    }
+}
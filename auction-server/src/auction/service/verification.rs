@@ -66,7 +66,6 @@ use {
         instruction::CompiledInstruction,
         pubkey::Pubkey,
         signature::Signature,
-        signer::Signer as _,
         transaction::VersionedTransaction,
     },
     std::{
@@ -95,6 +94,38 @@ pub trait Verification<T: ChainTrait> {
     ) -> Result<VerificationResult<T>, RestError>;
 }
 
+impl<T: ChainTrait> Service<T> {
+    /// Verify that the bid owner can bid on the opportunities of the permission key,
+    /// as only the allowlisted searchers can bid during the exclusivity window of an opportunity.
+    async fn verify_exclusivity(
+        &self,
+        bid: &entities::BidCreate<T>,
+        permission_key: PermissionKey,
+    ) -> Result<(), RestError> {
+        let profile_id = bid.profile.as_ref().map(|profile| profile.id);
+        let opportunities = self
+            .opportunity_service
+            .get_live_opportunities(GetLiveOpportunitiesInput {
+                key: opportunity::entities::OpportunityKey(bid.chain_id.clone(), permission_key),
+            })
+            .await;
+        match opportunities
+            .iter()
+            .find(|opportunity| !opportunity.is_open_to(profile_id))
+        {
+            Some(opportunity) => Err(RestError::BadParameters(format!(
+                "The opportunity is exclusive to other searchers until {}",
+                opportunity
+                    .exclusivity
+                    .as_ref()
+                    .map_or(OffsetDateTime::now_utc(), |exclusivity| exclusivity
+                        .end_time)
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Service<Evm> {
     pub fn get_simulation_call(
         &self,
@@ -186,6 +217,8 @@ impl Verification<Evm> for Service<Evm> {
         input: VerifyBidInput<Evm>,
     ) -> Result<VerificationResult<Evm>, RestError> {
         let bid = input.bid_create;
+        self.verify_exclusivity(&bid, bid.chain_data.permission_key.clone())
+            .await?;
         let call = self.get_simulation_call(
             bid.chain_data.permission_key.clone(),
             vec![MulticallData::from((
@@ -435,7 +468,7 @@ impl Service<Svm> {
         let minimum_bid_life_time = match self.get_submission_state(permission_key).await {
             entities::SubmitType::ByServer => Some(BID_MINIMUM_LIFE_TIME_SVM_SERVER),
             entities::SubmitType::ByOther => Some(BID_MINIMUM_LIFE_TIME_SVM_OTHER),
-            entities::SubmitType::Invalid => None,
+            entities::SubmitType::InAuction | entities::SubmitType::Invalid => None,
         };
 
         match minimum_bid_life_time {
@@ -542,13 +575,14 @@ impl Service<Svm> {
         let accounts = chain_data.transaction.message.static_account_keys();
         let permission_key = chain_data.get_permission_key();
         match self.get_submission_state(&permission_key).await {
-            entities::SubmitType::Invalid => {
-                // TODO Look at the todo comment in get_quote.rs file in opportunity module
-                Err(RestError::BadParameters(format!(
-                    "The permission key is not valid for auction anymore: {:?}",
-                    permission_key
-                )))
-            }
+            entities::SubmitType::Invalid => Err(RestError::BadParameters(format!(
+                "The permission key is not valid for auction anymore: {:?}",
+                permission_key
+            ))),
+            entities::SubmitType::InAuction => Err(RestError::BadParameters(format!(
+                "The permission key is in an auction: {:?}",
+                permission_key
+            ))),
             entities::SubmitType::ByOther => {
                 let opportunities = self
                     .opportunity_service
@@ -673,6 +707,8 @@ impl Verification<Svm> for Service<Svm> {
         let permission_key = bid_chain_data.get_permission_key();
         self.check_deadline(&permission_key, bid_data.deadline)
             .await?;
+        self.verify_exclusivity(&bid, PermissionKey::from(permission_key.0))
+            .await?;
         self.verify_signatures(&bid, &bid_chain_data).await?;
         // TODO we should verify that the wallet bids also include another instruction to the swap program with the appropriate accounts and fields
         self.simulate_bid(&bid).await?;
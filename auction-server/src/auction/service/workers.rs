@@ -8,11 +8,17 @@ use {
         api::ws::UpdateEvent,
         auction::{
             api::SvmChainUpdate,
-            service::conclude_auction::ConcludeAuctionInput,
+            service::{
+                conclude_auction::ConcludeAuctionInput,
+                update_relayer_balance::UpdateRelayerBalanceInput,
+            },
         },
-        kernel::entities::{
-            Evm,
-            Svm,
+        kernel::{
+            entities::{
+                Evm,
+                Svm,
+            },
+            health::LoopHealthRecorder,
         },
         server::{
             EXIT_CHECK_INTERVAL,
@@ -23,7 +29,6 @@ use {
         anyhow,
         Result,
     },
-    axum_prometheus::metrics,
     ethers::providers::Middleware,
     solana_client::rpc_config::{
         RpcTransactionLogsConfig,
@@ -46,7 +51,7 @@ impl<T: ChainTrait> Service<T>
 where
     Service<T>: AuctionManager<T>,
 {
-    pub async fn run_submission_loop(&self) -> Result<()> {
+    pub async fn run_submission_loop(&self, health: LoopHealthRecorder) -> Result<()> {
         tracing::info!(
             chain_id = self.config.chain_id,
             "Starting transaction submitter..."
@@ -54,27 +59,32 @@ where
         let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
 
         let ws_client = self.get_ws_client().await?;
-        let mut stream = Service::get_trigger_stream(&ws_client).await?;
+        let mut stream = Service::get_trigger_stream(&ws_client, &health).await?;
 
         while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
             tokio::select! {
                 trigger = stream.next() => {
                     let trigger = trigger.ok_or(anyhow!("Trigger stream ended for chain: {}", self.config.chain_id))?;
                     tracing::debug!(chain_id = self.config.chain_id, time = ?OffsetDateTime::now_utc(), trigger = ?trigger, "New trigger received");
-                    self.task_tracker.spawn({
-                        let service = self.clone();
-                        async move {
-                            service.handle_auctions().await;
-                        }
-                    });
-
-                    if Service::is_ready_to_conclude(trigger) {
+                    if self.get_chain_status().await.processes_auctions() {
                         self.task_tracker.spawn({
                             let service = self.clone();
                             async move {
-                                service.conclude_auctions().await;
+                                service.handle_auctions().await;
                             }
                         });
+
+                        if Service::is_ready_to_conclude(trigger) {
+                            self.task_tracker.spawn({
+                                let service = self.clone();
+                                async move {
+                                    service.conclude_auctions().await;
+                                }
+                            });
+                        }
+                    } else {
+                        tracing::debug!(chain_id = self.config.chain_id, "Chain is paused, skipping the trigger");
                     }
                 }
                 _ = exit_check_interval.tick() => {}
@@ -86,7 +96,7 @@ where
 }
 
 impl Service<Evm> {
-    pub async fn run_tracker_loop(&self) -> Result<()> {
+    pub async fn run_tracker_loop(&self, health: LoopHealthRecorder) -> Result<()> {
         tracing::info!(chain_id = self.config.chain_id, "Starting tracker...");
 
         let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
@@ -100,6 +110,7 @@ impl Service<Evm> {
             .contract
             .get_relayer_address();
         while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
             tokio::select! {
                 _ = submission_interval.tick() => {
                     match self.config.chain_config.provider.get_balance(relayer_address, None).await {
@@ -109,11 +120,10 @@ impl Service<Evm> {
                             // The f64 conversion is made to be able to serve metrics within the constraints of Prometheus.
                             // The balance is in wei, so we need to divide by 1e18 to convert it to eth.
                             let balance = r.as_u128() as f64 / 1e18;
-                            let label = [
-                                ("chain_id", self.config.chain_id.clone()),
-                                ("address", format!("{:?}", relayer_address)),
-                            ];
-                            metrics::gauge!("relayer_balance", &label).set(balance);
+                            self.update_relayer_balance(UpdateRelayerBalanceInput {
+                                address: format!("{:?}", relayer_address),
+                                balance,
+                            }).await;
                         }
                         Err(e) => {
                             tracing::error!("Error while getting balance. error: {:?}", e);
@@ -130,9 +140,40 @@ impl Service<Evm> {
 }
 
 const GET_LATEST_BLOCKHASH_INTERVAL_SVM: Duration = Duration::from_secs(5);
+const GET_RELAYER_BALANCE_INTERVAL_SVM: Duration = Duration::from_secs(10);
 
 impl Service<Svm> {
-    pub async fn run_auction_conclusion_loop(&self) -> Result<()> {
+    pub async fn run_tracker_loop(&self, health: LoopHealthRecorder) -> Result<()> {
+        tracing::info!(chain_id = self.config.chain_id, "Starting tracker...");
+
+        let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
+        let mut balance_interval = tokio::time::interval(GET_RELAYER_BALANCE_INTERVAL_SVM);
+        let relayer_pubkey = self.config.chain_config.express_relay.relayer.pubkey();
+        while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
+            tokio::select! {
+                _ = balance_interval.tick() => {
+                    match self.config.chain_config.client.get_balance(&relayer_pubkey).await {
+                        Ok(lamports) => {
+                            // The balance is in lamports, so we need to divide by 1e9 to convert it to sol.
+                            self.update_relayer_balance(UpdateRelayerBalanceInput {
+                                address: relayer_pubkey.to_string(),
+                                balance: lamports as f64 / 1e9,
+                            }).await;
+                        }
+                        Err(e) => {
+                            tracing::error!(error = ?e, "Error while getting relayer balance");
+                        }
+                    };
+                }
+                _ = exit_check_interval.tick() => {}
+            }
+        }
+        tracing::info!("Shutting down tracker...");
+        Ok(())
+    }
+
+    pub async fn run_auction_conclusion_loop(&self, health: LoopHealthRecorder) -> Result<()> {
         tracing::info!(
             chain_id = self.config.chain_id,
             "Starting auction conclusion..."
@@ -140,6 +181,7 @@ impl Service<Svm> {
         let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
         let mut stream = self.config.chain_config.log_sender.subscribe();
         while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
             tokio::select! {
                 rpc_log = stream.recv() => {
                     match rpc_log {
@@ -151,6 +193,10 @@ impl Service<Svm> {
                                 log = ?rpc_log.clone(),
                                 "New log trigger received",
                             );
+                            if !self.get_chain_status().await.processes_auctions() {
+                                tracing::debug!(chain_id = self.config.chain_id, "Chain is paused, skipping the log trigger");
+                                continue;
+                            }
                             if let Ok(signature) = Signature::from_str(&rpc_log.value.signature){
                             self.task_tracker.spawn({
                                 let service = self.clone();
@@ -177,7 +223,7 @@ impl Service<Svm> {
         Ok(())
     }
 
-    pub async fn run_log_listener_loop(&self) -> Result<()> {
+    pub async fn run_log_listener_loop(&self, health: LoopHealthRecorder) -> Result<()> {
         let chain_id = self.config.chain_id.clone();
         tracing::info!(chain_id = chain_id, "Starting log listener...");
         let ws_client = self.get_ws_client().await?;
@@ -190,19 +236,33 @@ impl Service<Svm> {
                     .program_id
                     .to_string()]),
                 RpcTransactionLogsConfig {
-                    commitment: Some(CommitmentConfig::confirmed()),
+                    commitment: Some(self.config.chain_config.conclusion_commitment),
                 },
             )
             .await
             .unwrap();
+        // The slot updates keep the websocket liveness recorded when no express relay transaction lands for a while.
+        let (mut slot_stream, _) = ws_client.slot_subscribe().await?;
         let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
         while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
             tokio::select! {
+                slot = slot_stream.next() => {
+                    match slot {
+                        None => return Err(anyhow!("Slot stream ended for chain: {}", &chain_id)),
+                        Some(_) => health.record_ws_event(&chain_id),
+                    }
+                }
                 rpc_log = stream.next() => {
                     match rpc_log {
                         None => return Err(anyhow!("Log trigger stream ended for chain: {}", &chain_id)),
                         Some(rpc_log) => {
+                            health.record_ws_event(&chain_id);
                             tracing::debug!("New log trigger received for {} at {}: {:?}", &chain_id, OffsetDateTime::now_utc(), rpc_log.clone());
+                            if !self.get_chain_status().await.processes_auctions() {
+                                tracing::debug!(chain_id = chain_id, "Chain is paused, skipping the log trigger");
+                                continue;
+                            }
                                 if let Err(e) = self.config.chain_config.log_sender.send(rpc_log) {
                                     tracing::error!(error = ?e, "Failed to send log to channel");
                                 }
@@ -216,8 +276,36 @@ impl Service<Svm> {
         Ok(())
     }
 
-    pub async fn run_watcher_loop(&self) -> Result<()> {
+    pub async fn run_settlement_loop(&self, health: LoopHealthRecorder) -> Result<()> {
+        let settlement_commitment = match self.config.chain_config.settlement_commitment {
+            Some(settlement_commitment) => settlement_commitment,
+            // The settlement check is disabled for the chain
+            None => return Ok(()),
+        };
+        tracing::info!(
+            chain_id = self.config.chain_id,
+            "Starting settlement checker..."
+        );
+        let mut exit_check_interval = tokio::time::interval(EXIT_CHECK_INTERVAL);
+        let mut settlement_interval = tokio::time::interval(Duration::from_secs(5));
+        while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
+            tokio::select! {
+                _ = settlement_interval.tick() => {
+                    if let Err(err) = self.finalize_bids(settlement_commitment).await {
+                        tracing::error!(error = ?err, chain_id = self.config.chain_id, "Failed to check the bids settlement");
+                    }
+                }
+                _ = exit_check_interval.tick() => {}
+            }
+        }
+        tracing::info!("Shutting down settlement checker...");
+        Ok(())
+    }
+
+    pub async fn run_watcher_loop(&self, health: LoopHealthRecorder) -> Result<()> {
         while !SHOULD_EXIT.load(Ordering::Acquire) {
+            health.record_tick();
             let responses = (
                 self.config
                     .chain_config
@@ -13,10 +13,14 @@ use {
         serde_as,
         DisplayFromStr,
     },
-    solana_sdk::pubkey::Pubkey,
+    solana_sdk::{
+        commitment_config::CommitmentLevel,
+        pubkey::Pubkey,
+    },
     std::{
         collections::HashMap,
         fs,
+        time::Duration,
     },
 };
 
@@ -66,11 +70,15 @@ pub struct RunOptions {
     #[command(flatten)]
     pub config: ConfigOptions,
 
+    #[command(flatten)]
+    pub remote_signer: RemoteSignerOptions,
+
     /// A 20-byte (40 char) hex encoded Ethereum private key for one of the subwallets
     /// which can be used for relaying the bids.
+    /// Not required if the relayer keys are held by a remote signer.
     #[arg(long = "subwallet-private-key")]
     #[arg(env = "SUBWALLET_PRIVATE_KEY")]
-    pub subwallet_private_key: String,
+    pub subwallet_private_key: Option<String>,
 
     /// SVM relayer private key in base58 format.
     #[arg(long = "private-key-svm")]
@@ -82,6 +90,35 @@ pub struct RunOptions {
     pub secret_key: String,
 }
 
+#[derive(Args, Clone, Debug)]
+#[command(next_help_heading = "Remote Signer Options")]
+#[group(id = "RemoteSigner")]
+pub struct RemoteSignerOptions {
+    /// Endpoint of the signing daemon holding the relayer keys, `http(s)://...` or `unix:///path/to/socket`.
+    /// If not set, the relayer keys are read from the private key options.
+    #[arg(long = "remote-signer-url")]
+    #[arg(env = "REMOTE_SIGNER_URL")]
+    pub url: Option<String>,
+
+    /// Id of the evm relayer key in the signing daemon.
+    #[arg(long = "remote-signer-key-id-evm")]
+    #[arg(env = "REMOTE_SIGNER_KEY_ID_EVM")]
+    #[arg(default_value = "evm")]
+    pub key_id_evm: String,
+
+    /// Id of the svm relayer key in the signing daemon.
+    #[arg(long = "remote-signer-key-id-svm")]
+    #[arg(env = "REMOTE_SIGNER_KEY_ID_SVM")]
+    #[arg(default_value = "svm")]
+    pub key_id_svm: String,
+
+    /// Timeout in seconds for the requests to the signing daemon.
+    #[arg(long = "remote-signer-timeout")]
+    #[arg(env = "REMOTE_SIGNER_TIMEOUT")]
+    #[arg(default_value = "5")]
+    pub timeout: u64,
+}
+
 #[derive(Args, Clone, Debug)]
 #[command(next_help_heading = "Config Options")]
 #[group(id = "Config")]
@@ -122,6 +159,15 @@ pub struct ConfigEvm {
     /// URL of a Geth RPC endpoint to use for interacting with the blockchain.
     pub geth_rpc_addr: String,
 
+    /// URLs of Geth RPC endpoints to fail over to when `geth_rpc_addr` is unreachable, in order of preference.
+    #[serde(default)]
+    pub geth_rpc_fallback_addrs: Vec<String>,
+
+    /// Delay in milliseconds after which idempotent RPC requests are also sent to the next endpoint.
+    /// Hedging is disabled if not set.
+    #[serde(default)]
+    pub rpc_hedge_delay_ms: Option<u64>,
+
     /// URL of a Geth WS endpoint to use for interacting with the blockchain.
     pub geth_ws_addr: String,
 
@@ -143,6 +189,44 @@ pub struct ConfigEvm {
     /// Use the legacy transaction format (for networks without EIP 1559).
     #[serde(default)]
     pub legacy_tx: bool,
+
+    /// Relayer balance thresholds in eth.
+    #[serde(default)]
+    pub relayer_balance_thresholds: RelayerBalanceThresholds,
+
+    /// Number of blocks the auction transaction should be deep in before the bid results are final.
+    /// The results are final as soon as the transaction is included if not set.
+    #[serde(default)]
+    pub confirmations: u64,
+
+    /// Number of the first storage slots of a token to check when looking for its balance and allowance slots.
+    #[serde(default = "default_spoof_slot_search_limit")]
+    pub spoof_slot_search_limit: u64,
+}
+
+fn default_spoof_slot_search_limit() -> u64 {
+    256
+}
+
+impl ConfigEvm {
+    pub fn get_rpc_addrs(&self) -> Vec<String> {
+        std::iter::once(self.geth_rpc_addr.clone())
+            .chain(self.geth_rpc_fallback_addrs.iter().cloned())
+            .collect()
+    }
+
+    pub fn get_rpc_hedge_delay(&self) -> Option<Duration> {
+        self.rpc_hedge_delay_ms.map(Duration::from_millis)
+    }
+}
+
+/// Thresholds for the relayer balance, in the native token of the chain (eth or sol).
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct RelayerBalanceThresholds {
+    /// Below this balance the searchers are notified that the relayer is running low.
+    pub low:      Option<f64>,
+    /// Below this balance no auction is submitted and new bids are rejected.
+    pub critical: Option<f64>,
 }
 
 fn default_rpc_timeout_svm() -> u64 {
@@ -154,22 +238,103 @@ fn default_rpc_timeout_svm() -> u64 {
 pub struct ConfigSvm {
     /// Id of the express relay program.
     #[serde_as(as = "DisplayFromStr")]
-    pub express_relay_program_id:      Pubkey,
+    pub express_relay_program_id:        Pubkey,
     /// RPC endpoint to use for reading from the blockchain.
-    pub rpc_read_url:                  String,
+    pub rpc_read_url:                    String,
+    /// RPC endpoints to fail over to when `rpc_read_url` is unreachable, in order of preference.
+    #[serde(default)]
+    pub rpc_read_fallback_urls:          Vec<String>,
     /// RPC endpoint to use for broadcasting transactions
-    pub rpc_tx_submission_url:         String,
+    pub rpc_tx_submission_url:           String,
+    /// RPC endpoints to fail over to when `rpc_tx_submission_url` is unreachable, in order of preference.
+    #[serde(default)]
+    pub rpc_tx_submission_fallback_urls: Vec<String>,
+    /// Delay in milliseconds after which idempotent RPC requests are also sent to the next endpoint.
+    /// Hedging is disabled if not set.
+    #[serde(default)]
+    pub rpc_hedge_delay_ms:              Option<u64>,
     /// WS endpoint to use for interacting with the blockchain.
-    pub ws_addr:                       String,
+    pub ws_addr:                         String,
     /// Timeout for RPC requests in seconds.
     #[serde(default = "default_rpc_timeout_svm")]
-    pub rpc_timeout:                   u64,
+    pub rpc_timeout:                     u64,
     /// The router account for Phantom.
-    #[serde_as(as = "DisplayFromStr")]
-    pub wallet_program_router_account: Pubkey,
+    /// Deprecated: it is registered at startup as the router of the phantom integrator.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub wallet_program_router_account:   Option<Pubkey>,
     #[serde(default)]
     /// Percentile of prioritization fees to query from the `rpc_read_url`.
     /// This should be None unless the RPC `getRecentPrioritizationFees`'s supports the percentile parameter, for example Triton RPC.
     /// It is an integer between 0 and 10000 with 10000 representing 100%.
-    pub prioritization_fee_percentile: Option<u64>,
+    pub prioritization_fee_percentile:   Option<u64>,
+    /// Relayer balance thresholds in sol.
+    #[serde(default)]
+    pub relayer_balance_thresholds:      RelayerBalanceThresholds,
+    /// Commitment levels used for reading the chain state.
+    #[serde(default)]
+    pub commitment:                      CommitmentLevelsSvm,
+    /// Reference price source used along with the auction history to estimate the quote prices.
+    #[serde(default)]
+    pub price_source:                    Option<PriceSourceConfig>,
+}
+
+/// Source of the reference token prices.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PriceSourceConfig {
+    /// A json file mapping the token mints to their price and decimals, read on every request.
+    File { path: String },
+    /// An http endpoint returning the same json mapping for the `mints` query parameter.
+    Http {
+        url:     String,
+        /// Timeout for the requests in seconds.
+        #[serde(default = "default_price_source_timeout")]
+        timeout: u64,
+    },
+}
+
+fn default_price_source_timeout() -> u64 {
+    2
+}
+
+/// Commitment levels used for each purpose of reading the svm chain state.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CommitmentLevelsSvm {
+    /// Commitment level of the accounts loaded for the bid simulations.
+    pub simulation: CommitmentLevel,
+    /// Commitment level at which the bid results are concluded.
+    pub conclusion: CommitmentLevel,
+    /// If set, the won and failed bids are checked again at this commitment level
+    /// and the time they are settled is recorded.
+    pub settlement: Option<CommitmentLevel>,
+}
+
+impl Default for CommitmentLevelsSvm {
+    fn default() -> Self {
+        Self {
+            simulation: CommitmentLevel::Processed,
+            conclusion: CommitmentLevel::Confirmed,
+            settlement: None,
+        }
+    }
+}
+
+impl ConfigSvm {
+    pub fn get_rpc_read_urls(&self) -> Vec<String> {
+        std::iter::once(self.rpc_read_url.clone())
+            .chain(self.rpc_read_fallback_urls.iter().cloned())
+            .collect()
+    }
+
+    pub fn get_rpc_tx_submission_urls(&self) -> Vec<String> {
+        std::iter::once(self.rpc_tx_submission_url.clone())
+            .chain(self.rpc_tx_submission_fallback_urls.iter().cloned())
+            .collect()
+    }
+
+    pub fn get_rpc_hedge_delay(&self) -> Option<Duration> {
+        self.rpc_hedge_delay_ms.map(Duration::from_millis)
+    }
 }
@@ -1,5 +1,8 @@
 use {
-    super::traced_client::TracedClient,
+    super::{
+        signer::RelayerSignerEvm,
+        traced_client::TracedClient,
+    },
     ethers::{
         contract::abigen,
         middleware::{
@@ -16,10 +19,7 @@ use {
             Middleware,
             Provider,
         },
-        signers::{
-            LocalWallet,
-            Signer,
-        },
+        signers::Signer,
         types::{
             transaction::eip2718::TypedTransaction,
             Address,
@@ -80,7 +80,7 @@ impl Transformer for LegacyTxTransformer {
 pub type ExpressRelayContractEvm = ExpressRelay<Provider<TracedClient>>;
 pub type SignableProvider = TransformerMiddleware<
     GasOracleMiddleware<
-        NonceManagerMiddleware<SignerMiddleware<Provider<TracedClient>, LocalWallet>>,
+        NonceManagerMiddleware<SignerMiddleware<Provider<TracedClient>, RelayerSignerEvm>>,
         EthProviderOracle<Provider<TracedClient>>,
     >,
     LegacyTxTransformer,
@@ -20,7 +20,10 @@ use {
         packet::PACKET_DATA_SIZE,
         transaction::VersionedTransaction,
     },
-    std::fmt::Display,
+    std::{
+        fmt::Display,
+        str::FromStr,
+    },
 };
 
 pub type ChainId = String;
@@ -58,6 +61,23 @@ impl<'de> Deserialize<'de> for PermissionKeySvm {
     }
 }
 
+impl FromStr for PermissionKeySvm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(s)?;
+        Ok(PermissionKeySvm(bytes.try_into().map_err(|_| {
+            anyhow::anyhow!("Svm permission key should be 64 bytes")
+        })?))
+    }
+}
+
+impl From<PermissionKeySvm> for PermissionKey {
+    fn from(permission_key: PermissionKeySvm) -> Self {
+        Bytes::from(permission_key.0)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ChainType {
     Evm,
@@ -0,0 +1,118 @@
+use {
+    crate::kernel::entities::ChainId,
+    std::{
+        collections::HashMap,
+        sync::{
+            Arc,
+            RwLock,
+        },
+    },
+    time::OffsetDateTime,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct RpcHealth {
+    pub last_success_time: Option<OffsetDateTime>,
+    pub last_error_time:   Option<OffsetDateTime>,
+    /// Latency of the last successful request in seconds.
+    pub last_latency:      Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LoopHealth {
+    pub last_tick_time:  Option<OffsetDateTime>,
+    pub last_error_time: Option<OffsetDateTime>,
+}
+
+/// Health records of the components which can not be reached from the api state,
+/// updated next to the places the metrics are recorded.
+#[derive(Debug, Default)]
+pub struct HealthRegistry {
+    rpc:       RwLock<HashMap<ChainId, RpcHealth>>,
+    ws_events: RwLock<HashMap<ChainId, OffsetDateTime>>,
+    loops:     RwLock<HashMap<String, LoopHealth>>,
+}
+
+impl HealthRegistry {
+    pub fn record_rpc_request(&self, chain_id: &ChainId, success: bool, latency: f64) {
+        let now = OffsetDateTime::now_utc();
+        let mut rpc = self.rpc.write().expect("Failed to lock rpc health");
+        let health = rpc.entry(chain_id.clone()).or_default();
+        if success {
+            health.last_success_time = Some(now);
+            health.last_latency = Some(latency);
+        } else {
+            health.last_error_time = Some(now);
+        }
+    }
+
+    pub fn get_rpc_health(&self) -> HashMap<ChainId, RpcHealth> {
+        self.rpc.read().expect("Failed to lock rpc health").clone()
+    }
+
+    /// Records an event received from a chain websocket subscription.
+    pub fn record_ws_event(&self, chain_id: &ChainId) {
+        self.ws_events
+            .write()
+            .expect("Failed to lock ws health")
+            .insert(chain_id.clone(), OffsetDateTime::now_utc());
+    }
+
+    pub fn get_ws_events(&self) -> HashMap<ChainId, OffsetDateTime> {
+        self.ws_events
+            .read()
+            .expect("Failed to lock ws health")
+            .clone()
+    }
+
+    pub fn record_loop_tick(&self, name: &str) {
+        self.loops
+            .write()
+            .expect("Failed to lock loop health")
+            .entry(name.to_string())
+            .or_default()
+            .last_tick_time = Some(OffsetDateTime::now_utc());
+    }
+
+    pub fn record_loop_error(&self, name: &str) {
+        self.loops
+            .write()
+            .expect("Failed to lock loop health")
+            .entry(name.to_string())
+            .or_default()
+            .last_error_time = Some(OffsetDateTime::now_utc());
+    }
+
+    pub fn get_loops_health(&self) -> HashMap<String, LoopHealth> {
+        self.loops
+            .read()
+            .expect("Failed to lock loop health")
+            .clone()
+    }
+}
+
+/// Records the health of a single background loop in the registry.
+#[derive(Debug, Clone)]
+pub struct LoopHealthRecorder {
+    name:     String,
+    registry: Arc<HealthRegistry>,
+}
+
+impl LoopHealthRecorder {
+    pub fn new(name: String, registry: Arc<HealthRegistry>) -> Self {
+        Self { name, registry }
+    }
+
+    pub fn record_tick(&self) {
+        self.registry.record_loop_tick(&self.name);
+    }
+
+    /// Records an event the loop received from a chain websocket subscription.
+    pub fn record_ws_event(&self, chain_id: &ChainId) {
+        self.registry.record_ws_event(chain_id);
+    }
+
+    pub fn record_error(&self) {
+        self.registry.record_loop_error(&self.name);
+    }
+}
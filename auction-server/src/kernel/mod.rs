@@ -1,5 +1,8 @@
 pub mod contracts;
 pub mod db;
 pub mod entities;
+pub mod health;
+pub mod rpc_failover;
+pub mod signer;
 pub mod traced_client;
 pub mod traced_sender_svm;
@@ -0,0 +1,281 @@
+use {
+    crate::kernel::entities::ChainId,
+    axum_prometheus::metrics,
+    std::{
+        future::Future,
+        sync::{
+            Arc,
+            Mutex,
+        },
+        time::{
+            Duration,
+            Instant,
+        },
+    },
+};
+
+/// Number of consecutive transport failures after which the circuit of an endpoint is opened.
+const CIRCUIT_FAILURE_THRESHOLD: u32 = 3;
+/// Time an open circuit waits before letting a probe request through.
+const CIRCUIT_OPEN_DURATION: Duration = Duration::from_secs(30);
+/// Weight of the latest sample in the moving averages used for scoring.
+const SCORE_SMOOTHING: f64 = 0.2;
+
+#[derive(Debug, Default)]
+struct EndpointState {
+    consecutive_failures: u32,
+    open_until:           Option<Instant>,
+    /// Moving average of the transport failure rate.
+    error_rate:           f64,
+    /// Moving average of the latency of successful requests in seconds.
+    latency:              Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct Endpoint<T> {
+    pub transport: T,
+    /// Host of the endpoint, safe to be used as a metric label.
+    pub label:     String,
+    state:         Mutex<EndpointState>,
+}
+
+impl<T> Endpoint<T> {
+    fn is_open(&self, now: Instant) -> bool {
+        let state = self.state.lock().expect("poisoned endpoint state");
+        state.open_until.is_some_and(|open_until| open_until > now)
+    }
+
+    fn score(&self) -> f64 {
+        self.state
+            .lock()
+            .expect("poisoned endpoint state")
+            .error_rate
+    }
+
+    fn latency(&self) -> Option<f64> {
+        self.state.lock().expect("poisoned endpoint state").latency
+    }
+
+    fn record(&self, chain_id: &ChainId, transport_failure: bool, latency: f64) {
+        let mut state = self.state.lock().expect("poisoned endpoint state");
+        let sample = if transport_failure { 1.0 } else { 0.0 };
+        state.error_rate = state.error_rate * (1.0 - SCORE_SMOOTHING) + sample * SCORE_SMOOTHING;
+        if transport_failure {
+            state.consecutive_failures += 1;
+            if state.consecutive_failures >= CIRCUIT_FAILURE_THRESHOLD {
+                if state.open_until.is_none() {
+                    tracing::warn!(
+                        chain_id = %chain_id,
+                        endpoint = %self.label,
+                        "Opening rpc endpoint circuit"
+                    );
+                }
+                state.open_until = Some(Instant::now() + CIRCUIT_OPEN_DURATION);
+            }
+        } else {
+            if state.open_until.is_some() {
+                tracing::info!(
+                    chain_id = %chain_id,
+                    endpoint = %self.label,
+                    "Closing rpc endpoint circuit"
+                );
+            }
+            state.consecutive_failures = 0;
+            state.open_until = None;
+            state.latency = Some(match state.latency {
+                Some(average) => average * (1.0 - SCORE_SMOOTHING) + latency * SCORE_SMOOTHING,
+                None => latency,
+            });
+        }
+
+        let labels = [
+            ("chain_id", chain_id.clone()),
+            ("endpoint", self.label.clone()),
+        ];
+        metrics::gauge!("rpc_endpoint_circuit_open", &labels).set(if state.open_until.is_some() {
+            1.0
+        } else {
+            0.0
+        });
+        metrics::gauge!("rpc_endpoint_error_rate", &labels).set(state.error_rate);
+    }
+}
+
+/// An ordered list of rpc endpoints for a chain with health scoring and circuit breaking.
+///
+/// Requests go to the healthiest endpoint first and fail over to the next one on transport errors.
+/// Endpoints with an open circuit are only used when all the others failed.
+#[derive(Debug)]
+pub struct EndpointPool<T> {
+    chain_id:    ChainId,
+    endpoints:   Vec<Arc<Endpoint<T>>>,
+    /// If set, idempotent requests are also sent to the next endpoint when the first one
+    /// has not answered after this delay.
+    hedge_delay: Option<Duration>,
+}
+
+pub fn get_endpoint_label(url: &str) -> String {
+    // Urls usually contain api keys, so only the host is exposed.
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_string()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+impl<T> EndpointPool<T> {
+    pub fn new(
+        chain_id: ChainId,
+        endpoints: Vec<(String, T)>,
+        hedge_delay: Option<Duration>,
+    ) -> Self {
+        Self {
+            chain_id,
+            endpoints: endpoints
+                .into_iter()
+                .map(|(url, transport)| {
+                    Arc::new(Endpoint {
+                        transport,
+                        label: get_endpoint_label(&url),
+                        state: Mutex::new(EndpointState::default()),
+                    })
+                })
+                .collect(),
+            hedge_delay,
+        }
+    }
+
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    pub fn primary(&self) -> &T {
+        &self.endpoints[0].transport
+    }
+
+    /// Endpoints with a closed circuit sorted by their error rate, the configured order breaks the ties.
+    /// Endpoints with an open circuit come last.
+    fn get_ordered_endpoints(&self) -> Vec<Arc<Endpoint<T>>> {
+        let now = Instant::now();
+        let (mut closed, open): (Vec<_>, Vec<_>) = self
+            .endpoints
+            .iter()
+            .cloned()
+            .partition(|endpoint| !endpoint.is_open(now));
+        closed.sort_by(|a, b| a.score().total_cmp(&b.score()));
+        closed.extend(open);
+        closed
+    }
+
+    fn get_hedge_delay(&self, endpoint: &Endpoint<T>) -> Option<Duration> {
+        self.hedge_delay.map(|delay| match endpoint.latency() {
+            // Do not hedge requests which are normally slower than the configured delay.
+            Some(latency) => delay.max(Duration::from_secs_f64(latency * 2.0)),
+            None => delay,
+        })
+    }
+
+    async fn attempt<R, E, F, Fut>(
+        &self,
+        endpoint: &Arc<Endpoint<T>>,
+        is_transport_error: &impl Fn(&E) -> bool,
+        send: &F,
+    ) -> Result<R, E>
+    where
+        F: Fn(Arc<Endpoint<T>>) -> Fut,
+        Fut: Future<Output = Result<R, E>>,
+    {
+        let start = Instant::now();
+        let res = send(endpoint.clone()).await;
+        let transport_failure = res.as_ref().err().is_some_and(is_transport_error);
+        endpoint.record(
+            &self.chain_id,
+            transport_failure,
+            start.elapsed().as_secs_f64(),
+        );
+        res
+    }
+
+    async fn attempt_hedged<R, E, F, Fut>(
+        &self,
+        primary: &Arc<Endpoint<T>>,
+        secondary: &Arc<Endpoint<T>>,
+        delay: Duration,
+        is_transport_error: &impl Fn(&E) -> bool,
+        send: &F,
+    ) -> (Result<R, E>, bool)
+    where
+        F: Fn(Arc<Endpoint<T>>) -> Fut,
+        Fut: Future<Output = Result<R, E>>,
+    {
+        let mut primary_attempt = Box::pin(self.attempt(primary, is_transport_error, send));
+        tokio::select! {
+            res = &mut primary_attempt => return (res, false),
+            _ = tokio::time::sleep(delay) => {}
+        }
+        metrics::counter!(
+            "rpc_hedged_requests_total",
+            &[("chain_id", self.chain_id.clone())]
+        )
+        .increment(1);
+        let secondary_attempt = Box::pin(self.attempt(secondary, is_transport_error, send));
+        let res = futures::future::select_ok([primary_attempt, secondary_attempt])
+            .await
+            .map(|(res, _)| res);
+        (res, true)
+    }
+
+    /// Sends the request to the endpoints until one of them answers without a transport error.
+    pub async fn execute<R, E, F, Fut>(
+        &self,
+        idempotent: bool,
+        is_transport_error: impl Fn(&E) -> bool,
+        send: F,
+    ) -> Result<R, E>
+    where
+        F: Fn(Arc<Endpoint<T>>) -> Fut,
+        Fut: Future<Output = Result<R, E>>,
+    {
+        let endpoints = self.get_ordered_endpoints();
+        let mut index = 0;
+        loop {
+            let endpoint = &endpoints[index];
+            let hedge = match (idempotent, endpoints.get(index + 1)) {
+                (true, Some(secondary)) => self
+                    .get_hedge_delay(endpoint)
+                    .map(|delay| (secondary, delay)),
+                _ => None,
+            };
+            let res = match hedge {
+                Some((secondary, delay)) => {
+                    let (res, hedged) = self
+                        .attempt_hedged(endpoint, secondary, delay, &is_transport_error, &send)
+                        .await;
+                    index += if hedged { 2 } else { 1 };
+                    res
+                }
+                None => {
+                    index += 1;
+                    self.attempt(endpoint, &is_transport_error, &send).await
+                }
+            };
+            match res {
+                Err(err) if is_transport_error(&err) && index < endpoints.len() => {
+                    tracing::warn!(
+                        chain_id = %self.chain_id,
+                        endpoint = %endpoint.label,
+                        "Rpc request failed, failing over to the next endpoint"
+                    );
+                    metrics::counter!(
+                        "rpc_failovers_total",
+                        &[
+                            ("chain_id", self.chain_id.clone()),
+                            ("endpoint", endpoint.label.clone()),
+                        ]
+                    )
+                    .increment(1);
+                }
+                res => return res,
+            }
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use {
+    anyhow::{
+        anyhow,
+        Result,
+    },
+    axum::async_trait,
+    axum_prometheus::metrics,
+    base64::Engine,
+    ethers::{
+        signers::{
+            LocalWallet,
+            Signer,
+            WalletError,
+        },
+        types::{
+            transaction::{
+                eip2718::TypedTransaction,
+                eip712::Eip712,
+            },
+            Address,
+            Signature as SignatureEvm,
+            H256,
+        },
+        utils::{
+            hash_message,
+            to_eip155_v,
+        },
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{
+            Keypair,
+            Signature as SignatureSvm,
+            Signer as _,
+        },
+    },
+    std::{
+        fmt,
+        path::PathBuf,
+        str::FromStr,
+        sync::Arc,
+        time::{
+            Duration,
+            Instant,
+        },
+    },
+    tokio::{
+        io::{
+            AsyncBufReadExt,
+            AsyncWriteExt,
+            BufReader,
+        },
+        net::UnixStream,
+    },
+};
+
+/// Transport used to reach the remote signing daemon.
+#[derive(Clone, Debug)]
+pub enum RemoteSignerEndpoint {
+    /// `http://` or `https://` url, the request is sent as a json POST body.
+    Http(reqwest::Url),
+    /// `unix://` path, the request is sent as a single json line and answered with a single json line.
+    Unix(PathBuf),
+}
+
+impl FromStr for RemoteSignerEndpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(path) = s.strip_prefix("unix://") {
+            return Ok(RemoteSignerEndpoint::Unix(PathBuf::from(path)));
+        }
+        let url = reqwest::Url::parse(s)?;
+        match url.scheme() {
+            "http" | "https" => Ok(RemoteSignerEndpoint::Http(url)),
+            scheme => Err(anyhow!("Unsupported remote signer scheme: {}", scheme)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum RemoteSignerRequest {
+    /// Returns the public identity of the key: the 20 bytes address for evm keys
+    /// and the 32 bytes public key for svm keys.
+    PublicKey { key_id: String },
+    /// Signs the payload with the key. The payload is a 32 bytes digest for evm keys
+    /// and the serialized transaction message for svm keys.
+    Sign { key_id: String, payload: String },
+}
+
+impl RemoteSignerRequest {
+    fn method(&self) -> &'static str {
+        match self {
+            RemoteSignerRequest::PublicKey { .. } => "public_key",
+            RemoteSignerRequest::Sign { .. } => "sign",
+        }
+    }
+}
+
+/// All the binary fields of the protocol are base64 encoded.
+#[derive(Deserialize, Debug)]
+struct RemoteSignerResponse {
+    result: Option<String>,
+    error:  Option<String>,
+}
+
+/// Client for a signing daemon which keeps the relayer keys outside of the server process.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    endpoint: RemoteSignerEndpoint,
+    key_id:   String,
+    timeout:  Duration,
+    client:   reqwest::Client,
+}
+
+impl RemoteSigner {
+    pub fn new(endpoint: RemoteSignerEndpoint, key_id: String, timeout: u64) -> Result<Self> {
+        let timeout = Duration::from_secs(timeout);
+        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            endpoint,
+            key_id,
+            timeout,
+            client,
+        })
+    }
+
+    pub async fn public_key(&self) -> Result<Vec<u8>> {
+        self.call(RemoteSignerRequest::PublicKey {
+            key_id: self.key_id.clone(),
+        })
+        .await
+    }
+
+    pub async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        self.call(RemoteSignerRequest::Sign {
+            key_id:  self.key_id.clone(),
+            payload: base64::engine::general_purpose::STANDARD.encode(payload),
+        })
+        .await
+    }
+
+    async fn call(&self, request: RemoteSignerRequest) -> Result<Vec<u8>> {
+        let start = Instant::now();
+        let method = request.method();
+        let res = match tokio::time::timeout(self.timeout, self.send(&request)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!("Remote signer request timed out")),
+        };
+        let result_label = match &res {
+            Ok(_) => "success",
+            Err(e) => {
+                tracing::error!(error = ?e, key_id = self.key_id, "remote signer request failed");
+                "error"
+            }
+        };
+
+        let labels = [
+            ("key_id", self.key_id.clone()),
+            ("method", method.to_string()),
+            ("result", result_label.to_string()),
+        ];
+        let latency = start.elapsed().as_secs_f64();
+        metrics::counter!("remote_signer_requests_total", &labels).increment(1);
+        metrics::histogram!("remote_signer_requests_duration_seconds", &labels).record(latency);
+
+        let response = res?;
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(anyhow!("Remote signer returned error: {}", error)),
+            (Some(result), None) => Ok(base64::engine::general_purpose::STANDARD.decode(result)?),
+            (None, None) => Err(anyhow!("Remote signer returned an empty response")),
+        }
+    }
+
+    async fn send(&self, request: &RemoteSignerRequest) -> Result<RemoteSignerResponse> {
+        let body = serde_json::to_vec(request)?;
+        match &self.endpoint {
+            RemoteSignerEndpoint::Http(url) => {
+                let response = self
+                    .client
+                    .post(url.clone())
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+                Ok(serde_json::from_slice(&response.bytes().await?)?)
+            }
+            RemoteSignerEndpoint::Unix(path) => {
+                let mut stream = UnixStream::connect(path).await?;
+                stream.write_all(&body).await?;
+                stream.write_all(b"\n").await?;
+                let mut line = String::new();
+                BufReader::new(stream).read_line(&mut line).await?;
+                Ok(serde_json::from_str(&line)?)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RelayerSignerError {
+    Local(WalletError),
+    Remote(anyhow::Error),
+}
+
+impl fmt::Display for RelayerSignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelayerSignerError::Local(e) => write!(f, "Local signer error: {}", e),
+            RelayerSignerError::Remote(e) => write!(f, "Remote signer error: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for RelayerSignerError {
+}
+
+/// Relayer signer for evm chains.
+/// The in-process wallet is the default, the remote variant delegates signing of digests to the signing daemon.
+#[derive(Clone, Debug)]
+pub enum RelayerSignerEvm {
+    Local(LocalWallet),
+    Remote {
+        signer:   RemoteSigner,
+        address:  Address,
+        chain_id: u64,
+    },
+}
+
+impl RelayerSignerEvm {
+    pub async fn new_remote(signer: RemoteSigner) -> Result<Self> {
+        let public_key = signer.public_key().await?;
+        if public_key.len() != Address::len_bytes() {
+            return Err(anyhow!(
+                "Remote signer returned an invalid evm address of {} bytes",
+                public_key.len()
+            ));
+        }
+        Ok(RelayerSignerEvm::Remote {
+            signer,
+            address: Address::from_slice(&public_key),
+            chain_id: 1,
+        })
+    }
+
+    async fn sign_hash_remote(
+        signer: &RemoteSigner,
+        address: Address,
+        hash: H256,
+    ) -> Result<SignatureEvm, RelayerSignerError> {
+        let signature = signer
+            .sign(hash.as_bytes())
+            .await
+            .map_err(RelayerSignerError::Remote)?;
+        let mut signature = SignatureEvm::try_from(signature.as_slice())
+            .map_err(|e| RelayerSignerError::Remote(anyhow!(e)))?;
+        // Signing daemons may return the raw recovery id instead of the ethereum style v
+        if signature.v < 27 {
+            signature.v += 27;
+        }
+        let recovered = signature
+            .recover(hash)
+            .map_err(|e| RelayerSignerError::Remote(anyhow!(e)))?;
+        if recovered != address {
+            return Err(RelayerSignerError::Remote(anyhow!(
+                "Remote signer returned a signature of {:?} not matching {:?}",
+                recovered,
+                address
+            )));
+        }
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Signer for RelayerSignerEvm {
+    type Error = RelayerSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<SignatureEvm, Self::Error> {
+        match self {
+            RelayerSignerEvm::Local(wallet) => wallet
+                .sign_message(message)
+                .await
+                .map_err(RelayerSignerError::Local),
+            RelayerSignerEvm::Remote {
+                signer, address, ..
+            } => Self::sign_hash_remote(signer, *address, hash_message(message)).await,
+        }
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<SignatureEvm, Self::Error> {
+        match self {
+            RelayerSignerEvm::Local(wallet) => wallet
+                .sign_transaction(tx)
+                .await
+                .map_err(RelayerSignerError::Local),
+            RelayerSignerEvm::Remote {
+                signer,
+                address,
+                chain_id,
+            } => {
+                let mut tx = tx.clone();
+                if tx.chain_id().is_none() {
+                    tx.set_chain_id(*chain_id);
+                }
+                let chain_id = tx.chain_id().map(|id| id.as_u64()).unwrap_or(*chain_id);
+                let mut signature = Self::sign_hash_remote(signer, *address, tx.sighash()).await?;
+                if let TypedTransaction::Legacy(_) = tx {
+                    signature.v = to_eip155_v((signature.v - 27) as u8, chain_id);
+                }
+                Ok(signature)
+            }
+        }
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<SignatureEvm, Self::Error> {
+        match self {
+            RelayerSignerEvm::Local(wallet) => wallet
+                .sign_typed_data(payload)
+                .await
+                .map_err(RelayerSignerError::Local),
+            RelayerSignerEvm::Remote {
+                signer, address, ..
+            } => {
+                let hash = payload
+                    .encode_eip712()
+                    .map_err(|e| RelayerSignerError::Remote(anyhow!(e.to_string())))?;
+                Self::sign_hash_remote(signer, *address, H256::from(hash)).await
+            }
+        }
+    }
+
+    fn address(&self) -> Address {
+        match self {
+            RelayerSignerEvm::Local(wallet) => wallet.address(),
+            RelayerSignerEvm::Remote { address, .. } => *address,
+        }
+    }
+
+    fn chain_id(&self) -> u64 {
+        match self {
+            RelayerSignerEvm::Local(wallet) => wallet.chain_id(),
+            RelayerSignerEvm::Remote { chain_id, .. } => *chain_id,
+        }
+    }
+
+    fn with_chain_id<T: Into<u64>>(self, chain_id: T) -> Self {
+        match self {
+            RelayerSignerEvm::Local(wallet) => {
+                RelayerSignerEvm::Local(wallet.with_chain_id(chain_id))
+            }
+            RelayerSignerEvm::Remote {
+                signer, address, ..
+            } => RelayerSignerEvm::Remote {
+                signer,
+                address,
+                chain_id: chain_id.into(),
+            },
+        }
+    }
+}
+
+/// Relayer signer for svm chains.
+/// The in-process keypair is the default, the remote variant delegates signing of transaction messages to the signing daemon.
+#[derive(Clone)]
+pub enum RelayerSignerSvm {
+    Local(Arc<Keypair>),
+    Remote {
+        signer: RemoteSigner,
+        pubkey: Pubkey,
+    },
+}
+
+impl RelayerSignerSvm {
+    pub async fn new_remote(signer: RemoteSigner) -> Result<Self> {
+        let public_key = signer.public_key().await?;
+        let pubkey = Pubkey::try_from(public_key.as_slice()).map_err(|_| {
+            anyhow!(
+                "Remote signer returned an invalid svm public key of {} bytes",
+                public_key.len()
+            )
+        })?;
+        Ok(RelayerSignerSvm::Remote { signer, pubkey })
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        match self {
+            RelayerSignerSvm::Local(keypair) => keypair.pubkey(),
+            RelayerSignerSvm::Remote { pubkey, .. } => *pubkey,
+        }
+    }
+
+    pub async fn sign_message(&self, message: &[u8]) -> Result<SignatureSvm> {
+        match self {
+            RelayerSignerSvm::Local(keypair) => Ok(keypair.sign_message(message)),
+            RelayerSignerSvm::Remote { signer, pubkey } => {
+                let signature = SignatureSvm::try_from(signer.sign(message).await?.as_slice())
+                    .map_err(|e| anyhow!("Remote signer returned an invalid signature: {}", e))?;
+                if !signature.verify(pubkey.as_ref(), message) {
+                    return Err(anyhow!(
+                        "Remote signer returned a signature not matching {}",
+                        pubkey
+                    ));
+                }
+                Ok(signature)
+            }
+        }
+    }
+}
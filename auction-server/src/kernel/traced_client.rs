@@ -1,6 +1,15 @@
 use {
-    crate::config::ChainId,
-    anyhow::Result,
+    crate::{
+        config::ChainId,
+        kernel::{
+            health::HealthRegistry,
+            rpc_failover::EndpointPool,
+        },
+    },
+    anyhow::{
+        anyhow,
+        Result,
+    },
     axum::async_trait,
     axum_prometheus::metrics,
     ethers::{
@@ -13,6 +22,7 @@ use {
     },
     std::{
         fmt::Debug,
+        sync::Arc,
         time::{
             Duration,
             Instant,
@@ -20,10 +30,37 @@ use {
     },
 };
 
+/// Methods which can be safely sent to several endpoints at the same time.
+const IDEMPOTENT_METHODS: &[&str] = &[
+    "eth_blockNumber",
+    "eth_call",
+    "eth_chainId",
+    "eth_estimateGas",
+    "eth_feeHistory",
+    "eth_gasPrice",
+    "eth_getBalance",
+    "eth_getBlockByHash",
+    "eth_getBlockByNumber",
+    "eth_getCode",
+    "eth_getLogs",
+    "eth_getStorageAt",
+    "eth_getTransactionByHash",
+    "eth_getTransactionCount",
+    "eth_getTransactionReceipt",
+    "eth_maxPriorityFeePerGas",
+    "net_version",
+];
+
 #[derive(Debug, Clone)]
 pub struct TracedClient {
-    inner:    Http,
-    chain_id: ChainId,
+    endpoints: Arc<EndpointPool<Http>>,
+    health:    Arc<HealthRegistry>,
+}
+
+fn is_transport_error(error: &HttpClientError) -> bool {
+    // Json rpc errors are returned by a reachable node, e.g. reverted calls,
+    // so they are not retried on another endpoint.
+    !matches!(error, HttpClientError::JsonRpcError(_))
 }
 
 #[async_trait]
@@ -38,39 +75,77 @@ impl JsonRpcClient for TracedClient {
         method: &str,
         params: T,
     ) -> Result<R, HttpClientError> {
+        let chain_id = self.endpoints.chain_id();
         let start = Instant::now();
-        let res = self.inner.request(method, params).await;
-
-        let result_label = match &res {
-            Ok(_) => "success",
-            Err(e) => {
-                tracing::error!(error = ?e, "rpc request failed");
-                "error"
-            }
-        };
+        // The params are serialized once so the request can be sent to several endpoints.
+        let params = serde_json::to_value(params).map_err(|err| HttpClientError::SerdeJson {
+            err,
+            text: "failed to serialize the request params".to_string(),
+        })?;
+        let res = self
+            .endpoints
+            .execute(
+                IDEMPOTENT_METHODS.contains(&method),
+                is_transport_error,
+                |endpoint| {
+                    let params = params.clone();
+                    async move {
+                        let start = Instant::now();
+                        let res = endpoint.transport.request(method, params).await;
+                        let result_label = match &res {
+                            Ok(_) => "success",
+                            Err(e) => {
+                                tracing::error!(error = ?e, endpoint = %endpoint.label, "rpc request failed");
+                                "error"
+                            }
+                        };
 
-        let labels = [
-            ("chain_id", self.chain_id.clone()),
-            ("method", method.to_string()),
-            ("result", result_label.to_string()),
-        ];
+                        let labels = [
+                            ("chain_id", chain_id.clone()),
+                            ("method", method.to_string()),
+                            ("result", result_label.to_string()),
+                            ("endpoint", endpoint.label.clone()),
+                        ];
+                        metrics::counter!("rpc_requests_total", &labels).increment(1);
+                        metrics::histogram!("rpc_requests_duration_seconds", &labels)
+                            .record(start.elapsed().as_secs_f64());
+                        res
+                    }
+                },
+            )
+            .await;
 
-        let latency = start.elapsed().as_secs_f64();
-        metrics::counter!("rpc_requests_total", &labels).increment(1);
-        metrics::histogram!("rpc_requests_duration_seconds", &labels).record(latency);
+        self.health
+            .record_rpc_request(chain_id, res.is_ok(), start.elapsed().as_secs_f64());
         res
     }
 }
 
 impl TracedClient {
-    pub fn new(chain_id: ChainId, url: &str, timeout: u64) -> Result<Provider<TracedClient>> {
-        let url = reqwest::Url::parse(url)?;
+    /// Creates a provider sending the requests to the given urls, in order of preference.
+    pub fn new(
+        chain_id: ChainId,
+        urls: &[String],
+        timeout: u64,
+        hedge_delay: Option<Duration>,
+        health: Arc<HealthRegistry>,
+    ) -> Result<Provider<TracedClient>> {
+        if urls.is_empty() {
+            return Err(anyhow!("No rpc url provided for chain {}", chain_id));
+        }
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(timeout))
             .build()?;
+        let endpoints = urls
+            .iter()
+            .map(|url| {
+                let parsed = reqwest::Url::parse(url)?;
+                Ok((url.clone(), Http::new_with_client(parsed, client.clone())))
+            })
+            .collect::<Result<Vec<_>>>()?;
         Ok(Provider::new(TracedClient {
-            inner: Http::new_with_client(url, client),
-            chain_id,
+            endpoints: Arc::new(EndpointPool::new(chain_id, endpoints, hedge_delay)),
+            health,
         }))
     }
 }
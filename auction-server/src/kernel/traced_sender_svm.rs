@@ -1,5 +1,11 @@
 use {
-    crate::config::ChainId,
+    crate::{
+        config::ChainId,
+        kernel::{
+            health::HealthRegistry,
+            rpc_failover::EndpointPool,
+        },
+    },
     axum::async_trait,
     axum_prometheus::metrics,
     solana_client::{
@@ -13,15 +19,50 @@ use {
         },
     },
     solana_rpc_client::http_sender::HttpSender,
-    std::time::{
-        Duration,
-        Instant,
+    std::{
+        sync::Arc,
+        time::{
+            Duration,
+            Instant,
+        },
     },
 };
 
 pub struct TracedSenderSvm {
-    sender:   HttpSender,
-    chain_id: ChainId,
+    endpoints: EndpointPool<HttpSender>,
+    health:    Arc<HealthRegistry>,
+}
+
+fn is_transport_error(error: &client_error::ClientError) -> bool {
+    matches!(
+        error.kind(),
+        client_error::ClientErrorKind::Io(_) | client_error::ClientErrorKind::Reqwest(_)
+    )
+}
+
+/// Only the reads are hedged, the transactions are never sent to several endpoints at once.
+fn is_idempotent(request: RpcRequest) -> bool {
+    matches!(
+        request,
+        RpcRequest::GetAccountInfo
+            | RpcRequest::GetBalance
+            | RpcRequest::GetBlockHeight
+            | RpcRequest::GetEpochInfo
+            | RpcRequest::GetFeeForMessage
+            | RpcRequest::GetHealth
+            | RpcRequest::GetLatestBlockhash
+            | RpcRequest::GetMinimumBalanceForRentExemption
+            | RpcRequest::GetMultipleAccounts
+            | RpcRequest::GetProgramAccounts
+            | RpcRequest::GetRecentPrioritizationFees
+            | RpcRequest::GetSignatureStatuses
+            | RpcRequest::GetSignaturesForAddress
+            | RpcRequest::GetSlot
+            | RpcRequest::GetTokenAccountBalance
+            | RpcRequest::GetTransaction
+            | RpcRequest::GetVersion
+            | RpcRequest::IsBlockhashValid
+    )
 }
 
 #[async_trait]
@@ -31,45 +72,76 @@ impl RpcSender for TracedSenderSvm {
         request: RpcRequest,
         params: serde_json::Value,
     ) -> client_error::Result<serde_json::Value> {
+        let chain_id = self.endpoints.chain_id();
         let start = Instant::now();
-        let res = self.sender.send(request, params).await;
-        let result_label = match &res {
-            Ok(_) => "success",
-            Err(e) => {
-                tracing::error!(error = ?e, "svm rpc request failed");
-                "error"
-            }
-        };
+        let res = self
+            .endpoints
+            .execute(is_idempotent(request), is_transport_error, |endpoint| {
+                let params = params.clone();
+                async move {
+                    let start = Instant::now();
+                    let res = endpoint.transport.send(request, params).await;
+                    let result_label = match &res {
+                        Ok(_) => "success",
+                        Err(e) => {
+                            tracing::error!(error = ?e, endpoint = %endpoint.label, "svm rpc request failed");
+                            "error"
+                        }
+                    };
 
-        let labels = [
-            ("chain_id", self.chain_id.clone()),
-            ("method", request.to_string()),
-            ("result", result_label.to_string()),
-        ];
+                    let labels = [
+                        ("chain_id", chain_id.clone()),
+                        ("method", request.to_string()),
+                        ("result", result_label.to_string()),
+                        ("endpoint", endpoint.label.clone()),
+                    ];
+                    metrics::counter!("rpc_requests_total_svm", &labels).increment(1);
+                    metrics::histogram!("rpc_requests_duration_seconds_svm", &labels)
+                        .record(start.elapsed().as_secs_f64());
+                    res
+                }
+            })
+            .await;
 
-        let latency = start.elapsed().as_secs_f64();
-        metrics::counter!("rpc_requests_total_svm", &labels).increment(1);
-        metrics::histogram!("rpc_requests_duration_seconds_svm", &labels).record(latency);
+        self.health
+            .record_rpc_request(chain_id, res.is_ok(), start.elapsed().as_secs_f64());
         res
     }
 
     fn get_transport_stats(&self) -> RpcTransportStats {
-        self.sender.get_transport_stats()
+        self.endpoints.primary().get_transport_stats()
     }
 
     fn url(&self) -> String {
-        self.sender.url()
+        self.endpoints.primary().url()
     }
 }
 
 impl TracedSenderSvm {
+    /// Creates a client sending the requests to the given urls, in order of preference.
     pub fn new_client(
         chain_id: ChainId,
-        url: &str,
+        urls: &[String],
         timeout: u64,
+        hedge_delay: Option<Duration>,
         config: RpcClientConfig,
+        health: Arc<HealthRegistry>,
     ) -> RpcClient {
-        let sender = HttpSender::new_with_timeout(url, Duration::from_secs(timeout));
-        RpcClient::new_sender(TracedSenderSvm { sender, chain_id }, config)
+        let endpoints = urls
+            .iter()
+            .map(|url| {
+                (
+                    url.clone(),
+                    HttpSender::new_with_timeout(url, Duration::from_secs(timeout)),
+                )
+            })
+            .collect();
+        RpcClient::new_sender(
+            TracedSenderSvm {
+                endpoints: EndpointPool::new(chain_id, endpoints, hedge_delay),
+                health,
+            },
+            config,
+        )
     }
 }
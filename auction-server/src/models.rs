@@ -63,3 +63,45 @@ pub enum ChainType {
     Evm,
     Svm,
 }
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "integrator_program", rename_all = "lowercase")]
+pub enum IntegratorProgram {
+    Limo,
+    Phantom,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "integrator_submitter", rename_all = "lowercase")]
+pub enum IntegratorSubmitter {
+    Integrator,
+    Server,
+}
+
+pub type IntegratorId = Uuid;
+#[derive(Clone, FromRow, Debug)]
+pub struct Integrator {
+    pub id:             IntegratorId,
+    pub chain_id:       String,
+    pub name:           String,
+    pub program:        IntegratorProgram,
+    pub router_account: String,
+    pub submitter:      IntegratorSubmitter,
+    pub profile_ids:    Vec<ProfileId>,
+
+    #[allow(dead_code)]
+    pub created_at: PrimitiveDateTime,
+}
+
+pub type PermissionKeyNamespaceId = Uuid;
+/// The permission keys starting with the prefix of the namespace can only be used by its profile.
+#[derive(Clone, FromRow, Debug)]
+pub struct PermissionKeyNamespace {
+    pub id:         PermissionKeyNamespaceId,
+    pub chain_id:   String,
+    pub profile_id: ProfileId,
+    pub prefix:     Vec<u8>,
+
+    #[allow(dead_code)]
+    pub created_at: PrimitiveDateTime,
+}
@@ -1,16 +1,21 @@
 use {
     super::{
+        entities,
         repository::OPPORTUNITY_PAGE_SIZE_CAP,
         service::{
             add_opportunity::AddOpportunityInput,
+            add_spoof_override::AddSpoofOverrideInput,
             get_opportunities::GetOpportunitiesInput,
             get_quote::GetQuoteInput,
+            get_quote_details::GetQuoteDetailsInput,
             handle_opportunity_bid::HandleOpportunityBidInput,
             remove_opportunities::RemoveOpportunitiesInput,
         },
     },
     crate::{
+        admin_only,
         api::{
+            admin_middleware,
             require_login_middleware,
             Auth,
             ErrorBodyResponse,
@@ -31,7 +36,11 @@ use {
             Query,
             State,
         },
-        http::StatusCode,
+        http::{
+            HeaderMap,
+            HeaderValue,
+            StatusCode,
+        },
         middleware,
         routing::{
             delete,
@@ -41,6 +50,10 @@ use {
         Json,
         Router,
     },
+    base64::{
+        engine::general_purpose::URL_SAFE_NO_PAD,
+        Engine,
+    },
     ethers::types::{
         Address,
         Bytes,
@@ -59,9 +72,17 @@ use {
     solana_sdk::{
         clock::Slot,
         pubkey::Pubkey,
+        signature::Signature as SignatureSvm,
         transaction::VersionedTransaction,
     },
-    std::sync::Arc,
+    std::{
+        fmt::{
+            Display,
+            Formatter,
+        },
+        str::FromStr,
+        sync::Arc,
+    },
     time::OffsetDateTime,
     utoipa::{
         schema,
@@ -75,6 +96,7 @@ use {
 // Base types
 pub type UnixTimestampMicros = i128;
 pub type OpportunityId = Uuid;
+pub type QuoteId = Uuid;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 #[serde(rename_all = "lowercase")]
@@ -112,6 +134,16 @@ pub struct OpportunityDeleteV1Svm {
     pub program:            ProgramSvm,
 }
 
+/// The reason an opportunity was removed by the server.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OpportunityRemovalReason {
+    /// The opportunity could not be verified for too long.
+    Expired,
+    /// The opportunity is not executable anymore.
+    Invalid,
+}
+
 /// Opportunity parameters needed for deleting live opportunities.
 #[serde_as]
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
@@ -120,8 +152,12 @@ pub struct OpportunityDeleteV1Evm {
     #[schema(example = "0xdeadbeefcafe", value_type = String)]
     pub permission_key: Bytes,
     /// The chain id for the opportunity.
-    #[schema(example = "solana", value_type = String)]
+    #[schema(example = "op_sepolia", value_type = String)]
     pub chain_id:       ChainId,
+    /// The reason the opportunities were removed. Only set when the removal is broadcast by the server.
+    #[schema(read_only)]
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub reason:         Option<OpportunityRemovalReason>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
@@ -176,6 +212,47 @@ fn default_opportunity_mode() -> OpportunityMode {
 fn default_limit() -> usize {
     20
 }
+/// The header holding the cursor of the next page of opportunities.
+/// It is only set if the page is full.
+pub const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+/// An opaque cursor to continue listing the opportunities after a returned page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OpportunityCursor(pub entities::OpportunityCursor);
+
+impl Display for OpportunityCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cursor = format!(
+            "{}:{}",
+            self.0.creation_time.unix_timestamp_nanos() / 1000,
+            self.0.id
+        );
+        write!(f, "{}", URL_SAFE_NO_PAD.encode(cursor))
+    }
+}
+
+impl FromStr for OpportunityCursor {
+    type Err = RestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_cursor = || RestError::BadParameters("Invalid cursor".to_string());
+        let cursor = URL_SAFE_NO_PAD
+            .decode(s)
+            .ok()
+            .and_then(|cursor| String::from_utf8(cursor).ok())
+            .ok_or_else(invalid_cursor)?;
+        let (creation_time, id) = cursor.split_once(':').ok_or_else(invalid_cursor)?;
+        let creation_time: UnixTimestampMicros =
+            creation_time.parse().map_err(|_| invalid_cursor())?;
+        Ok(OpportunityCursor(entities::OpportunityCursor {
+            creation_time: OffsetDateTime::from_unix_timestamp_nanos(creation_time * 1000)
+                .map_err(|_| invalid_cursor())?,
+            id:            id.parse().map_err(|_| invalid_cursor())?,
+        }))
+    }
+}
+
+#[serde_as]
 #[derive(Clone, Serialize, Deserialize, IntoParams)]
 pub struct GetOpportunitiesQueryParams {
     #[param(example = "op_sepolia", value_type = Option < String >)]
@@ -184,17 +261,54 @@ pub struct GetOpportunitiesQueryParams {
     #[param(default = "live")]
     #[serde(default = "default_opportunity_mode")]
     pub mode:           OpportunityMode,
-    /// The permission key to filter the opportunities by. Used only in historical mode.
+    /// The permission key to filter the opportunities by.
     #[param(example = "0xdeadbeef", value_type = Option< String >)]
     pub permission_key: Option<Bytes>,
     /// The time to get the opportunities from.
     #[param(example="2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
     #[serde(default, with = "crate::serde::nullable_datetime")]
     pub from_time:      Option<OffsetDateTime>,
+    /// The program of the svm opportunities to filter the opportunities by.
+    #[param(example = "limo", value_type = Option<ProgramSvm>)]
+    #[serde(default)]
+    pub program:        Option<ProgramSvm>,
+    /// The router account of the svm opportunities to filter the opportunities by.
+    #[param(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub router:         Option<Pubkey>,
+    /// The token address or mint to filter the opportunities by. It can be on either side of the opportunity.
+    #[param(example = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", value_type = Option<String>)]
+    #[serde(default)]
+    pub token:          Option<String>,
+    /// The profile which submitted the opportunities.
+    #[param(example = "obo3ee3e-58cc-4372-a567-0e02b2c3d479", value_type = Option<String>)]
+    #[serde(default)]
+    pub profile_id:     Option<models::ProfileId>,
+    /// The cursor returned in the x-next-cursor header of the previous page.
+    /// The opportunities are sorted by creation time and id, so that the opportunities with the same creation time are not missed.
+    #[param(value_type = Option<String>)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub cursor:         Option<OpportunityCursor>,
     /// The maximum number of opportunities to return. Capped at 100; if more than 100 requested, at most 100 will be returned.
     #[param(example = "20", value_type = usize, maximum = 100)]
     #[serde(default = "default_limit")]
-    limit:              usize,
+    pub limit:          usize,
+}
+
+/// A window during which only some searchers can bid on the opportunity.
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug, ToResponse)]
+pub struct OpportunityExclusivity {
+    /// The end of the window formatted in rfc3339. The opportunity is open to all the searchers afterwards.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = String)]
+    #[serde(with = "time::serde::rfc3339")]
+    pub end_time:    OffsetDateTime,
+    /// The profiles of the searchers that can bid on the opportunity during the window.
+    /// Only set when the opportunity is submitted, the allowlist is not shared with the searchers.
+    #[schema(value_type = Vec<String>, write_only)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profile_ids: Vec<models::ProfileId>,
 }
 
 // ----- Evm types -----
@@ -262,6 +376,15 @@ pub struct OpportunityCreateV1Evm {
 
     pub sell_tokens: Vec<TokenAmountEvm>,
     pub buy_tokens:  Vec<TokenAmountEvm>,
+
+    /// The time the opportunity expires formatted in rfc3339.
+    /// If not set, the server decides when the opportunity is removed.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub expiration_time: Option<OffsetDateTime>,
+    /// The window during which only the allowlisted searchers can bid on the opportunity.
+    #[serde(default)]
+    pub exclusivity:     Option<OpportunityExclusivity>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug, ToResponse)]
@@ -310,6 +433,53 @@ pub struct TokenAmountSvm {
     pub amount: u64,
 }
 
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LimoOrderStatus {
+    Active,
+    Filled,
+    Cancelled,
+}
+
+/// The decoded content of a Limo order.
+#[serde_as]
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug, ToResponse)]
+pub struct LimoOrderDetails {
+    /// The maker of the order.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub maker:                  Pubkey,
+    /// The global config account of the order.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub global_config:          Pubkey,
+    /// The mint of the token the maker sells.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub input_mint:             Pubkey,
+    /// The mint of the token the maker buys.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub output_mint:            Pubkey,
+    /// The input amount of the order when it was created.
+    #[schema(example = 1000)]
+    pub initial_input_amount:   u64,
+    /// The input amount left to be filled.
+    #[schema(example = 1000)]
+    pub remaining_input_amount: u64,
+    /// The output amount the maker expects for the initial input amount.
+    #[schema(example = 1000)]
+    pub expected_output_amount: u64,
+    /// The output amount the maker already received.
+    #[schema(example = 0)]
+    pub filled_output_amount:   u64,
+    /// The status of the order.
+    pub status:                 LimoOrderStatus,
+    /// The last time the order was updated (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000)]
+    pub last_updated_timestamp: u64,
+}
+
 /// Parameters needed to create a new opportunity from the Phantom wallet.
 /// Auction server will extract the output token price for the auction.
 #[serde_as]
@@ -327,9 +497,16 @@ pub struct QuoteCreatePhantomV1Svm {
     #[schema(example = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", value_type = String)]
     #[serde_as(as = "DisplayFromStr")]
     pub output_token_mint:           Pubkey,
-    /// The input token amount that the user wants to swap.
-    #[schema(example = 100)]
-    pub input_token_amount:          u64,
+    /// The exact input token amount that the user wants to swap.
+    /// Exactly one of the input and output token amounts should be specified.
+    #[schema(example = 100, value_type = Option<u64>)]
+    #[serde(default)]
+    pub input_token_amount:          Option<u64>,
+    /// The exact output token amount that the user wants to receive.
+    /// If specified, the searchers compete on the input token amount instead.
+    #[schema(example = 100, value_type = Option<u64>)]
+    #[serde(default)]
+    pub output_token_amount:         Option<u64>,
     /// The maximum slippage percentage that the user is willing to accept.
     #[schema(example = 0.5)]
     pub maximum_slippage_percentage: f64,
@@ -363,6 +540,9 @@ pub enum QuoteCreate {
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
 pub struct QuoteV1Svm {
+    /// The id of the quote, which can be used to track the status of its transaction.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:                          QuoteId,
     /// The signed transaction for the quote to be executed on chain which is valid until the expiration time.
     #[schema(example = "SGVsbG8sIFdvcmxkIQ==", value_type = String)]
     #[serde(with = "crate::serde::transaction_svm")]
@@ -371,8 +551,10 @@ pub struct QuoteV1Svm {
     #[schema(example = 1_700_000_000_000_000i64, value_type = i64)]
     pub expiration_time:             i64,
     /// The input token amount that the user wants to swap.
+    /// It is estimated by the server if the quote was created with an exact output amount.
     pub input_token:                 TokenAmountSvm,
     /// The output token amount that the user will receive.
+    /// It is estimated by the server if the quote was created with an exact input amount.
     pub output_token:                TokenAmountSvm,
     /// The maximum slippage percentage that the user is willing to accept.
     #[schema(example = 0.5)]
@@ -397,6 +579,133 @@ pub enum Quote {
     Svm(QuoteSvm),
 }
 
+/// The side of the swap with the exact amount specified by the user.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecifiedTokenSide {
+    /// The user sells an exact amount of the input token, which is the buy token of the searcher.
+    /// The searchers compete on the largest output amount.
+    Input,
+    /// The user buys an exact amount of the output token, which is the sell token of the searcher.
+    /// The searchers compete on the smallest input amount.
+    Output,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteStatus {
+    /// The signed transaction was returned and is not seen on chain yet.
+    Issued,
+    /// The transaction was submitted by the user and included on chain.
+    Submitted,
+    /// The transaction succeeded and is settled on chain.
+    Landed,
+    /// The transaction was not included on chain before the expiration time.
+    Expired,
+    /// The transaction was included on chain but failed.
+    Failed,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+pub struct QuoteDetails {
+    /// The id of the quote.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub id:                          QuoteId,
+    /// The status of the quote transaction.
+    pub status:                      QuoteStatus,
+    /// The chain id for the quote.
+    #[schema(example = "solana", value_type = String)]
+    pub chain_id:                    ChainId,
+    /// The id of the quote opportunity.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub opportunity_id:              OpportunityId,
+    /// The id of the winning bid of the quote.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub bid_id:                      BidId,
+    /// The user wallet address which requested the quote.
+    #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub user_wallet_address:         Pubkey,
+    /// The input token amount of the quote.
+    pub input_token:                 TokenAmountSvm,
+    /// The output token amount of the quote.
+    pub output_token:                TokenAmountSvm,
+    /// The maximum slippage percentage that the user is willing to accept.
+    #[schema(example = 0.5)]
+    pub maximum_slippage_percentage: f64,
+    /// The signature of the quote transaction.
+    #[schema(example = "Jb2urXPyEh4xiBgzYvwEFe4q1iMxG1DNxWGGQg94AmKgqFTwLAiTiHrYiYxwHUB4DV8u5ahNEVtMMDm3sNSRdTg", value_type = String)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub signature:                   SignatureSvm,
+    /// The expiration time of the quote (in seconds since the Unix epoch).
+    #[schema(example = 1_700_000_000i64, value_type = i64)]
+    pub expiration_time:             i64,
+    /// The time the quote was issued formatted in rfc3339.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = String)]
+    #[serde(with = "time::serde::rfc3339")]
+    pub creation_time:               OffsetDateTime,
+}
+
+/// The way the token contract calculates the storage keys of its mappings.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageLayout {
+    /// The key of the entry is hashed before the slot of the mapping.
+    Solidity,
+    /// The slot of the mapping is hashed before the key of the entry.
+    Vyper,
+}
+
+/// The storage slots of a token which can not be found by the server.
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+pub struct SpoofOverrideCreate {
+    /// The chain id of the token.
+    #[schema(example = "op_sepolia", value_type = String)]
+    pub chain_id:       ChainId,
+    /// The token contract address.
+    #[schema(example = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", value_type = String)]
+    pub token:          Address,
+    /// The slot of the balances mapping in the token storage.
+    #[schema(example = "3", value_type = String)]
+    #[serde(with = "crate::serde::u256")]
+    pub balance_slot:   U256,
+    /// The slot of the allowances mapping in the token storage.
+    #[schema(example = "4", value_type = String)]
+    #[serde(with = "crate::serde::u256")]
+    pub allowance_slot: U256,
+    /// The layout of the mappings in the token storage.
+    pub layout:         StorageLayout,
+}
+
+/// The opportunity created for a quote requested over the websocket.
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+pub struct QuoteRequestResult {
+    /// The id of the quote, used to accept or cancel it.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub quote_id:     OpportunityId,
+    /// The input token amount of the quote.
+    /// It is estimated by the server if the quote was created with an exact output amount.
+    pub input_token:  TokenAmountSvm,
+    /// The output token amount of the quote.
+    /// It is estimated by the server if the quote was created with an exact input amount.
+    pub output_token: TokenAmountSvm,
+}
+
+/// The best searcher bid so far on a quote requested over the websocket.
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+pub struct QuoteUpdate {
+    /// The id of the quote.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub quote_id:   OpportunityId,
+    /// The id of the bid, which should be used to accept the quote.
+    #[schema(example = "beedbeed-58cc-4372-a567-0e02b2c3d479", value_type = String)]
+    pub bid_id:     BidId,
+    /// The amount of the bid in lamports.
+    #[schema(example = 1000, value_type = u64)]
+    pub bid_amount: u64,
+}
+
 /// Program specific parameters for the opportunity.
 #[serde_as]
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
@@ -459,6 +768,15 @@ pub struct OpportunityCreateV1Svm {
     #[serde(flatten)]
     #[schema(inline)]
     pub program_params: OpportunityCreateProgramParamsV1Svm,
+
+    /// The time the opportunity expires formatted in rfc3339.
+    /// If not set, the server decides when the opportunity is removed.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub expiration_time: Option<OffsetDateTime>,
+    /// The window during which only the allowlisted searchers can bid on the opportunity.
+    #[serde(default)]
+    pub exclusivity:     Option<OpportunityExclusivity>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
@@ -476,24 +794,58 @@ pub enum ProgramSvm {
     Limo,
 }
 
-fn get_program(auth: &Auth) -> Result<ProgramSvm, RestError> {
+fn get_integrator_profile(auth: &Auth) -> Result<&models::Profile, RestError> {
     match auth {
         Auth::Authorized(_, profile) => {
             if profile.role == models::ProfileRole::Searcher {
                 return Err(RestError::Forbidden);
             }
-
-            match profile.name.as_str() {
-                "limo" => Ok(ProgramSvm::Limo),
-                "phantom" => Ok(ProgramSvm::Phantom),
-                _ => Err(RestError::Forbidden),
-            }
+            Ok(profile)
         }
         Auth::Admin => Err(RestError::Forbidden),
         Auth::Unauthorized => Err(RestError::Unauthorized),
     }
 }
 
+async fn get_program(
+    store: &StoreNew,
+    auth: &Auth,
+    chain_id: &ChainId,
+) -> Result<ProgramSvm, RestError> {
+    let profile = get_integrator_profile(auth)?;
+    if let Some(integrator) = store
+        .store
+        .get_integrator_by_profile(chain_id, profile.id)
+        .await
+    {
+        return Ok(integrator.program.into());
+    }
+
+    // Fallback for the profiles which are not linked to a registered integrator
+    match profile.name.as_str() {
+        "limo" => Ok(ProgramSvm::Limo),
+        "phantom" => Ok(ProgramSvm::Phantom),
+        _ => Err(RestError::Forbidden),
+    }
+}
+
+/// Checks that the permission key is in a namespace reserved for the profile.
+async fn verify_permission_key_owner(
+    store: &StoreNew,
+    profile: &models::Profile,
+    chain_id: &ChainId,
+    permission_key: &PermissionKey,
+) -> Result<(), RestError> {
+    match store
+        .store
+        .get_permission_key_owner(chain_id, permission_key)
+        .await
+    {
+        Some(profile_id) if profile_id == profile.id => Ok(()),
+        _ => Err(RestError::Forbidden),
+    }
+}
+
 impl OpportunityCreateSvm {
     fn get_program(&self) -> ProgramSvm {
         match self {
@@ -505,6 +857,16 @@ impl OpportunityCreateSvm {
     }
 }
 
+impl QuoteCreate {
+    fn get_program(&self) -> ProgramSvm {
+        match self {
+            QuoteCreate::Svm(QuoteCreateSvm::V1(QuoteCreateV1Svm::Phantom(_))) => {
+                ProgramSvm::Phantom
+            }
+        }
+    }
+}
+
 /// Program specific parameters for the opportunity.
 #[serde_as]
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug, ToResponse)]
@@ -524,6 +886,9 @@ pub enum OpportunityParamsV1ProgramSvm {
         #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
         #[serde_as(as = "DisplayFromStr")]
         order_address: Pubkey,
+        /// The decoded order, so that the searchers do not need to decode it themselves.
+        #[serde(default)]
+        order_details: Option<LimoOrderDetails>,
     },
     /// Phantom program specific parameters for the opportunity.
     #[serde(rename = "phantom")]
@@ -538,6 +903,16 @@ pub enum OpportunityParamsV1ProgramSvm {
         #[schema(example = 0.5, value_type = f64)]
         maximum_slippage_percentage: f64,
 
+        /// Confidence of the estimated buy token amount, between 0 and 1.
+        #[schema(example = 0.8, value_type = Option<f64>)]
+        #[serde(default)]
+        price_confidence: Option<f64>,
+
+        /// The side of the swap with the exact amount specified by the user.
+        /// The amount of the other side is estimated by the server.
+        #[schema(example = "input", value_type = SpecifiedTokenSide)]
+        specified_token_side: SpecifiedTokenSide,
+
         /// The permission account to be permitted by the ER contract for the opportunity execution of the protocol.
         #[schema(example = "DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5", value_type = String)]
         #[serde_as(as = "DisplayFromStr")]
@@ -565,6 +940,15 @@ pub struct OpportunityParamsV1Svm {
     pub program:  OpportunityParamsV1ProgramSvm,
     #[schema(example = "solana", value_type = String)]
     pub chain_id: ChainId,
+
+    /// The time the opportunity expires formatted in rfc3339.
+    /// If not set, the server decides when the opportunity is removed.
+    #[schema(example = "2024-05-23T21:26:57.329954Z", value_type = Option<String>)]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub expiration_time: Option<OffsetDateTime>,
+    /// The window during which only the allowlisted searchers can bid on the opportunity.
+    #[serde(default)]
+    pub exclusivity:     Option<OpportunityExclusivity>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug, ToResponse)]
@@ -624,6 +1008,21 @@ impl Opportunity {
             Opportunity::Svm(opportunity) => opportunity.creation_time,
         }
     }
+
+    pub fn get_opportunity_id(&self) -> OpportunityId {
+        match self {
+            Opportunity::Evm(opportunity) => opportunity.opportunity_id,
+            Opportunity::Svm(opportunity) => opportunity.opportunity_id,
+        }
+    }
+
+    fn get_cursor(&self) -> Option<OpportunityCursor> {
+        Some(OpportunityCursor(entities::OpportunityCursor {
+            creation_time: OffsetDateTime::from_unix_timestamp_nanos(self.creation_time() * 1000)
+                .ok()?,
+            id:            self.get_opportunity_id(),
+        }))
+    }
 }
 
 impl OpportunityDelete {
@@ -672,7 +1071,12 @@ pub async fn opportunity_bid(
 ///
 /// The opportunity will be verified by the server. If the opportunity is valid, it will be stored in the database
 /// and will be available for bidding.
-#[utoipa::path(post, path = "/v1/opportunities", request_body = OpportunityCreate, responses(
+/// Evm opportunities can only be submitted by the protocol which reserved the namespace of their permission key.
+#[utoipa::path(post, path = "/v1/opportunities", request_body = OpportunityCreate,
+security(
+    ("bearerAuth" = []),
+),
+responses(
     (status = 200, description = "The created opportunity", body = Opportunity),
     (status = 400, response = ErrorBodyResponse),
     (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
@@ -683,23 +1087,36 @@ pub async fn post_opportunity(
     Json(params): Json<OpportunityCreate>,
 ) -> Result<Json<Opportunity>, RestError> {
     let opportunity_with_metadata: Opportunity = match params {
-        OpportunityCreate::Evm(params) => store
-            .opportunity_service_evm
-            .add_opportunity(AddOpportunityInput {
-                opportunity: params.into(),
-            })
-            .await?
-            .into(),
+        OpportunityCreate::Evm(params) => {
+            let OpportunityCreateEvm::V1(params_v1) = &params;
+            let profile = get_integrator_profile(&auth)?;
+            verify_permission_key_owner(
+                &store,
+                profile,
+                &params_v1.chain_id,
+                &params_v1.permission_key,
+            )
+            .await?;
+
+            let mut opportunity: entities::OpportunityCreateEvm = params.into();
+            opportunity.core_fields.profile_id = Some(profile.id);
+            store
+                .opportunity_service_evm
+                .add_opportunity(AddOpportunityInput { opportunity })
+                .await?
+                .into()
+        }
         OpportunityCreate::Svm(params) => {
-            if get_program(&auth)? != params.get_program() {
+            let OpportunityCreateSvm::V1(params_v1) = &params;
+            if get_program(&store, &auth, &params_v1.chain_id).await? != params.get_program() {
                 return Err(RestError::Forbidden);
             }
 
+            let mut opportunity: entities::OpportunityCreateSvm = params.into();
+            opportunity.core_fields.profile_id = Some(get_integrator_profile(&auth)?.id);
             store
                 .opportunity_service_svm
-                .add_opportunity(AddOpportunityInput {
-                    opportunity: params.into(),
-                })
+                .add_opportunity(AddOpportunityInput { opportunity })
                 .await?
                 .into()
         }
@@ -709,10 +1126,12 @@ pub async fn post_opportunity(
 
 /// Fetch opportunities ready for execution or historical opportunities
 /// depending on the mode. You need to provide `chain_id` for historical mode.
-/// Opportunities are sorted by creation time in ascending order.
+/// Opportunities are sorted by creation time and id in ascending order.
 /// Total number of opportunities returned is capped by the server to preserve bandwidth.
+/// If the page is full, the cursor of the next page is returned in the x-next-cursor header.
 #[utoipa::path(get, path = "/v1/opportunities", responses(
-(status = 200, description = "Array of opportunities ready for bidding", body = Vec < Opportunity >),
+(status = 200, description = "Array of opportunities ready for bidding", body = Vec < Opportunity >,
+headers(("x-next-cursor" = String, description = "The cursor of the next page of opportunities"))),
 (status = 400, response = ErrorBodyResponse),
 (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
 ),
@@ -720,7 +1139,7 @@ params(GetOpportunitiesQueryParams))]
 pub async fn get_opportunities(
     State(store): State<Arc<StoreNew>>,
     query_params: Query<GetOpportunitiesQueryParams>,
-) -> Result<axum::Json<Vec<Opportunity>>, RestError> {
+) -> Result<(HeaderMap, axum::Json<Vec<Opportunity>>), RestError> {
     let opportunities_evm = store
         .opportunity_service_evm
         .get_opportunities(GetOpportunitiesInput {
@@ -756,13 +1175,19 @@ pub async fn get_opportunities(
             );
         }
 
-        opportunities.sort_by_key(|a| a.creation_time());
-        Ok(Json(
-            opportunities
-                .into_iter()
-                .take(std::cmp::min(query_params.limit, OPPORTUNITY_PAGE_SIZE_CAP))
-                .collect(),
-        ))
+        opportunities.sort_by_key(|a| (a.creation_time(), a.get_opportunity_id()));
+        let limit = std::cmp::min(query_params.limit, OPPORTUNITY_PAGE_SIZE_CAP);
+        let opportunities: Vec<Opportunity> = opportunities.into_iter().take(limit).collect();
+
+        let mut headers = HeaderMap::new();
+        if opportunities.len() == limit {
+            if let Some(cursor) = opportunities.last().and_then(|o| o.get_cursor()) {
+                if let Ok(cursor) = HeaderValue::from_str(&cursor.to_string()) {
+                    headers.insert(NEXT_CURSOR_HEADER, cursor);
+                }
+            }
+        }
+        Ok((headers, Json(opportunities)))
     }
 }
 
@@ -770,6 +1195,8 @@ pub async fn get_opportunities(
 ///
 /// The server will estimate the quote price, which will be used to create an opportunity.
 /// After a certain time, searcher bids are collected, the winning signed bid will be returned along with the estimated price.
+/// Wallets which want to show a live price can request the quote over the websocket instead,
+/// and accept one of the streamed bids.
 #[utoipa::path(post, path = "/v1/opportunities/quote", request_body = QuoteCreate, responses(
     (status = 200, description = "The created quote", body = Quote),
     (status = 400, response = ErrorBodyResponse),
@@ -780,20 +1207,58 @@ pub async fn post_quote(
     State(store): State<Arc<StoreNew>>,
     Json(params): Json<QuoteCreate>,
 ) -> Result<Json<Quote>, RestError> {
-    if get_program(&auth)? != ProgramSvm::Phantom {
-        return Err(RestError::Forbidden);
-    }
-
     let quote = store
         .opportunity_service_svm
-        .get_quote(GetQuoteInput {
-            quote_create: params.into(),
-        })
+        .get_quote(get_quote_input(&store, &auth, params).await?)
         .await?;
 
     Ok(Json(quote.into()))
 }
 
+/// Query the status of a quote.
+///
+/// The status follows the transaction of the quote until it lands on chain or expires.
+#[utoipa::path(get, path = "/v1/opportunities/quote/{quote_id}",
+    params(("quote_id" = String, description = "Quote id to query for")), responses(
+    (status = 200, description = "The quote with its status", body = QuoteDetails),
+    (status = 400, response = ErrorBodyResponse),
+    (status = 404, description = "Quote was not found", body = ErrorBodyResponse),
+),)]
+pub async fn get_quote_status(
+    State(store): State<Arc<StoreNew>>,
+    Path(quote_id): Path<QuoteId>,
+) -> Result<Json<QuoteDetails>, RestError> {
+    let quote = store
+        .opportunity_service_svm
+        .get_quote_details(GetQuoteDetailsInput { quote_id })
+        .await?;
+    Ok(Json(quote.into()))
+}
+
+/// Checks that the quote is requested by an integrator registered for the quote program
+/// and attaches the router of the integrator.
+pub async fn get_quote_input(
+    store: &StoreNew,
+    auth: &Auth,
+    params: QuoteCreate,
+) -> Result<GetQuoteInput, RestError> {
+    let program = params.get_program();
+    let quote_create: entities::QuoteCreate = params.try_into()?;
+    let profile = get_integrator_profile(auth)?;
+    let integrator = store
+        .store
+        .get_integrator_by_profile(&quote_create.chain_id, profile.id)
+        .await
+        .filter(|integrator| ProgramSvm::from(integrator.program.clone()) == program)
+        .ok_or(RestError::Forbidden)?;
+
+    Ok(GetQuoteInput {
+        quote_create,
+        router: integrator.get_router_account(),
+        integrator_name: integrator.name,
+    })
+}
+
 /// Delete all opportunities for specified data.
 #[utoipa::path(delete, path = "/v1/opportunities", request_body = OpportunityDelete,
 security(
@@ -810,19 +1275,37 @@ pub async fn delete_opportunities(
     Json(opportunity_delete): Json<OpportunityDelete>,
 ) -> Result<StatusCode, RestError> {
     match opportunity_delete {
-        OpportunityDelete::Evm(_) => Err(RestError::Forbidden),
+        OpportunityDelete::Evm(params_evm) => {
+            let OpportunityDeleteEvm::V1(params) = params_evm;
+            let profile = get_integrator_profile(&auth)?;
+            verify_permission_key_owner(&store, profile, &params.chain_id, &params.permission_key)
+                .await?;
+
+            store
+                .opportunity_service_evm
+                .remove_opportunities(RemoveOpportunitiesInput {
+                    key: entities::OpportunityKey(params.chain_id, params.permission_key),
+                })
+                .await?;
+
+            Ok(StatusCode::NO_CONTENT)
+        }
         OpportunityDelete::Svm(params_svm) => {
             let OpportunityDeleteSvm::V1(params) = params_svm;
-            if get_program(&auth)? != params.program {
+            if get_program(&store, &auth, &params.chain_id).await? != params.program {
                 return Err(RestError::Forbidden);
             }
 
             store
                 .opportunity_service_svm
                 .remove_opportunities(RemoveOpportunitiesInput {
-                    chain_id:           params.chain_id,
-                    permission_account: params.permission_account,
-                    router:             params.router,
+                    key: entities::OpportunityKey(
+                        params.chain_id,
+                        entities::OpportunitySvm::get_permission_key(
+                            params.router,
+                            params.permission_account,
+                        ),
+                    ),
                 })
                 .await?;
 
@@ -831,11 +1314,43 @@ pub async fn delete_opportunities(
     }
 }
 
+/// Set the balance and allowance slots of an ERC20 token.
+///
+/// The slots are used to spoof the balances of the tokens when verifying the evm opportunities.
+/// They take precedence over the slots found by the server and are persisted across restarts.
+#[utoipa::path(post, path = "/v1/opportunities/spoof_info", request_body = SpoofOverrideCreate,
+    security(
+        ("bearerAuth" = []),
+    ),
+    responses(
+    (status = 204, description = "The slots were set for the token"),
+    (status = 400, response = ErrorBodyResponse),
+    (status = 404, description = "Chain id was not found", body = ErrorBodyResponse),
+),)]
+pub async fn post_spoof_override(
+    State(store): State<Arc<StoreNew>>,
+    Json(params): Json<SpoofOverrideCreate>,
+) -> Result<StatusCode, RestError> {
+    store
+        .opportunity_service_evm
+        .add_spoof_override(AddSpoofOverrideInput {
+            chain_id:       params.chain_id,
+            token:          params.token,
+            balance_slot:   params.balance_slot,
+            allowance_slot: params.allowance_slot,
+            layout:         params.layout.into(),
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub fn get_routes(store: Arc<StoreNew>) -> Router<Arc<StoreNew>> {
     Router::new()
         .route("/", post(post_opportunity))
         .route("/quote", post(post_quote))
+        .route("/quote/:quote_id", get(get_quote_status))
         .route("/", get(get_opportunities))
         .route("/:opportunity_id/bids", post(opportunity_bid))
         .route("/", login_required!(store, delete(delete_opportunities)))
+        .route("/spoof_info", admin_only!(store, post(post_spoof_override)))
 }
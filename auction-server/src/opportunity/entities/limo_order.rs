@@ -0,0 +1,231 @@
+use {
+    anyhow::{
+        anyhow,
+        Result,
+    },
+    solana_sdk::{
+        pubkey,
+        pubkey::Pubkey,
+    },
+};
+
+/// The Limo program which owns the order accounts.
+pub const LIMO_PROGRAM_ID: Pubkey = pubkey!("LiMoM9rMhrdYrfzUCxQppvxCSG1FcrUK9G8uLq4A1GF");
+/// Seed of the authority pda of a Limo global config.
+const LIMO_AUTHORITY_SEED: &[u8] = b"authority";
+/// Anchor discriminator of the Limo order account.
+const LIMO_ORDER_DISCRIMINATOR: [u8; 8] = [134, 173, 223, 185, 77, 86, 28, 51];
+/// Size of the Limo order account data, including the discriminator and the trailing padding.
+const LIMO_ORDER_SIZE: usize = 424;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LimoOrderStatus {
+    Active,
+    Filled,
+    Cancelled,
+}
+
+/// The decoded content of a Limo order account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimoOrder {
+    pub global_config:          Pubkey,
+    pub maker:                  Pubkey,
+    pub input_mint:             Pubkey,
+    pub input_mint_program_id:  Pubkey,
+    pub output_mint:            Pubkey,
+    pub output_mint_program_id: Pubkey,
+    pub initial_input_amount:   u64,
+    pub expected_output_amount: u64,
+    pub remaining_input_amount: u64,
+    pub filled_output_amount:   u64,
+    pub tip_amount:             u64,
+    pub number_of_fills:        u64,
+    pub status:                 LimoOrderStatus,
+    pub last_updated_timestamp: u64,
+}
+
+struct Reader<'a> {
+    data:   &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn read<const N: usize>(&mut self) -> [u8; N] {
+        let bytes = self.data[self.offset..self.offset + N]
+            .try_into()
+            .expect("slice length is N");
+        self.offset += N;
+        bytes
+    }
+
+    fn read_pubkey(&mut self) -> Pubkey {
+        Pubkey::new_from_array(self.read::<32>())
+    }
+
+    fn read_u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.read::<8>())
+    }
+
+    fn read_u8(&mut self) -> u8 {
+        self.read::<1>()[0]
+    }
+}
+
+impl LimoOrder {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self> {
+        if data.len() != LIMO_ORDER_SIZE {
+            return Err(anyhow!(
+                "Invalid limo order size: expected {} bytes, got {}",
+                LIMO_ORDER_SIZE,
+                data.len()
+            ));
+        }
+        if data[..8] != LIMO_ORDER_DISCRIMINATOR {
+            return Err(anyhow!("Invalid limo order discriminator"));
+        }
+
+        let mut reader = Reader { data, offset: 8 };
+        let global_config = reader.read_pubkey();
+        let maker = reader.read_pubkey();
+        let input_mint = reader.read_pubkey();
+        let input_mint_program_id = reader.read_pubkey();
+        let output_mint = reader.read_pubkey();
+        let output_mint_program_id = reader.read_pubkey();
+        let initial_input_amount = reader.read_u64();
+        let expected_output_amount = reader.read_u64();
+        let remaining_input_amount = reader.read_u64();
+        let filled_output_amount = reader.read_u64();
+        let tip_amount = reader.read_u64();
+        let number_of_fills = reader.read_u64();
+        let _order_type = reader.read_u8();
+        let status = match reader.read_u8() {
+            0 => LimoOrderStatus::Active,
+            1 => LimoOrderStatus::Filled,
+            2 => LimoOrderStatus::Cancelled,
+            status => return Err(anyhow!("Invalid limo order status: {}", status)),
+        };
+        // in_vault_bump, flash_ix_lock and padding
+        reader.read::<6>();
+        let last_updated_timestamp = reader.read_u64();
+
+        Ok(LimoOrder {
+            global_config,
+            maker,
+            input_mint,
+            input_mint_program_id,
+            output_mint,
+            output_mint_program_id,
+            initial_input_amount,
+            expected_output_amount,
+            remaining_input_amount,
+            filled_output_amount,
+            tip_amount,
+            number_of_fills,
+            status,
+            last_updated_timestamp,
+        })
+    }
+
+    /// The express relay router of the order, which is the authority pda of its global config.
+    pub fn get_router(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[LIMO_AUTHORITY_SEED, self.global_config.as_ref()],
+            &LIMO_PROGRAM_ID,
+        )
+        .0
+    }
+
+    /// Amount of the output token the maker still expects to receive.
+    pub fn get_remaining_output_amount(&self) -> u64 {
+        self.expected_output_amount
+            .saturating_sub(self.filled_output_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        base64::{
+            engine::general_purpose::STANDARD,
+            Engine,
+        },
+        std::str::FromStr,
+    };
+
+    /// An active Limo order account, constructed with the `Order` layout of the Limo program idl.
+    /// The order expects 5 SOL for 1000 USDC and is filled 3 times, with 600 USDC remaining.
+    const ORDER_ACCOUNT_DATA: &str = concat!(
+        "hq3fuU1WHDMBAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fILlfBS87DFuPeyHUMc8JZu6B",
+        "bDw1nBS+i8DBncyLAceaxvp6877brTo9ZfNqq8l0MbG75MLS9uDkfKYCA0UvXWEG3fbh12Whk9nL",
+        "4UbO63msHLSF7V9bN5E6jPWFfv8AqQabiFf+q4GE+2h/Y0YYwDXaxDncGus7VZig8AAAAAABBt32",
+        "4ddloZPZy+FGzut5rBy0he1fWzeROoz1hX7/AKkAypo7AAAAAADyBSoBAAAAAEbDIwAAAAAAlDV3",
+        "AAAAAOgDAAAAAAAAAwAAAAAAAAAAAP4AAAAAAOA2WWcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==",
+    );
+    /// Offset of the status in the account data, after the discriminator, the keys, the amounts and the order type.
+    const STATUS_OFFSET: usize = 249;
+
+    fn get_order_account_data() -> Vec<u8> {
+        STANDARD
+            .decode(ORDER_ACCOUNT_DATA)
+            .expect("Failed to decode the order account data")
+    }
+
+    fn pubkey(address: &str) -> Pubkey {
+        Pubkey::from_str(address).expect("Failed to parse pubkey")
+    }
+
+    #[test]
+    fn test_decode_limo_order() {
+        let order = LimoOrder::try_from_slice(&get_order_account_data())
+            .expect("Failed to decode the limo order");
+        assert_eq!(
+            order,
+            LimoOrder {
+                global_config:          pubkey("4wBqpZM9xaSheZzJSMawUKKwhdpChKbZ5eu5ky4Vigw"),
+                maker:                  pubkey("DUcTi3rDyS5QEmZ4BNRBejtArmDCWaPYGfN44vBJXKL5"),
+                input_mint:             pubkey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+                input_mint_program_id:  pubkey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+                output_mint:            pubkey("So11111111111111111111111111111111111111112"),
+                output_mint_program_id: pubkey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+                initial_input_amount:   1_000_000_000,
+                expected_output_amount: 5_000_000_000,
+                remaining_input_amount: 600_000_000,
+                filled_output_amount:   2_000_000_000,
+                tip_amount:             1_000,
+                number_of_fills:        3,
+                status:                 LimoOrderStatus::Active,
+                last_updated_timestamp: 1_733_900_000,
+            }
+        );
+        assert_eq!(order.get_remaining_output_amount(), 3_000_000_000);
+        assert_eq!(
+            order.get_router(),
+            pubkey("EGEPMQRm6WJPgrhvNyQz1NeJqeDXiMfkC9rs5QSX7UM8")
+        );
+    }
+
+    #[test]
+    fn test_decode_limo_order_status() {
+        let mut data = get_order_account_data();
+        data[STATUS_OFFSET] = 1;
+        let order = LimoOrder::try_from_slice(&data).expect("Failed to decode the limo order");
+        assert_eq!(order.status, LimoOrderStatus::Filled);
+
+        data[STATUS_OFFSET] = 3;
+        assert!(LimoOrder::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_decode_limo_order_with_invalid_account() {
+        let data = get_order_account_data();
+        assert!(LimoOrder::try_from_slice(&data[..LIMO_ORDER_SIZE - 1]).is_err());
+
+        let mut data = data;
+        data[0] = 0;
+        assert!(LimoOrder::try_from_slice(&data).is_err());
+    }
+}
@@ -1,6 +1,8 @@
+mod limo_order;
 mod opportunity;
 mod opportunity_evm;
 mod opportunity_svm;
+mod price_estimate;
 mod quote;
 mod spoof_info;
 mod token_amount;
@@ -8,9 +10,11 @@ mod token_amount_evm;
 mod token_amount_svm;
 
 pub use {
+    limo_order::*,
     opportunity::*,
     opportunity_evm::*,
     opportunity_svm::*,
+    price_estimate::*,
     quote::*,
     spoof_info::*,
     token_amount_svm::*,
@@ -6,6 +6,7 @@ use {
             ChainId,
             PermissionKey,
         },
+        models::ProfileId,
         opportunity::{
             api,
             repository::{
@@ -13,7 +14,12 @@ use {
             },
         },
     },
-    ethers::types::Bytes,
+    ethers::types::{
+        Bytes,
+        U256,
+    },
+    serde_json::Value as JsonValue,
+    solana_sdk::pubkey::Pubkey,
     std::{
         fmt::Debug,
         ops::Deref,
@@ -27,37 +33,269 @@ pub type OpportunityId = Uuid;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OpportunityKey(pub ChainId, pub PermissionKey);
 
+/// The lifecycle of an opportunity.
+/// An opportunity can not be removed while its auction is running, so that its bids are not
+/// considered invalid before the auction result is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpportunityState {
+    /// The opportunity accepts bids.
+    Live,
+    /// The winner bids of the opportunity are being selected and submitted.
+    InAuction,
+    /// The auction result is known. The opportunity can be auctioned again if it is still valid.
+    AuctionConcluded,
+    /// The opportunity is not valid anymore, the reason is stored with the removal.
+    Removed,
+}
+
+impl OpportunityState {
+    /// The states from which an opportunity can move to this state.
+    pub fn get_previous_states(&self) -> Vec<OpportunityState> {
+        match self {
+            OpportunityState::Live => vec![OpportunityState::InAuction],
+            OpportunityState::InAuction => {
+                vec![OpportunityState::Live, OpportunityState::AuctionConcluded]
+            }
+            OpportunityState::AuctionConcluded => vec![OpportunityState::InAuction],
+            OpportunityState::Removed => {
+                vec![OpportunityState::Live, OpportunityState::AuctionConcluded]
+            }
+        }
+    }
+}
+
+/// A window during which only the allowlisted searchers can bid on the opportunity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpportunityExclusivity {
+    pub end_time:    OffsetDateTime,
+    pub profile_ids: Vec<ProfileId>,
+}
+
+impl OpportunityExclusivity {
+    pub fn is_allowlisted(&self, profile_id: Option<ProfileId>) -> bool {
+        profile_id.is_some_and(|profile_id| self.profile_ids.contains(&profile_id))
+    }
+
+    pub fn is_open_to(&self, profile_id: Option<ProfileId>) -> bool {
+        self.end_time <= OffsetDateTime::now_utc() || self.is_allowlisted(profile_id)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct OpportunityCoreFields<T: TokenAmount> {
-    pub id:             OpportunityId,
-    pub permission_key: Bytes,
-    pub chain_id:       ChainId,
-    pub sell_tokens:    Vec<T>,
-    pub buy_tokens:     Vec<T>,
-    pub creation_time:  OffsetDateTime,
-    pub refresh_time:   OffsetDateTime,
+    pub id:              OpportunityId,
+    pub permission_key:  Bytes,
+    pub chain_id:        ChainId,
+    pub sell_tokens:     Vec<T>,
+    pub buy_tokens:      Vec<T>,
+    pub creation_time:   OffsetDateTime,
+    pub refresh_time:    OffsetDateTime,
+    pub state:           OpportunityState,
+    /// The time set by the protocol after which the opportunity is removed.
+    pub expiration_time: Option<OffsetDateTime>,
+    pub exclusivity:     Option<OpportunityExclusivity>,
+    /// The profile which submitted the opportunity.
+    pub profile_id:      Option<ProfileId>,
 }
 
 impl<T: TokenAmount> OpportunityCoreFields<T> {
     pub fn new_with_current_time(val: OpportunityCoreFieldsCreate<T>) -> Self {
         Self {
-            id:             Uuid::new_v4(),
-            permission_key: val.permission_key,
-            chain_id:       val.chain_id,
-            sell_tokens:    val.sell_tokens,
-            buy_tokens:     val.buy_tokens,
-            creation_time:  OffsetDateTime::now_utc(),
-            refresh_time:   OffsetDateTime::now_utc(),
+            id:              Uuid::new_v4(),
+            permission_key:  val.permission_key,
+            chain_id:        val.chain_id,
+            sell_tokens:     val.sell_tokens,
+            buy_tokens:      val.buy_tokens,
+            creation_time:   OffsetDateTime::now_utc(),
+            refresh_time:    OffsetDateTime::now_utc(),
+            state:           OpportunityState::Live,
+            expiration_time: val.expiration_time,
+            exclusivity:     val.exclusivity,
+            profile_id:      val.profile_id,
         }
     }
+
+    pub fn is_expired(&self) -> bool {
+        self.expiration_time
+            .is_some_and(|expiration_time| expiration_time <= OffsetDateTime::now_utc())
+    }
+
+    /// Whether the searcher with the given profile can bid on the opportunity at the moment.
+    pub fn is_open_to(&self, profile_id: Option<ProfileId>) -> bool {
+        self.exclusivity
+            .as_ref()
+            .map_or(true, |exclusivity| exclusivity.is_open_to(profile_id))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct OpportunityCoreFieldsCreate<T: TokenAmount> {
-    pub permission_key: Bytes,
+    pub permission_key:  Bytes,
+    pub chain_id:        ChainId,
+    pub sell_tokens:     Vec<T>,
+    pub buy_tokens:      Vec<T>,
+    pub expiration_time: Option<OffsetDateTime>,
+    pub exclusivity:     Option<OpportunityExclusivity>,
+    pub profile_id:      Option<ProfileId>,
+}
+
+impl<T: TokenAmount> OpportunityCoreFieldsCreate<T> {
+    pub fn validate_lifetime(&self) -> Result<(), RestError> {
+        let now = OffsetDateTime::now_utc();
+        if let Some(expiration_time) = self.expiration_time {
+            if expiration_time <= now {
+                return Err(RestError::BadParameters(
+                    "Expiration time should be in the future".to_string(),
+                ));
+            }
+        }
+        if let Some(exclusivity) = &self.exclusivity {
+            if exclusivity.end_time <= now {
+                return Err(RestError::BadParameters(
+                    "Exclusivity end time should be in the future".to_string(),
+                ));
+            }
+            if self
+                .expiration_time
+                .is_some_and(|expiration_time| expiration_time <= exclusivity.end_time)
+            {
+                return Err(RestError::BadParameters(
+                    "Exclusivity end time should be before the expiration time".to_string(),
+                ));
+            }
+            if exclusivity.profile_ids.is_empty() {
+                return Err(RestError::BadParameters(
+                    "Exclusivity should allow at least one profile".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A position in the list of opportunities sorted by creation time and id.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpportunityCursor {
+    pub creation_time: OffsetDateTime,
+    pub id:            OpportunityId,
+}
+
+impl OpportunityCursor {
+    /// Whether the opportunity comes after the cursor.
+    /// The creation times are compared with the microsecond precision they are stored with.
+    pub fn precedes(&self, creation_time: OffsetDateTime, id: OpportunityId) -> bool {
+        (creation_time.unix_timestamp_nanos() / 1000, id)
+            > (self.creation_time.unix_timestamp_nanos() / 1000, self.id)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OpportunityFilter {
+    pub chain_id:       Option<ChainId>,
+    pub permission_key: Option<PermissionKey>,
+    pub from_time:      Option<OffsetDateTime>,
+    pub cursor:         Option<OpportunityCursor>,
+    pub program:        Option<api::ProgramSvm>,
+    pub router:         Option<Pubkey>,
+    /// The token on either side of the opportunity, as it is stored in the token amounts.
+    pub token:          Option<JsonValue>,
+    pub profile_id:     Option<ProfileId>,
+}
+
+impl OpportunityFilter {
+    /// The program, router and tokens are matched against their stored representation,
+    /// in the same way the historical opportunities are filtered in the database.
+    pub fn matches<T: Opportunity>(&self, opportunity: &T) -> bool {
+        let metadata = serde_json::to_value(opportunity.get_models_metadata()).unwrap_or_default();
+        let has_token = |token: &JsonValue| {
+            [
+                serde_json::to_value(&opportunity.sell_tokens),
+                serde_json::to_value(&opportunity.buy_tokens),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|tokens| {
+                tokens.as_array().is_some_and(|tokens| {
+                    tokens
+                        .iter()
+                        .any(|token_amount| token_amount.get("token") == Some(token))
+                })
+            })
+        };
+
+        self.chain_id
+            .as_ref()
+            .map_or(true, |chain_id| opportunity.chain_id == *chain_id)
+            && self.permission_key.as_ref().map_or(true, |permission_key| {
+                opportunity.permission_key == *permission_key
+            })
+            && self
+                .from_time
+                .map_or(true, |from_time| opportunity.creation_time >= from_time)
+            && self.cursor.map_or(true, |cursor| {
+                cursor.precedes(opportunity.creation_time, opportunity.id)
+            })
+            && self.program.as_ref().map_or(true, |program| {
+                metadata.get("program") == serde_json::to_value(program).ok().as_ref()
+            })
+            && self.router.map_or(true, |router| {
+                metadata.get("router") == Some(&JsonValue::String(router.to_string()))
+            })
+            && self.token.as_ref().map_or(true, has_token)
+            && self.profile_id.map_or(true, |profile_id| {
+                opportunity.profile_id == Some(profile_id)
+            })
+    }
+}
+
+/// The fields of an opportunity the websocket subscriptions filter on, regardless of its chain type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpportunitySubscriptionFields {
     pub chain_id:       ChainId,
-    pub sell_tokens:    Vec<T>,
-    pub buy_tokens:     Vec<T>,
+    pub permission_key: PermissionKey,
+    pub program:        Option<api::ProgramSvm>,
+    pub router:         Option<Pubkey>,
+    /// The tokens on both sides of the opportunity with their amounts.
+    /// The evm tokens are in lowercase hex format and the svm mints in base58 format.
+    pub tokens:         Vec<(String, U256)>,
+    pub exclusivity:    Option<OpportunityExclusivity>,
+}
+
+/// A websocket subscription to a subset of the opportunities of a chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpportunitySubscriptionFilter {
+    pub chain_id:       ChainId,
+    pub program:        Option<api::ProgramSvm>,
+    pub router:         Option<Pubkey>,
+    pub permission_key: Option<PermissionKey>,
+    /// The opportunity should have one of these tokens on either side.
+    pub tokens:         Vec<String>,
+    /// The minimum amount of one of the filter tokens in the opportunity.
+    pub min_amount:     Option<U256>,
+}
+
+impl OpportunitySubscriptionFilter {
+    pub fn matches(&self, fields: &OpportunitySubscriptionFields) -> bool {
+        let has_token = self.tokens.is_empty()
+            || fields.tokens.iter().any(|(token, amount)| {
+                self.tokens.contains(token)
+                    && self
+                        .min_amount
+                        .map_or(true, |min_amount| *amount >= min_amount)
+            });
+        self.chain_id == fields.chain_id
+            && self
+                .program
+                .as_ref()
+                .map_or(true, |program| fields.program.as_ref() == Some(program))
+            && self
+                .router
+                .map_or(true, |router| fields.router == Some(router))
+            && self.permission_key.as_ref().map_or(true, |permission_key| {
+                fields.permission_key == *permission_key
+            })
+            && has_token
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -85,19 +323,26 @@ pub trait Opportunity:
 
     fn new_with_current_time(val: Self::OpportunityCreate) -> Self;
     fn get_models_metadata(&self) -> Self::ModelMetadata;
-    fn get_opportunity_delete(&self) -> api::OpportunityDelete;
+    fn get_opportunity_delete(
+        &self,
+        reason: api::OpportunityRemovalReason,
+    ) -> api::OpportunityDelete;
     fn get_key(&self) -> OpportunityKey {
         OpportunityKey(self.chain_id.clone(), self.permission_key.clone())
     }
 
+    fn get_subscription_fields(&self) -> OpportunitySubscriptionFields;
+
     fn compare(&self, other: &Self::OpportunityCreate) -> OpportunityComparison;
     fn refresh(&mut self);
+    fn set_state(&mut self, state: OpportunityState);
 }
 
 pub trait OpportunityCreate: Debug + Clone + From<Self::ApiOpportunityCreate> + PartialEq {
     type ApiOpportunityCreate;
 
     fn get_key(&self) -> OpportunityKey;
+    fn validate_lifetime(&self) -> Result<(), RestError>;
 }
 
 #[derive(Debug)]
@@ -113,6 +358,15 @@ pub enum OpportunityVerificationResult {
     UnableToSpoof,
 }
 
+impl From<&OpportunityRemovalReason> for api::OpportunityRemovalReason {
+    fn from(reason: &OpportunityRemovalReason) -> Self {
+        match reason {
+            OpportunityRemovalReason::Expired => api::OpportunityRemovalReason::Expired,
+            OpportunityRemovalReason::Invalid(_) => api::OpportunityRemovalReason::Invalid,
+        }
+    }
+}
+
 impl From<OpportunityRemovalReason> for repository::OpportunityRemovalReason {
     fn from(reason: OpportunityRemovalReason) -> Self {
         match reason {
@@ -121,3 +375,22 @@ impl From<OpportunityRemovalReason> for repository::OpportunityRemovalReason {
         }
     }
 }
+
+impl From<api::OpportunityExclusivity> for OpportunityExclusivity {
+    fn from(val: api::OpportunityExclusivity) -> Self {
+        OpportunityExclusivity {
+            end_time:    val.end_time,
+            profile_ids: val.profile_ids,
+        }
+    }
+}
+
+impl From<OpportunityExclusivity> for api::OpportunityExclusivity {
+    fn from(val: OpportunityExclusivity) -> Self {
+        // The allowlist is only used by the server and is not shared with the searchers
+        api::OpportunityExclusivity {
+            end_time:    val.end_time,
+            profile_ids: vec![],
+        }
+    }
+}
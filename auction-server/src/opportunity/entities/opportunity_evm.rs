@@ -10,6 +10,7 @@ use {
         OpportunityCreate,
     },
     crate::{
+        api::RestError,
         kernel::entities::PermissionKey,
         opportunity::{
             api,
@@ -67,13 +68,33 @@ impl Opportunity for OpportunityEvm {
         }
     }
 
-    fn get_opportunity_delete(&self) -> api::OpportunityDelete {
+    fn get_opportunity_delete(
+        &self,
+        reason: api::OpportunityRemovalReason,
+    ) -> api::OpportunityDelete {
         api::OpportunityDelete::Evm(api::OpportunityDeleteEvm::V1(api::OpportunityDeleteV1Evm {
             permission_key: self.core_fields.permission_key.clone(),
             chain_id:       self.core_fields.chain_id.clone(),
+            reason:         Some(reason),
         }))
     }
 
+    fn get_subscription_fields(&self) -> super::OpportunitySubscriptionFields {
+        super::OpportunitySubscriptionFields {
+            chain_id:       self.chain_id.clone(),
+            permission_key: self.permission_key.clone(),
+            program:        None,
+            router:         None,
+            tokens:         self
+                .sell_tokens
+                .iter()
+                .chain(self.buy_tokens.iter())
+                .map(|token| (format!("{:?}", token.token), token.amount))
+                .collect(),
+            exclusivity:    self.exclusivity.clone(),
+        }
+    }
+
     fn compare(&self, other: &Self::OpportunityCreate) -> super::OpportunityComparison {
         if *other == self.clone().into() {
             OpportunityComparison::Duplicate
@@ -85,6 +106,10 @@ impl Opportunity for OpportunityEvm {
     fn refresh(&mut self) {
         self.core_fields.refresh_time = OffsetDateTime::now_utc();
     }
+
+    fn set_state(&mut self, state: super::OpportunityState) {
+        self.core_fields.state = state;
+    }
 }
 
 impl OpportunityCreate for OpportunityCreateEvm {
@@ -96,6 +121,10 @@ impl OpportunityCreate for OpportunityCreateEvm {
             self.core_fields.permission_key.clone(),
         )
     }
+
+    fn validate_lifetime(&self) -> Result<(), RestError> {
+        self.core_fields.validate_lifetime()
+    }
 }
 
 impl Deref for OpportunityEvm {
@@ -136,6 +165,11 @@ impl From<OpportunityEvm> for api::OpportunityEvm {
                         .into_iter()
                         .map(|t| t.into())
                         .collect(),
+                    expiration_time:   val.expiration_time,
+                    exclusivity:       val
+                        .exclusivity
+                        .clone()
+                        .map(|exclusivity| exclusivity.into()),
                 },
             )),
         }
@@ -147,10 +181,13 @@ impl From<api::OpportunityCreateEvm> for OpportunityCreateEvm {
         let api::OpportunityCreateEvm::V1(params) = val;
         OpportunityCreateEvm {
             core_fields:       OpportunityCoreFieldsCreate::<TokenAmountEvm> {
-                permission_key: params.permission_key,
-                chain_id:       params.chain_id,
-                sell_tokens:    params.sell_tokens.into_iter().map(|t| t.into()).collect(),
-                buy_tokens:     params.buy_tokens.into_iter().map(|t| t.into()).collect(),
+                permission_key:  params.permission_key,
+                chain_id:        params.chain_id,
+                sell_tokens:     params.sell_tokens.into_iter().map(|t| t.into()).collect(),
+                buy_tokens:      params.buy_tokens.into_iter().map(|t| t.into()).collect(),
+                expiration_time: params.expiration_time,
+                exclusivity:     params.exclusivity.map(|exclusivity| exclusivity.into()),
+                profile_id:      None,
             },
             target_contract:   params.target_contract,
             target_calldata:   params.target_calldata,
@@ -186,6 +223,13 @@ impl TryFrom<repository::Opportunity<repository::OpportunityMetadataEvm>> for Op
                 id: val.id,
                 creation_time: val.creation_time.assume_utc(),
                 refresh_time: val.creation_time.assume_utc(),
+                state: val.state.into(),
+                expiration_time: val.expiration_time.map(|time| time.assume_utc()),
+                exclusivity: val
+                    .exclusivity
+                    .clone()
+                    .map(|exclusivity| exclusivity.0.into()),
+                profile_id: val.profile_id,
                 permission_key: PermissionKey::from(val.permission_key),
                 chain_id: val.chain_id,
                 sell_tokens,
@@ -202,10 +246,13 @@ impl From<OpportunityEvm> for OpportunityCreateEvm {
     fn from(val: OpportunityEvm) -> Self {
         OpportunityCreateEvm {
             core_fields:       OpportunityCoreFieldsCreate::<TokenAmountEvm> {
-                permission_key: val.core_fields.permission_key,
-                chain_id:       val.core_fields.chain_id,
-                sell_tokens:    val.core_fields.sell_tokens,
-                buy_tokens:     val.core_fields.buy_tokens,
+                permission_key:  val.core_fields.permission_key,
+                chain_id:        val.core_fields.chain_id,
+                sell_tokens:     val.core_fields.sell_tokens,
+                buy_tokens:      val.core_fields.buy_tokens,
+                expiration_time: val.core_fields.expiration_time,
+                exclusivity:     val.core_fields.exclusivity,
+                profile_id:      val.core_fields.profile_id,
             },
             target_contract:   val.target_contract,
             target_call_value: val.target_call_value,
@@ -1,15 +1,21 @@
 use {
     super::{
+        limo_order::{
+            LimoOrder,
+            LimoOrderStatus,
+        },
         opportunity::{
             Opportunity,
             OpportunityCoreFields,
         },
+        quote::SpecifiedTokenSide,
         token_amount_svm::TokenAmountSvm,
         OpportunityComparison,
         OpportunityCoreFieldsCreate,
         OpportunityCreate,
     },
     crate::{
+        api::RestError,
         kernel::entities::PermissionKey,
         opportunity::{
             api,
@@ -35,10 +41,19 @@ pub struct OpportunitySvmProgramLimo {
     pub order_address: Pubkey,
 }
 
+impl OpportunitySvmProgramLimo {
+    pub fn get_order(&self) -> anyhow::Result<LimoOrder> {
+        LimoOrder::try_from_slice(&self.order)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct OpportunitySvmProgramWallet {
     pub user_wallet_address:         Pubkey,
     pub maximum_slippage_percentage: f64,
+    /// Confidence of the estimated buy token amount, only set for the quote opportunities.
+    pub price_confidence:            Option<f64>,
+    pub specified_token_side:        SpecifiedTokenSide,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -102,6 +117,8 @@ impl Opportunity for OpportunitySvm {
                     repository::OpportunityMetadataSvmProgramWallet {
                         user_wallet_address:         program.user_wallet_address,
                         maximum_slippage_percentage: program.maximum_slippage_percentage,
+                        price_confidence:            program.price_confidence,
+                        specified_token_side:        program.specified_token_side,
                     },
                 )
             }
@@ -114,7 +131,10 @@ impl Opportunity for OpportunitySvm {
         }
     }
 
-    fn get_opportunity_delete(&self) -> api::OpportunityDelete {
+    fn get_opportunity_delete(
+        &self,
+        _reason: api::OpportunityRemovalReason,
+    ) -> api::OpportunityDelete {
         api::OpportunityDelete::Svm(api::OpportunityDeleteSvm::V1(api::OpportunityDeleteV1Svm {
             chain_id:           self.chain_id.clone(),
             permission_account: self.permission_account,
@@ -123,6 +143,22 @@ impl Opportunity for OpportunitySvm {
         }))
     }
 
+    fn get_subscription_fields(&self) -> super::OpportunitySubscriptionFields {
+        super::OpportunitySubscriptionFields {
+            chain_id:       self.chain_id.clone(),
+            permission_key: self.permission_key.clone(),
+            program:        Some(self.program.clone().into()),
+            router:         Some(self.router),
+            tokens:         self
+                .sell_tokens
+                .iter()
+                .chain(self.buy_tokens.iter())
+                .map(|token| (token.token.to_string(), token.amount.into()))
+                .collect(),
+            exclusivity:    self.exclusivity.clone(),
+        }
+    }
+
     fn compare(&self, other: &Self::OpportunityCreate) -> super::OpportunityComparison {
         let mut self_clone: OpportunityCreateSvm = self.clone().into();
         self_clone.slot = other.slot;
@@ -140,6 +176,10 @@ impl Opportunity for OpportunitySvm {
     fn refresh(&mut self) {
         self.core_fields.refresh_time = OffsetDateTime::now_utc();
     }
+
+    fn set_state(&mut self, state: super::OpportunityState) {
+        self.core_fields.state = state;
+    }
 }
 
 impl OpportunityCreate for OpportunityCreateSvm {
@@ -151,6 +191,10 @@ impl OpportunityCreate for OpportunityCreateSvm {
             self.core_fields.permission_key.clone(),
         )
     }
+
+    fn validate_lifetime(&self) -> Result<(), RestError> {
+        self.core_fields.validate_lifetime()
+    }
 }
 
 impl Deref for OpportunitySvm {
@@ -171,6 +215,7 @@ impl From<OpportunitySvm> for api::OpportunitySvm {
     fn from(val: OpportunitySvm) -> Self {
         let program = match val.program.clone() {
             OpportunitySvmProgram::Limo(prgoram) => api::OpportunityParamsV1ProgramSvm::Limo {
+                order_details: prgoram.get_order().ok().map(|order| order.into()),
                 order:         prgoram.order,
                 order_address: prgoram.order_address,
             },
@@ -178,6 +223,8 @@ impl From<OpportunitySvm> for api::OpportunitySvm {
                 api::OpportunityParamsV1ProgramSvm::Phantom {
                     user_wallet_address:         program.user_wallet_address,
                     maximum_slippage_percentage: program.maximum_slippage_percentage,
+                    price_confidence:            program.price_confidence,
+                    specified_token_side:        program.specified_token_side.into(),
                     permission_account:          val.permission_account,
                     router_account:              val.router,
                     // TODO can we make it type safe?
@@ -207,6 +254,11 @@ impl From<OpportunitySvm> for api::OpportunitySvm {
             params:         api::OpportunityParamsSvm::V1(api::OpportunityParamsV1Svm {
                 program,
                 chain_id: val.chain_id.clone(),
+                expiration_time: val.expiration_time,
+                exclusivity: val
+                    .exclusivity
+                    .clone()
+                    .map(|exclusivity| exclusivity.into()),
             }),
         }
     }
@@ -246,6 +298,8 @@ impl TryFrom<repository::Opportunity<repository::OpportunityMetadataSvm>> for Op
                 OpportunitySvmProgram::Phantom(OpportunitySvmProgramWallet {
                     user_wallet_address:         program.user_wallet_address,
                     maximum_slippage_percentage: program.maximum_slippage_percentage,
+                    price_confidence:            program.price_confidence,
+                    specified_token_side:        program.specified_token_side,
                 })
             }
         };
@@ -254,6 +308,13 @@ impl TryFrom<repository::Opportunity<repository::OpportunityMetadataSvm>> for Op
                 id: val.id,
                 creation_time: val.creation_time.assume_utc(),
                 refresh_time: val.creation_time.assume_utc(),
+                state: val.state.into(),
+                expiration_time: val.expiration_time.map(|time| time.assume_utc()),
+                exclusivity: val
+                    .exclusivity
+                    .clone()
+                    .map(|exclusivity| exclusivity.0.into()),
+                profile_id: val.profile_id,
                 permission_key: PermissionKey::from(val.permission_key),
                 chain_id: val.chain_id,
                 sell_tokens,
@@ -284,20 +345,25 @@ impl From<api::OpportunityCreateSvm> for OpportunityCreateSvm {
             } => OpportunitySvmProgram::Phantom(OpportunitySvmProgramWallet {
                 user_wallet_address,
                 maximum_slippage_percentage,
+                price_confidence: None,
+                specified_token_side: SpecifiedTokenSide::Input,
             }),
         };
 
         OpportunityCreateSvm {
             core_fields: OpportunityCoreFieldsCreate::<TokenAmountSvm> {
-                permission_key: [
+                permission_key:  [
                     params.router.to_bytes(),
                     params.permission_account.to_bytes(),
                 ]
                 .concat()
                 .into(),
-                chain_id:       params.chain_id,
-                sell_tokens:    params.sell_tokens.into_iter().map(|t| t.into()).collect(),
-                buy_tokens:     params.buy_tokens.into_iter().map(|t| t.into()).collect(),
+                chain_id:        params.chain_id,
+                sell_tokens:     params.sell_tokens.into_iter().map(|t| t.into()).collect(),
+                buy_tokens:      params.buy_tokens.into_iter().map(|t| t.into()).collect(),
+                expiration_time: params.expiration_time,
+                exclusivity:     params.exclusivity.map(|exclusivity| exclusivity.into()),
+                profile_id:      None,
             },
             program,
             permission_account: params.permission_account,
@@ -311,10 +377,13 @@ impl From<OpportunitySvm> for OpportunityCreateSvm {
     fn from(val: OpportunitySvm) -> Self {
         OpportunityCreateSvm {
             core_fields:        OpportunityCoreFieldsCreate::<TokenAmountSvm> {
-                permission_key: val.core_fields.permission_key,
-                chain_id:       val.core_fields.chain_id,
-                sell_tokens:    val.core_fields.sell_tokens,
-                buy_tokens:     val.core_fields.buy_tokens,
+                permission_key:  val.core_fields.permission_key,
+                chain_id:        val.core_fields.chain_id,
+                sell_tokens:     val.core_fields.sell_tokens,
+                buy_tokens:      val.core_fields.buy_tokens,
+                expiration_time: val.core_fields.expiration_time,
+                exclusivity:     val.core_fields.exclusivity,
+                profile_id:      val.core_fields.profile_id,
             },
             router:             val.router,
             permission_account: val.permission_account,
@@ -345,3 +414,30 @@ impl From<OpportunitySvmProgram> for api::ProgramSvm {
         }
     }
 }
+
+impl From<LimoOrderStatus> for api::LimoOrderStatus {
+    fn from(val: LimoOrderStatus) -> Self {
+        match val {
+            LimoOrderStatus::Active => api::LimoOrderStatus::Active,
+            LimoOrderStatus::Filled => api::LimoOrderStatus::Filled,
+            LimoOrderStatus::Cancelled => api::LimoOrderStatus::Cancelled,
+        }
+    }
+}
+
+impl From<LimoOrder> for api::LimoOrderDetails {
+    fn from(val: LimoOrder) -> Self {
+        api::LimoOrderDetails {
+            maker:                  val.maker,
+            global_config:          val.global_config,
+            input_mint:             val.input_mint,
+            output_mint:            val.output_mint,
+            initial_input_amount:   val.initial_input_amount,
+            remaining_input_amount: val.remaining_input_amount,
+            expected_output_amount: val.expected_output_amount,
+            filled_output_amount:   val.filled_output_amount,
+            status:                 val.status.into(),
+            last_updated_timestamp: val.last_updated_timestamp,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use super::token_amount_svm::TokenAmountSvm;
+
+/// A past trade of the input mint for the output mint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trade {
+    pub input_amount:  u64,
+    pub output_amount: u64,
+}
+
+/// The quote tokens with the amount which was not specified by the user estimated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceEstimate {
+    pub input_token:  TokenAmountSvm,
+    pub output_token: TokenAmountSvm,
+    /// How reliable the estimation is, between 0 (no data) and 1.
+    pub confidence:   f64,
+}
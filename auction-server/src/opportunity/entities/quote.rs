@@ -1,17 +1,41 @@
 use {
-    super::token_amount_svm::TokenAmountSvm,
+    super::{
+        opportunity::OpportunityId,
+        opportunity_svm::OpportunitySvm,
+        price_estimate::PriceEstimate,
+        token_amount_svm::TokenAmountSvm,
+    },
     crate::{
-        kernel::entities::ChainId,
+        api::RestError,
+        auction::entities::BidId,
+        kernel::entities::{
+            ChainId,
+            PermissionKeySvm,
+        },
         opportunity::api,
     },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
     solana_sdk::{
         pubkey::Pubkey,
+        signature::Signature,
         transaction::VersionedTransaction,
     },
+    std::cmp::Ordering,
+    time::OffsetDateTime,
+    uuid::Uuid,
 };
 
+pub type QuoteId = Uuid;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Quote {
+    pub id:                          QuoteId,
+    pub opportunity_id:              OpportunityId,
+    pub bid_id:                      BidId,
+    pub user_wallet_address:         Pubkey,
     pub transaction:                 VersionedTransaction,
     // The expiration time of the quote (in seconds since the Unix epoch)
     pub expiration_time:             i64,
@@ -21,36 +45,184 @@ pub struct Quote {
     pub chain_id:                    ChainId,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuoteStatus {
+    /// The signed transaction was returned and is not seen on chain yet.
+    Issued,
+    /// The transaction was submitted by the user and included on chain.
+    Submitted,
+    /// The transaction succeeded and is settled on chain.
+    Landed,
+    /// The transaction was not included on chain before the expiration time.
+    Expired,
+    /// The transaction was included on chain but failed.
+    Failed,
+}
+
+impl QuoteStatus {
+    /// The statuses from which a quote can move to this status.
+    pub fn get_previous_statuses(&self) -> Vec<QuoteStatus> {
+        match self {
+            QuoteStatus::Issued => vec![],
+            QuoteStatus::Submitted => vec![QuoteStatus::Issued],
+            QuoteStatus::Landed => vec![QuoteStatus::Issued, QuoteStatus::Submitted],
+            QuoteStatus::Expired => vec![QuoteStatus::Issued],
+            QuoteStatus::Failed => vec![QuoteStatus::Issued, QuoteStatus::Submitted],
+        }
+    }
+}
+
+/// A quote returned to the user with the status of its transaction.
 #[derive(Debug, Clone, PartialEq)]
-pub struct QuoteCreate {
+pub struct QuoteDetails {
+    pub id:                          QuoteId,
+    pub chain_id:                    ChainId,
+    pub opportunity_id:              OpportunityId,
+    pub bid_id:                      BidId,
     pub user_wallet_address:         Pubkey,
     pub input_token:                 TokenAmountSvm,
-    pub output_mint_token:           Pubkey,
+    pub output_token:                TokenAmountSvm,
+    pub maximum_slippage_percentage: f64,
+    pub signature:                   Signature,
+    pub expiration_time:             OffsetDateTime,
+    pub status:                      QuoteStatus,
+    pub creation_time:               OffsetDateTime,
+}
+
+/// The side of the swap with the exact amount specified by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpecifiedTokenSide {
+    #[default]
+    Input,
+    Output,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuoteTokens {
+    /// The user sells an exact amount of the input token.
+    InputTokenSpecified {
+        input_token:  TokenAmountSvm,
+        output_token: Pubkey,
+    },
+    /// The user buys an exact amount of the output token.
+    OutputTokenSpecified {
+        input_token:  Pubkey,
+        output_token: TokenAmountSvm,
+    },
+}
+
+impl QuoteTokens {
+    pub fn get_input_mint(&self) -> Pubkey {
+        match self {
+            QuoteTokens::InputTokenSpecified { input_token, .. } => input_token.token,
+            QuoteTokens::OutputTokenSpecified { input_token, .. } => *input_token,
+        }
+    }
+
+    pub fn get_output_mint(&self) -> Pubkey {
+        match self {
+            QuoteTokens::InputTokenSpecified { output_token, .. } => *output_token,
+            QuoteTokens::OutputTokenSpecified { output_token, .. } => output_token.token,
+        }
+    }
+
+    pub fn get_specified_amount(&self) -> u64 {
+        match self {
+            QuoteTokens::InputTokenSpecified { input_token, .. } => input_token.amount,
+            QuoteTokens::OutputTokenSpecified { output_token, .. } => output_token.amount,
+        }
+    }
+
+    pub fn get_specified_token_side(&self) -> SpecifiedTokenSide {
+        match self {
+            QuoteTokens::InputTokenSpecified { .. } => SpecifiedTokenSide::Input,
+            QuoteTokens::OutputTokenSpecified { .. } => SpecifiedTokenSide::Output,
+        }
+    }
+
+    /// Orders the bid amounts from the best to the worst for the user.
+    /// The searchers compete on the largest output for an exact input and on the smallest input for an exact output.
+    pub fn compare_bid_amounts(&self, amount: u64, other: u64) -> Ordering {
+        match self {
+            QuoteTokens::InputTokenSpecified { .. } => other.cmp(&amount),
+            QuoteTokens::OutputTokenSpecified { .. } => amount.cmp(&other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteCreate {
+    pub user_wallet_address:         Pubkey,
+    pub tokens:                      QuoteTokens,
     pub maximum_slippage_percentage: f64,
     pub chain_id:                    ChainId,
 }
 
-impl From<api::QuoteCreate> for QuoteCreate {
-    fn from(quote_create: api::QuoteCreate) -> Self {
+/// A quote which is open for the searcher bids on its opportunity until it is accepted or cancelled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteRequest {
+    pub quote_create:   QuoteCreate,
+    pub price_estimate: PriceEstimate,
+    pub opportunity:    OpportunitySvm,
+}
+
+impl QuoteRequest {
+    pub fn get_permission_key(&self) -> PermissionKeySvm {
+        // NOTE: This part will be removed after refactoring the permission key type
+        PermissionKeySvm(
+            self.opportunity
+                .permission_key
+                .to_vec()
+                .try_into()
+                .expect("Failed to convert permission key to slice"),
+        )
+    }
+}
+
+impl TryFrom<api::QuoteCreate> for QuoteCreate {
+    type Error = RestError;
+
+    fn try_from(quote_create: api::QuoteCreate) -> Result<Self, Self::Error> {
         let api::QuoteCreate::Svm(api::QuoteCreateSvm::V1(api::QuoteCreateV1Svm::Phantom(params))) =
             quote_create;
 
-        Self {
-            user_wallet_address:         params.user_wallet_address,
-            input_token:                 TokenAmountSvm {
-                token:  params.input_token_mint,
-                amount: params.input_token_amount,
+        let tokens = match (params.input_token_amount, params.output_token_amount) {
+            (Some(amount), None) => QuoteTokens::InputTokenSpecified {
+                input_token:  TokenAmountSvm {
+                    token: params.input_token_mint,
+                    amount,
+                },
+                output_token: params.output_token_mint,
             },
-            output_mint_token:           params.output_token_mint,
+            (None, Some(amount)) => QuoteTokens::OutputTokenSpecified {
+                input_token:  params.input_token_mint,
+                output_token: TokenAmountSvm {
+                    token: params.output_token_mint,
+                    amount,
+                },
+            },
+            _ => {
+                return Err(RestError::BadParameters(
+                    "Exactly one of the input and output token amounts should be specified"
+                        .to_string(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            user_wallet_address: params.user_wallet_address,
+            tokens,
             maximum_slippage_percentage: params.maximum_slippage_percentage,
-            chain_id:                    params.chain_id,
-        }
+            chain_id: params.chain_id,
+        })
     }
 }
 
 impl From<Quote> for api::Quote {
     fn from(quote: Quote) -> Self {
         api::Quote::Svm(api::QuoteSvm::V1(api::QuoteV1Svm {
+            id:                          quote.id,
             transaction:                 quote.transaction,
             expiration_time:             quote.expiration_time,
             input_token:                 quote.input_token.into(),
@@ -60,3 +232,43 @@ impl From<Quote> for api::Quote {
         }))
     }
 }
+
+impl From<SpecifiedTokenSide> for api::SpecifiedTokenSide {
+    fn from(side: SpecifiedTokenSide) -> Self {
+        match side {
+            SpecifiedTokenSide::Input => api::SpecifiedTokenSide::Input,
+            SpecifiedTokenSide::Output => api::SpecifiedTokenSide::Output,
+        }
+    }
+}
+
+impl From<QuoteStatus> for api::QuoteStatus {
+    fn from(status: QuoteStatus) -> Self {
+        match status {
+            QuoteStatus::Issued => api::QuoteStatus::Issued,
+            QuoteStatus::Submitted => api::QuoteStatus::Submitted,
+            QuoteStatus::Landed => api::QuoteStatus::Landed,
+            QuoteStatus::Expired => api::QuoteStatus::Expired,
+            QuoteStatus::Failed => api::QuoteStatus::Failed,
+        }
+    }
+}
+
+impl From<QuoteDetails> for api::QuoteDetails {
+    fn from(quote: QuoteDetails) -> Self {
+        api::QuoteDetails {
+            id:                          quote.id,
+            status:                      quote.status.into(),
+            chain_id:                    quote.chain_id,
+            opportunity_id:              quote.opportunity_id,
+            bid_id:                      quote.bid_id,
+            user_wallet_address:         quote.user_wallet_address,
+            input_token:                 quote.input_token.into(),
+            output_token:                quote.output_token.into(),
+            maximum_slippage_percentage: quote.maximum_slippage_percentage,
+            signature:                   quote.signature,
+            expiration_time:             quote.expiration_time.unix_timestamp(),
+            creation_time:               quote.creation_time,
+        }
+    }
+}
@@ -1,19 +1,44 @@
-use ethers::types::{
-    Address,
-    U256,
+use {
+    crate::opportunity::api,
+    ethers::types::{
+        Address,
+        U256,
+    },
 };
 
+/// The way the storage keys of the mappings are calculated by the token contract.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StorageLayout {
+    /// The key of the entry is hashed before the slot of the mapping.
+    Solidity,
+    /// The slot of the mapping is hashed before the key of the entry.
+    Vyper,
+}
+
+impl From<api::StorageLayout> for StorageLayout {
+    fn from(layout: api::StorageLayout) -> Self {
+        match layout {
+            api::StorageLayout::Solidity => StorageLayout::Solidity,
+            api::StorageLayout::Vyper => StorageLayout::Vyper,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum SpoofState {
     Spoofed {
         balance_slot:   U256,
         allowance_slot: U256,
+        layout:         StorageLayout,
     },
     UnableToSpoof,
 }
 
 #[derive(Clone)]
 pub struct SpoofInfo {
-    pub token: Address,
-    pub state: SpoofState,
+    pub token:          Address,
+    pub state:          SpoofState,
+    /// The implementation of the token when the slots were found, if the token is a proxy.
+    /// Slots set manually by the admins have no implementation and are never checked again.
+    pub implementation: Option<Address>,
 }
@@ -1,3 +1,4 @@
+mod price_source;
 mod repository;
 mod token_spoof;
 
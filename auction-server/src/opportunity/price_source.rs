@@ -0,0 +1,115 @@
+use {
+    crate::config::PriceSourceConfig,
+    anyhow::{
+        anyhow,
+        Result,
+    },
+    axum::async_trait,
+    serde::Deserialize,
+    serde_with::{
+        serde_as,
+        DisplayFromStr,
+    },
+    solana_sdk::pubkey::Pubkey,
+    std::{
+        collections::HashMap,
+        time::Duration,
+    },
+};
+
+/// Price of a whole token in a common quote currency.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TokenPrice {
+    pub price:    f64,
+    pub decimals: u8,
+}
+
+impl TokenPrice {
+    /// Price of the smallest unit of the token.
+    fn get_unit_price(&self) -> f64 {
+        self.price / 10f64.powi(self.decimals as i32)
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+struct TokenPrices(#[serde_as(as = "HashMap<DisplayFromStr, _>")] HashMap<Pubkey, TokenPrice>);
+
+/// A source of reference token prices.
+#[async_trait]
+pub trait PriceSource: Send + Sync {
+    /// Returns the prices of the given mints which are known to the source.
+    async fn get_prices(&self, mints: &[Pubkey]) -> Result<HashMap<Pubkey, TokenPrice>>;
+
+    /// Returns how many units of the output mint one unit of the input mint is worth.
+    async fn get_rate(&self, input_mint: &Pubkey, output_mint: &Pubkey) -> Result<Option<f64>> {
+        let prices = self.get_prices(&[*input_mint, *output_mint]).await?;
+        Ok(match (prices.get(input_mint), prices.get(output_mint)) {
+            (Some(input), Some(output)) if output.price > 0.0 => {
+                Some(input.get_unit_price() / output.get_unit_price())
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Reads the prices from a json file mapping the mints to their prices.
+/// The file is read on every request, so it can be updated while the server is running.
+pub struct FilePriceSource {
+    path: String,
+}
+
+#[async_trait]
+impl PriceSource for FilePriceSource {
+    async fn get_prices(&self, mints: &[Pubkey]) -> Result<HashMap<Pubkey, TokenPrice>> {
+        let content = tokio::fs::read_to_string(&self.path).await?;
+        let TokenPrices(prices) = serde_json::from_str(&content)?;
+        Ok(prices
+            .into_iter()
+            .filter(|(mint, _)| mints.contains(mint))
+            .collect())
+    }
+}
+
+/// Fetches the prices from an http endpoint returning the same mapping as the file source.
+pub struct HttpPriceSource {
+    client: reqwest::Client,
+    url:    String,
+}
+
+#[async_trait]
+impl PriceSource for HttpPriceSource {
+    async fn get_prices(&self, mints: &[Pubkey]) -> Result<HashMap<Pubkey, TokenPrice>> {
+        let mints = mints
+            .iter()
+            .map(|mint| mint.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let response = self
+            .client
+            .get(&self.url)
+            .query(&[("mints", mints)])
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Price source returned status {}",
+                response.status()
+            ));
+        }
+        let TokenPrices(prices) = serde_json::from_str(&response.text().await?)?;
+        Ok(prices)
+    }
+}
+
+pub fn get_price_source(config: &PriceSourceConfig) -> Result<Box<dyn PriceSource>> {
+    Ok(match config {
+        PriceSourceConfig::File { path } => Box::new(FilePriceSource { path: path.clone() }),
+        PriceSourceConfig::Http { url, timeout } => Box::new(HttpPriceSource {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(*timeout))
+                .build()?,
+            url:    url.clone(),
+        }),
+    })
+}
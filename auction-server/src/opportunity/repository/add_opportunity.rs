@@ -1,6 +1,9 @@
 use {
     super::{
-        models::OpportunityMetadata,
+        models::{
+            self,
+            OpportunityMetadata,
+        },
         InMemoryStore,
         Repository,
     },
@@ -32,7 +35,10 @@ impl<T: InMemoryStore> Repository<T> {
                                                         chain_type,
                                                         metadata,
                                                         sell_tokens,
-                                                        buy_tokens) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                                                        buy_tokens,
+                                                        expiration_time,
+                                                        exclusivity,
+                                                        profile_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
         opportunity.id,
         PrimitiveDateTime::new(opportunity.creation_time.date(), opportunity.creation_time.time()),
         opportunity.permission_key.to_vec(),
@@ -40,7 +46,10 @@ impl<T: InMemoryStore> Repository<T> {
         chain_type as _,
         serde_json::to_value(metadata).expect("Failed to serialize metadata"),
         serde_json::to_value(&opportunity.sell_tokens).expect("Failed to serialize sell_tokens"),
-        serde_json::to_value(&opportunity.buy_tokens).expect("Failed to serialize buy_tokens"))
+        serde_json::to_value(&opportunity.buy_tokens).expect("Failed to serialize buy_tokens"),
+        opportunity.expiration_time.map(|time| PrimitiveDateTime::new(time.date(), time.time())),
+        opportunity.exclusivity.clone().map(|exclusivity| serde_json::to_value(models::OpportunityExclusivity::from(exclusivity)).expect("Failed to serialize exclusivity")),
+        opportunity.profile_id)
             .execute(db)
             .await
             .map_err(|e| {
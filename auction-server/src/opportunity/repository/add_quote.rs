@@ -0,0 +1,57 @@
+use {
+    super::{
+        models,
+        InMemoryStoreSvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        opportunity::entities,
+    },
+    sqlx::Postgres,
+    time::{
+        OffsetDateTime,
+        PrimitiveDateTime,
+    },
+};
+
+impl Repository<InMemoryStoreSvm> {
+    pub async fn add_quote(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        quote: &entities::Quote,
+    ) -> Result<(), RestError> {
+        let now = OffsetDateTime::now_utc();
+        let now = PrimitiveDateTime::new(now.date(), now.time());
+        let expiration_time =
+            OffsetDateTime::from_unix_timestamp(quote.expiration_time).map_err(|e| {
+                tracing::error!(error = ?e, quote_id = %quote.id, "Invalid quote expiration time");
+                RestError::TemporarilyUnavailable
+            })?;
+        sqlx::query(
+            "INSERT INTO quote (id, chain_id, opportunity_id, bid_id, user_wallet_address, input_token, output_token, maximum_slippage_percentage, signature, expiration_time, status, creation_time, update_time) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12)",
+        )
+        .bind(quote.id)
+        .bind(&quote.chain_id)
+        .bind(quote.opportunity_id)
+        .bind(quote.bid_id)
+        .bind(quote.user_wallet_address.to_string())
+        .bind(serde_json::to_value(&quote.input_token).expect("Failed to serialize input_token"))
+        .bind(serde_json::to_value(&quote.output_token).expect("Failed to serialize output_token"))
+        .bind(quote.maximum_slippage_percentage)
+        .bind(quote.transaction.signatures[0].to_string())
+        .bind(PrimitiveDateTime::new(
+            expiration_time.date(),
+            expiration_time.time(),
+        ))
+        .bind(models::QuoteStatus::Issued)
+        .bind(now)
+        .execute(db)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = e.to_string(), quote_id = %quote.id, "DB: Failed to insert quote");
+            RestError::TemporarilyUnavailable
+        })?;
+        Ok(())
+    }
+}
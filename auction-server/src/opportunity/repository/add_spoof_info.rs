@@ -1,17 +1,25 @@
 use {
     super::{
+        InMemorySpoofInfo,
         InMemoryStoreEvm,
         Repository,
     },
-    crate::opportunity::entities,
+    crate::{
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    time::OffsetDateTime,
 };
 
 impl Repository<InMemoryStoreEvm> {
-    pub async fn add_spoof_info(&self, spoof_info: entities::SpoofInfo) {
-        self.in_memory_store
-            .spoof_info
-            .write()
-            .await
-            .insert(spoof_info.token, spoof_info.state);
+    /// Adds the spoof info to the memory, its implementation is considered checked at the time it is added.
+    pub async fn add_spoof_info(&self, chain_id: ChainId, spoof_info: entities::SpoofInfo) {
+        self.in_memory_store.spoof_info.write().await.insert(
+            (chain_id, spoof_info.token),
+            InMemorySpoofInfo {
+                spoof_info,
+                check_time: OffsetDateTime::now_utc(),
+            },
+        );
     }
 }
@@ -0,0 +1,59 @@
+use {
+    super::{
+        models,
+        InMemoryStoreEvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    sqlx::Postgres,
+    time::{
+        OffsetDateTime,
+        PrimitiveDateTime,
+    },
+};
+
+impl Repository<InMemoryStoreEvm> {
+    /// Set the spoof slots of a token manually.
+    /// These slots are used instead of the ones found by the server.
+    pub async fn add_spoof_override(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        chain_id: ChainId,
+        spoof_info: entities::SpoofInfo,
+    ) -> Result<(), RestError> {
+        let entities::SpoofState::Spoofed {
+            balance_slot,
+            allowance_slot,
+            layout,
+        } = spoof_info.state
+        else {
+            return Err(RestError::BadParameters(
+                "Spoof override should contain the slots".to_string(),
+            ));
+        };
+        let now = OffsetDateTime::now_utc();
+        sqlx::query(
+            "INSERT INTO token_spoof_override (chain_id, token, balance_slot, allowance_slot, layout, creation_time) VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (chain_id, token) DO UPDATE SET balance_slot = EXCLUDED.balance_slot, allowance_slot = EXCLUDED.allowance_slot, layout = EXCLUDED.layout, creation_time = EXCLUDED.creation_time",
+        )
+        .bind(&chain_id)
+        .bind(spoof_info.token.as_bytes())
+        .bind(models::convert_slot(balance_slot))
+        .bind(models::convert_slot(allowance_slot))
+        .bind(models::StorageLayout::from(layout))
+        .bind(PrimitiveDateTime::new(now.date(), now.time()))
+        .execute(db)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = e.to_string(), token = ?spoof_info.token, "DB: Failed to insert spoof override");
+            RestError::TemporarilyUnavailable
+        })?;
+
+        self.add_spoof_info(chain_id, spoof_info).await;
+        Ok(())
+    }
+}
@@ -8,66 +8,90 @@ use {
     },
     crate::{
         api::RestError,
-        kernel::entities::{
-            ChainId,
-            PermissionKey,
-        },
         opportunity::{
             entities,
             repository::models::OpportunityMetadata,
         },
     },
-    sqlx::QueryBuilder,
-    time::OffsetDateTime,
+    sqlx::{
+        types::Json,
+        QueryBuilder,
+    },
+    time::PrimitiveDateTime,
 };
 
 impl<T: InMemoryStore> Repository<T> {
     pub async fn get_opportunities(
         &self,
         db: &sqlx::Pool<sqlx::Postgres>,
-        chain_id: ChainId,
-        permission_key: Option<PermissionKey>,
-        from_time: Option<OffsetDateTime>,
+        filter: entities::OpportunityFilter,
+        limit: usize,
     ) -> Result<Vec<T::Opportunity>, RestError> {
         let mut query = QueryBuilder::new("SELECT * from opportunity WHERE chain_type = ");
         query.push_bind(
             <<T::Opportunity as entities::Opportunity>::ModelMetadata>::get_chain_type(),
         );
-        query.push(" AND chain_id = ");
-        query.push_bind(chain_id.clone());
-        if let Some(permission_key) = permission_key.clone() {
+        if let Some(chain_id) = filter.chain_id.clone() {
+            query.push(" AND chain_id = ");
+            query.push_bind(chain_id);
+        }
+        if let Some(permission_key) = filter.permission_key.clone() {
             query.push(" AND permission_key = ");
             query.push_bind(permission_key.to_vec());
         }
-        if let Some(from_time) = from_time {
+        if let Some(from_time) = filter.from_time {
             query.push(" AND creation_time >= ");
             query.push_bind(from_time);
         }
-        query.push(" ORDER BY creation_time ASC LIMIT ");
-        query.push_bind(super::OPPORTUNITY_PAGE_SIZE_CAP as i64);
-        let opps: Vec<models::Opportunity<<T::Opportunity as entities::Opportunity>::ModelMetadata>> = query
-            .build_query_as()
-            .fetch_all(db)
-            .await
-            .map_err(|e| {
-                tracing::error!(
-                    "DB: Failed to fetch opportunities: {} - chain_id: {:?} - permission_key: {:?} - from_time: {:?}",
-                    e,
-                    chain_id,
-                    permission_key,
-                    from_time,
-                );
-                RestError::TemporarilyUnavailable
-            })?;
+        if let Some(cursor) = filter.cursor {
+            query.push(" AND (creation_time, id) > (");
+            query.push_bind(PrimitiveDateTime::new(
+                cursor.creation_time.date(),
+                cursor.creation_time.time(),
+            ));
+            query.push(", ");
+            query.push_bind(cursor.id);
+            query.push(")");
+        }
+        if let Some(program) = filter.program.clone() {
+            query.push(" AND metadata->'program' = ");
+            query.push_bind(Json(program));
+        }
+        if let Some(router) = filter.router {
+            query.push(" AND metadata->'router' = ");
+            query.push_bind(Json(router.to_string()));
+        }
+        if let Some(token) = filter.token.clone() {
+            let token = Json(serde_json::json!([{ "token": token }]));
+            query.push(" AND (sell_tokens @> ");
+            query.push_bind(token.clone());
+            query.push(" OR buy_tokens @> ");
+            query.push_bind(token);
+            query.push(")");
+        }
+        if let Some(profile_id) = filter.profile_id {
+            query.push(" AND profile_id = ");
+            query.push_bind(profile_id);
+        }
+        query.push(" ORDER BY creation_time ASC, id ASC LIMIT ");
+        query.push_bind(std::cmp::min(limit, super::OPPORTUNITY_PAGE_SIZE_CAP) as i64);
+        let opps: Vec<
+            models::Opportunity<<T::Opportunity as entities::Opportunity>::ModelMetadata>,
+        > = query.build_query_as().fetch_all(db).await.map_err(|e| {
+            tracing::error!(
+                "DB: Failed to fetch opportunities: {} - filter: {:?}",
+                e,
+                filter,
+            );
+            RestError::TemporarilyUnavailable
+        })?;
 
         opps.into_iter().map(|opp| opp.clone().try_into().map_err(
             |_| {
                 tracing::error!(
-                    "Failed to convert database opportunity to entity opportunity: {:?} - chain_id: {:?} - permission_key: {:?} - from_time: {:?}",
+                    "Failed to convert database opportunity to entity opportunity: {:?} - filter: {:?}",
                     opp,
-                    chain_id,
-                    permission_key,
-                    from_time,
+                    filter,
                 );
                 RestError::TemporarilyUnavailable
             }
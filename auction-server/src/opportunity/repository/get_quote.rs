@@ -0,0 +1,35 @@
+use {
+    super::{
+        models,
+        InMemoryStoreSvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        opportunity::entities,
+    },
+    sqlx::Postgres,
+};
+
+impl Repository<InMemoryStoreSvm> {
+    pub async fn get_quote(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        quote_id: entities::QuoteId,
+    ) -> Result<entities::QuoteDetails, RestError> {
+        let quote: models::Quote = sqlx::query_as("SELECT * FROM quote WHERE id = $1")
+            .bind(quote_id)
+            .fetch_optional(db)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = e.to_string(), quote_id = %quote_id, "DB: Failed to fetch quote");
+                RestError::TemporarilyUnavailable
+            })?
+            .ok_or(RestError::QuoteIdNotFound)?;
+
+        quote.try_into().map_err(|e: anyhow::Error| {
+            tracing::error!(error = ?e, quote_id = %quote_id, "Failed to convert quote");
+            RestError::TemporarilyUnavailable
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    super::{
+        InMemoryStoreSvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    solana_sdk::pubkey::Pubkey,
+    sqlx::{
+        prelude::FromRow,
+        types::{
+            time::PrimitiveDateTime,
+            JsonValue,
+        },
+    },
+    time::OffsetDateTime,
+};
+
+#[derive(FromRow)]
+struct TradeTokens {
+    sell_tokens: JsonValue,
+    buy_tokens:  JsonValue,
+}
+
+impl Repository<InMemoryStoreSvm> {
+    /// Get the latest won limo orders which trade the input mint for the output mint.
+    /// Only limo orders are used because their prices are set by the makers,
+    /// while the prices of the quote opportunities come from our own estimations.
+    pub async fn get_recent_trades(
+        &self,
+        db: &sqlx::Pool<sqlx::Postgres>,
+        chain_id: &ChainId,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        from_time: OffsetDateTime,
+        limit: i64,
+    ) -> Result<Vec<entities::Trade>, RestError> {
+        // For limo orders the searchers buy the input mint of the maker and sell the output mint
+        let rows: Vec<TradeTokens> = sqlx::query_as(
+            "SELECT opportunity.sell_tokens, opportunity.buy_tokens FROM bid
+            JOIN LATERAL (
+                SELECT * FROM opportunity
+                WHERE opportunity.permission_key = bid.permission_key AND opportunity.chain_id = bid.chain_id AND opportunity.creation_time <= bid.creation_time
+                ORDER BY opportunity.creation_time DESC LIMIT 1
+            ) opportunity ON TRUE
+            WHERE bid.chain_id = $1 AND bid.status = 'won' AND bid.creation_time >= $2
+                AND opportunity.chain_type = 'svm' AND opportunity.metadata->>'program' = 'limo'
+                AND opportunity.buy_tokens->0->>'token' = $3 AND opportunity.sell_tokens->0->>'token' = $4
+            ORDER BY bid.creation_time DESC LIMIT $5",
+        )
+        .bind(chain_id)
+        .bind(PrimitiveDateTime::new(from_time.date(), from_time.time()))
+        .bind(input_mint.to_string())
+        .bind(output_mint.to_string())
+        .bind(limit)
+        .fetch_all(db)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                error = e.to_string(),
+                chain_id = %chain_id,
+                "DB: Failed to fetch recent trades"
+            );
+            RestError::TemporarilyUnavailable
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let sell_tokens: Vec<entities::TokenAmountSvm> =
+                    serde_json::from_value(row.sell_tokens).ok()?;
+                let buy_tokens: Vec<entities::TokenAmountSvm> =
+                    serde_json::from_value(row.buy_tokens).ok()?;
+                Some(entities::Trade {
+                    input_amount:  buy_tokens.first()?.amount,
+                    output_amount: sell_tokens.first()?.amount,
+                })
+            })
+            .filter(|trade| trade.input_amount > 0 && trade.output_amount > 0)
+            .collect())
+    }
+}
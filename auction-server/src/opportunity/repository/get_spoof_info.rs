@@ -1,21 +1,24 @@
 use {
     super::{
+        InMemorySpoofInfo,
         InMemoryStoreEvm,
         Repository,
     },
-    crate::opportunity::entities,
+    crate::kernel::entities::ChainId,
     ethers::types::Address,
 };
 
 impl Repository<InMemoryStoreEvm> {
-    pub async fn get_spoof_info(&self, token: Address) -> Option<entities::SpoofInfo> {
-        let state = self
-            .in_memory_store
+    pub async fn get_spoof_info(
+        &self,
+        chain_id: ChainId,
+        token: Address,
+    ) -> Option<InMemorySpoofInfo> {
+        self.in_memory_store
             .spoof_info
             .read()
             .await
-            .get(&token)
-            .cloned();
-        state.map(|state| entities::SpoofInfo { token, state })
+            .get(&(chain_id, token))
+            .cloned()
     }
 }
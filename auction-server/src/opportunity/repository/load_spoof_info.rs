@@ -0,0 +1,48 @@
+use {
+    super::{
+        models,
+        InMemoryStoreEvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    ethers::types::Address,
+    sqlx::Postgres,
+};
+
+impl Repository<InMemoryStoreEvm> {
+    /// Load the spoof info of a token from the database.
+    /// The slots set by the admins take precedence over the slots found by the server.
+    pub async fn load_spoof_info(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        chain_id: &ChainId,
+        token: Address,
+    ) -> Result<Option<entities::SpoofInfo>, RestError> {
+        let info: Option<models::TokenSpoofInfo> = sqlx::query_as(
+            "SELECT token, balance_slot, allowance_slot, layout, implementation FROM (
+                SELECT token, balance_slot, allowance_slot, layout, NULL::BYTEA AS implementation, 0 AS priority FROM token_spoof_override WHERE chain_id = $1 AND token = $2
+                UNION ALL
+                SELECT token, balance_slot, allowance_slot, layout, implementation, 1 AS priority FROM token_spoof_info WHERE chain_id = $1 AND token = $2
+            ) AS spoof_info ORDER BY priority LIMIT 1",
+        )
+        .bind(chain_id)
+        .bind(token.as_bytes())
+        .fetch_optional(db)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = e.to_string(), token = ?token, "DB: Failed to fetch spoof info");
+            RestError::TemporarilyUnavailable
+        })?;
+
+        info.map(|info| info.try_into())
+            .transpose()
+            .map_err(|e: anyhow::Error| {
+                tracing::error!(error = ?e, token = ?token, "Failed to convert spoof info");
+                RestError::TemporarilyUnavailable
+            })
+    }
+}
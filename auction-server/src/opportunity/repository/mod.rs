@@ -1,24 +1,34 @@
 use {
     super::entities,
+    crate::kernel::entities::ChainId,
     ethers::types::Address,
     std::{
         collections::HashMap,
         ops::Deref,
     },
+    time::OffsetDateTime,
     tokio::sync::RwLock,
 };
 
 mod add_opportunity;
+mod add_quote;
 mod add_spoof_info;
+mod add_spoof_override;
 mod get_in_memory_opportunities;
 mod get_in_memory_opportunities_by_key;
 mod get_in_memory_opportunity_by_id;
 mod get_opportunities;
+mod get_quote;
+mod get_recent_trades;
 mod get_spoof_info;
+mod load_spoof_info;
 mod models;
 mod refresh_in_memory_opportunity;
 mod remove_opportunities;
 mod remove_opportunity;
+mod save_spoof_info;
+mod update_opportunities_state;
+mod update_quote_status;
 
 pub use models::*;
 pub const OPPORTUNITY_PAGE_SIZE_CAP: usize = 100;
@@ -29,7 +39,7 @@ pub struct Repository<T: InMemoryStore> {
 }
 
 pub trait InMemoryStore:
-    Deref<Target = InMemoryStoreCoreFields<Self::Opportunity>> + Send + Sync
+    Deref<Target = InMemoryStoreCoreFields<Self::Opportunity>> + Send + Sync + 'static
 {
     type Opportunity: entities::Opportunity;
 
@@ -48,9 +58,16 @@ impl<T: entities::Opportunity> InMemoryStoreCoreFields<T> {
     }
 }
 
+/// The spoof info of a token with the last time its implementation was checked.
+#[derive(Debug, Clone)]
+pub struct InMemorySpoofInfo {
+    pub spoof_info: entities::SpoofInfo,
+    pub check_time: OffsetDateTime,
+}
+
 pub struct InMemoryStoreEvm {
     pub core_fields: InMemoryStoreCoreFields<entities::OpportunityEvm>,
-    pub spoof_info:  RwLock<HashMap<Address, entities::SpoofState>>,
+    pub spoof_info:  RwLock<HashMap<(ChainId, Address), InMemorySpoofInfo>>,
 }
 pub struct InMemoryStoreSvm {
     pub core_fields: InMemoryStoreCoreFields<entities::OpportunitySvm>,
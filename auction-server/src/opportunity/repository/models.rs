@@ -1,5 +1,11 @@
 use {
-    crate::models::ChainType,
+    crate::{
+        models::{
+            ChainType,
+            ProfileId,
+        },
+        opportunity::entities,
+    },
     ethers::types::{
         Address,
         Bytes,
@@ -23,11 +29,16 @@ use {
         prelude::FromRow,
         types::{
             time::PrimitiveDateTime,
+            BigDecimal,
             Json,
             JsonValue,
         },
     },
-    std::fmt::Debug,
+    std::{
+        fmt::Debug,
+        str::FromStr,
+    },
+    time::OffsetDateTime,
     uuid::Uuid,
 };
 
@@ -38,6 +49,62 @@ pub enum OpportunityRemovalReason {
     Invalid,
 }
 
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "opportunity_state", rename_all = "snake_case")]
+pub enum OpportunityState {
+    Live,
+    InAuction,
+    AuctionConcluded,
+    Removed,
+}
+
+impl From<entities::OpportunityState> for OpportunityState {
+    fn from(state: entities::OpportunityState) -> Self {
+        match state {
+            entities::OpportunityState::Live => OpportunityState::Live,
+            entities::OpportunityState::InAuction => OpportunityState::InAuction,
+            entities::OpportunityState::AuctionConcluded => OpportunityState::AuctionConcluded,
+            entities::OpportunityState::Removed => OpportunityState::Removed,
+        }
+    }
+}
+
+impl From<OpportunityState> for entities::OpportunityState {
+    fn from(state: OpportunityState) -> Self {
+        match state {
+            OpportunityState::Live => entities::OpportunityState::Live,
+            OpportunityState::InAuction => entities::OpportunityState::InAuction,
+            OpportunityState::AuctionConcluded => entities::OpportunityState::AuctionConcluded,
+            OpportunityState::Removed => entities::OpportunityState::Removed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpportunityExclusivity {
+    #[serde(with = "time::serde::rfc3339")]
+    pub end_time:    OffsetDateTime,
+    pub profile_ids: Vec<ProfileId>,
+}
+
+impl From<entities::OpportunityExclusivity> for OpportunityExclusivity {
+    fn from(exclusivity: entities::OpportunityExclusivity) -> Self {
+        OpportunityExclusivity {
+            end_time:    exclusivity.end_time,
+            profile_ids: exclusivity.profile_ids,
+        }
+    }
+}
+
+impl From<OpportunityExclusivity> for entities::OpportunityExclusivity {
+    fn from(exclusivity: OpportunityExclusivity) -> Self {
+        entities::OpportunityExclusivity {
+            end_time:    exclusivity.end_time,
+            profile_ids: exclusivity.profile_ids,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OpportunityMetadataEvm {
     pub target_contract:   Address,
@@ -61,6 +128,10 @@ pub struct OpportunityMetadataSvmProgramWallet {
     #[serde_as(as = "DisplayFromStr")]
     pub user_wallet_address:         Pubkey,
     pub maximum_slippage_percentage: f64,
+    #[serde(default)]
+    pub price_confidence:            Option<f64>,
+    #[serde(default)]
+    pub specified_token_side:        entities::SpecifiedTokenSide,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -104,14 +175,148 @@ impl OpportunityMetadata for OpportunityMetadataSvm {
 #[derive(Clone, FromRow, Debug)]
 #[allow(dead_code)]
 pub struct Opportunity<T: OpportunityMetadata> {
-    pub id:             Uuid,
-    pub creation_time:  PrimitiveDateTime,
-    pub permission_key: Vec<u8>,
-    pub chain_id:       String,
-    pub chain_type:     ChainType,
-    pub removal_time:   Option<PrimitiveDateTime>,
-    pub sell_tokens:    JsonValue,
-    pub buy_tokens:     JsonValue,
-    pub removal_reason: Option<OpportunityRemovalReason>,
-    pub metadata:       Json<T>,
+    pub id:              Uuid,
+    pub creation_time:   PrimitiveDateTime,
+    pub permission_key:  Vec<u8>,
+    pub chain_id:        String,
+    pub chain_type:      ChainType,
+    pub removal_time:    Option<PrimitiveDateTime>,
+    pub sell_tokens:     JsonValue,
+    pub buy_tokens:      JsonValue,
+    pub removal_reason:  Option<OpportunityRemovalReason>,
+    pub state:           OpportunityState,
+    pub expiration_time: Option<PrimitiveDateTime>,
+    pub exclusivity:     Option<Json<OpportunityExclusivity>>,
+    pub profile_id:      Option<ProfileId>,
+    pub metadata:        Json<T>,
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "quote_status", rename_all = "lowercase")]
+pub enum QuoteStatus {
+    Issued,
+    Submitted,
+    Landed,
+    Expired,
+    Failed,
+}
+
+impl From<entities::QuoteStatus> for QuoteStatus {
+    fn from(status: entities::QuoteStatus) -> Self {
+        match status {
+            entities::QuoteStatus::Issued => QuoteStatus::Issued,
+            entities::QuoteStatus::Submitted => QuoteStatus::Submitted,
+            entities::QuoteStatus::Landed => QuoteStatus::Landed,
+            entities::QuoteStatus::Expired => QuoteStatus::Expired,
+            entities::QuoteStatus::Failed => QuoteStatus::Failed,
+        }
+    }
+}
+
+impl From<QuoteStatus> for entities::QuoteStatus {
+    fn from(status: QuoteStatus) -> Self {
+        match status {
+            QuoteStatus::Issued => entities::QuoteStatus::Issued,
+            QuoteStatus::Submitted => entities::QuoteStatus::Submitted,
+            QuoteStatus::Landed => entities::QuoteStatus::Landed,
+            QuoteStatus::Expired => entities::QuoteStatus::Expired,
+            QuoteStatus::Failed => entities::QuoteStatus::Failed,
+        }
+    }
+}
+
+#[derive(Clone, FromRow, Debug)]
+pub struct Quote {
+    pub id:                          Uuid,
+    pub chain_id:                    String,
+    pub opportunity_id:              Uuid,
+    pub bid_id:                      Uuid,
+    pub user_wallet_address:         String,
+    pub input_token:                 JsonValue,
+    pub output_token:                JsonValue,
+    pub maximum_slippage_percentage: f64,
+    pub signature:                   String,
+    pub expiration_time:             PrimitiveDateTime,
+    pub status:                      QuoteStatus,
+    pub creation_time:               PrimitiveDateTime,
+    #[allow(dead_code)]
+    pub update_time:                 PrimitiveDateTime,
+}
+
+impl TryFrom<Quote> for entities::QuoteDetails {
+    type Error = anyhow::Error;
+
+    fn try_from(quote: Quote) -> Result<Self, Self::Error> {
+        Ok(entities::QuoteDetails {
+            id:                          quote.id,
+            chain_id:                    quote.chain_id,
+            opportunity_id:              quote.opportunity_id,
+            bid_id:                      quote.bid_id,
+            user_wallet_address:         quote.user_wallet_address.parse()?,
+            input_token:                 serde_json::from_value(quote.input_token)?,
+            output_token:                serde_json::from_value(quote.output_token)?,
+            maximum_slippage_percentage: quote.maximum_slippage_percentage,
+            signature:                   quote.signature.parse()?,
+            expiration_time:             quote.expiration_time.assume_utc(),
+            status:                      quote.status.into(),
+            creation_time:               quote.creation_time.assume_utc(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "storage_layout", rename_all = "lowercase")]
+pub enum StorageLayout {
+    Solidity,
+    Vyper,
+}
+
+impl From<entities::StorageLayout> for StorageLayout {
+    fn from(layout: entities::StorageLayout) -> Self {
+        match layout {
+            entities::StorageLayout::Solidity => StorageLayout::Solidity,
+            entities::StorageLayout::Vyper => StorageLayout::Vyper,
+        }
+    }
+}
+
+impl From<StorageLayout> for entities::StorageLayout {
+    fn from(layout: StorageLayout) -> Self {
+        match layout {
+            StorageLayout::Solidity => entities::StorageLayout::Solidity,
+            StorageLayout::Vyper => entities::StorageLayout::Vyper,
+        }
+    }
+}
+
+pub fn convert_slot(slot: U256) -> BigDecimal {
+    BigDecimal::from_str(&slot.to_string()).expect("Failed to convert slot to BigDecimal")
+}
+
+/// The spoof slots of a token, either found by the server or set manually by the admins.
+#[derive(Clone, FromRow, Debug)]
+pub struct TokenSpoofInfo {
+    pub token:          Vec<u8>,
+    pub balance_slot:   BigDecimal,
+    pub allowance_slot: BigDecimal,
+    pub layout:         StorageLayout,
+    pub implementation: Option<Vec<u8>>,
+}
+
+impl TryFrom<TokenSpoofInfo> for entities::SpoofInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(info: TokenSpoofInfo) -> Result<Self, Self::Error> {
+        Ok(entities::SpoofInfo {
+            token:          Address::from_slice(&info.token),
+            state:          entities::SpoofState::Spoofed {
+                balance_slot:   U256::from_dec_str(&info.balance_slot.to_string())?,
+                allowance_slot: U256::from_dec_str(&info.allowance_slot.to_string())?,
+                layout:         info.layout.into(),
+            },
+            implementation: info
+                .implementation
+                .map(|implementation| Address::from_slice(&implementation)),
+        })
+    }
 }
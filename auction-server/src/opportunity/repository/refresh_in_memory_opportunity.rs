@@ -20,8 +20,12 @@ impl<T: InMemoryStore> Repository<T> {
         match write_guard.entry(key.clone()) {
             Entry::Occupied(mut entry) => {
                 let opportunities = entry.get_mut();
-                match opportunities.iter().position(|o| *o == opportunity) {
-                    Some(index) => opportunities[index] = refreshed_opportunity.clone(),
+                // Compare by id, the state of the stored opportunity may have changed in the meantime
+                match opportunities.iter_mut().find(|o| o.id == opportunity.id) {
+                    Some(stored) => {
+                        stored.refresh();
+                        refreshed_opportunity = stored.clone();
+                    }
                     None => {
                         tracing::error!(opportunity = ?opportunity, "Refresh opportunity failed, opportunity not found");
                     }
@@ -1,6 +1,9 @@
 use {
     super::{
-        models::OpportunityRemovalReason,
+        models::{
+            OpportunityRemovalReason,
+            OpportunityState,
+        },
         InMemoryStore,
         Repository,
     },
@@ -9,7 +12,10 @@ use {
             ChainId,
             PermissionKey,
         },
-        opportunity::entities,
+        opportunity::entities::{
+            self,
+            OpportunityId,
+        },
     },
     sqlx::Postgres,
     time::{
@@ -19,6 +25,8 @@ use {
 };
 
 impl<T: InMemoryStore> Repository<T> {
+    /// Removes the opportunities of the key whose state allows it.
+    /// The opportunities in an auction are kept, and only the removed opportunities are returned.
     pub async fn remove_opportunities(
         &self,
         db: &sqlx::Pool<Postgres>,
@@ -27,19 +35,36 @@ impl<T: InMemoryStore> Repository<T> {
         opportunity_key: &entities::OpportunityKey,
         reason: OpportunityRemovalReason,
     ) -> anyhow::Result<Vec<T::Opportunity>> {
+        let previous_states: Vec<OpportunityState> = entities::OpportunityState::Removed
+            .get_previous_states()
+            .into_iter()
+            .map(|state| state.into())
+            .collect();
         let now = OffsetDateTime::now_utc();
-        sqlx::query("UPDATE opportunity SET removal_time = $1, removal_reason = $2 WHERE permission_key = $3 AND chain_id = $4 and removal_time IS NULL")
+        let removed_ids: Vec<OpportunityId> = sqlx::query_scalar("UPDATE opportunity SET removal_time = $1, removal_reason = $2, state = $3 WHERE permission_key = $4 AND chain_id = $5 AND state = ANY($6) RETURNING id")
             .bind(PrimitiveDateTime::new(now.date(), now.time()))
             .bind(reason)
+            .bind(OpportunityState::Removed)
             .bind(permission_key.as_ref())
             .bind(chain_id)
-            .execute(db)
+            .bind(previous_states)
+            .fetch_all(db)
             .await?;
 
         let mut write_guard = self.in_memory_store.opportunities.write().await;
-        let opportunities = write_guard.remove(opportunity_key);
+        let mut removed_opportunities = vec![];
+        if let Some(opportunities) = write_guard.get_mut(opportunity_key) {
+            let (removed, remaining): (Vec<_>, Vec<_>) = opportunities
+                .drain(..)
+                .partition(|opportunity| removed_ids.contains(&opportunity.id));
+            removed_opportunities = removed;
+            *opportunities = remaining;
+            if opportunities.is_empty() {
+                write_guard.remove(opportunity_key);
+            }
+        }
         drop(write_guard);
 
-        Ok(opportunities.unwrap_or_default())
+        Ok(removed_opportunities)
     }
 }
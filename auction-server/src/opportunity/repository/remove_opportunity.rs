@@ -1,6 +1,9 @@
 use {
     super::{
-        models::OpportunityRemovalReason,
+        models::{
+            OpportunityRemovalReason,
+            OpportunityState,
+        },
         InMemoryStore,
         Repository,
     },
@@ -16,26 +19,38 @@ use {
 };
 
 impl<T: InMemoryStore> Repository<T> {
+    /// Removes the opportunity if its state allows it.
+    /// Returns false if the opportunity is in an auction or was already removed.
     pub async fn remove_opportunity(
         &self,
         db: &sqlx::Pool<Postgres>,
         opportunity: &T::Opportunity,
         reason: entities::OpportunityRemovalReason,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<bool> {
         let reason: OpportunityRemovalReason = reason.into();
+        let previous_states: Vec<OpportunityState> = entities::OpportunityState::Removed
+            .get_previous_states()
+            .into_iter()
+            .map(|state| state.into())
+            .collect();
         let now = OffsetDateTime::now_utc();
-        sqlx::query("UPDATE opportunity SET removal_time = $1, removal_reason = $2 WHERE id = $3 AND removal_time IS NULL")
+        let result = sqlx::query("UPDATE opportunity SET removal_time = $1, removal_reason = $2, state = $3 WHERE id = $4 AND state = ANY($5)")
             .bind(PrimitiveDateTime::new(now.date(), now.time()))
             .bind(reason)
+            .bind(OpportunityState::Removed)
             .bind(opportunity.id)
+            .bind(previous_states)
             .execute(db)
             .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
 
         let key = opportunity.get_key();
         let mut write_guard = self.in_memory_store.opportunities.write().await;
         let entry = write_guard.entry(key.clone());
         if entry
-            .and_modify(|opps| opps.retain(|o| o != opportunity))
+            .and_modify(|opps| opps.retain(|o| o.id != opportunity.id))
             .or_default()
             .is_empty()
         {
@@ -43,6 +58,6 @@ impl<T: InMemoryStore> Repository<T> {
         }
         drop(write_guard);
 
-        Ok(())
+        Ok(true)
     }
 }
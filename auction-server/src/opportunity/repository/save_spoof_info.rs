@@ -0,0 +1,56 @@
+use {
+    super::{
+        models,
+        InMemoryStoreEvm,
+        Repository,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    sqlx::Postgres,
+    time::{
+        OffsetDateTime,
+        PrimitiveDateTime,
+    },
+};
+
+impl Repository<InMemoryStoreEvm> {
+    /// Persist the slots found by the server for a token.
+    /// Tokens which could not be spoofed are not persisted, so they are checked again after a restart.
+    pub async fn save_spoof_info(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        chain_id: &ChainId,
+        spoof_info: &entities::SpoofInfo,
+    ) -> Result<(), RestError> {
+        let entities::SpoofState::Spoofed {
+            balance_slot,
+            allowance_slot,
+            layout,
+        } = spoof_info.state
+        else {
+            return Ok(());
+        };
+        let now = OffsetDateTime::now_utc();
+        sqlx::query(
+            "INSERT INTO token_spoof_info (chain_id, token, balance_slot, allowance_slot, layout, implementation, creation_time) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (chain_id, token) DO UPDATE SET balance_slot = EXCLUDED.balance_slot, allowance_slot = EXCLUDED.allowance_slot, layout = EXCLUDED.layout, implementation = EXCLUDED.implementation, creation_time = EXCLUDED.creation_time",
+        )
+        .bind(chain_id)
+        .bind(spoof_info.token.as_bytes())
+        .bind(models::convert_slot(balance_slot))
+        .bind(models::convert_slot(allowance_slot))
+        .bind(models::StorageLayout::from(layout))
+        .bind(spoof_info.implementation.map(|implementation| implementation.as_bytes().to_vec()))
+        .bind(PrimitiveDateTime::new(now.date(), now.time()))
+        .execute(db)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = e.to_string(), token = ?spoof_info.token, "DB: Failed to insert spoof info");
+            RestError::TemporarilyUnavailable
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use {
+    super::{
+        models::OpportunityState,
+        InMemoryStore,
+        Repository,
+    },
+    crate::opportunity::entities::{
+        self,
+        Opportunity,
+    },
+    sqlx::Postgres,
+    uuid::Uuid,
+};
+
+impl<T: InMemoryStore> Repository<T> {
+    /// Moves the live opportunities of the key to the new state.
+    /// Only the opportunities whose current state allows the transition are updated and returned.
+    pub async fn update_opportunities_state(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        key: &entities::OpportunityKey,
+        state: entities::OpportunityState,
+    ) -> anyhow::Result<Vec<T::Opportunity>> {
+        let previous_states: Vec<OpportunityState> = state
+            .get_previous_states()
+            .into_iter()
+            .map(|state| state.into())
+            .collect();
+        let updated_ids: Vec<Uuid> = sqlx::query_scalar(
+            "UPDATE opportunity SET state = $1 WHERE chain_id = $2 AND permission_key = $3 AND state = ANY($4) RETURNING id",
+        )
+        .bind(OpportunityState::from(state))
+        .bind(&key.0)
+        .bind(key.1.as_ref())
+        .bind(previous_states)
+        .fetch_all(db)
+        .await?;
+
+        let mut write_guard = self.in_memory_store.opportunities.write().await;
+        let updated = match write_guard.get_mut(key) {
+            Some(opportunities) => opportunities
+                .iter_mut()
+                .filter(|opportunity| updated_ids.contains(&opportunity.id))
+                .map(|opportunity| {
+                    opportunity.set_state(state);
+                    opportunity.clone()
+                })
+                .collect(),
+            None => vec![],
+        };
+        drop(write_guard);
+
+        Ok(updated)
+    }
+}
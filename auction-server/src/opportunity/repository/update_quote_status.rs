@@ -0,0 +1,44 @@
+use {
+    super::{
+        models,
+        InMemoryStoreSvm,
+        Repository,
+    },
+    crate::{
+        auction::entities::BidId,
+        opportunity::entities,
+    },
+    sqlx::Postgres,
+    time::{
+        OffsetDateTime,
+        PrimitiveDateTime,
+    },
+};
+
+impl Repository<InMemoryStoreSvm> {
+    /// Moves the quote of the bid to the new status and returns true if the quote was updated.
+    /// Quotes are only moved forward, so that delayed updates are ignored.
+    pub async fn update_quote_status(
+        &self,
+        db: &sqlx::Pool<Postgres>,
+        bid_id: BidId,
+        status: entities::QuoteStatus,
+    ) -> anyhow::Result<bool> {
+        let now = OffsetDateTime::now_utc();
+        let previous_statuses: Vec<models::QuoteStatus> = status
+            .get_previous_statuses()
+            .into_iter()
+            .map(|status| status.into())
+            .collect();
+        let result = sqlx::query(
+            "UPDATE quote SET status = $1, update_time = $2 WHERE bid_id = $3 AND status = ANY($4)",
+        )
+        .bind(models::QuoteStatus::from(status))
+        .bind(PrimitiveDateTime::new(now.date(), now.time()))
+        .bind(bid_id)
+        .bind(previous_statuses)
+        .execute(db)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
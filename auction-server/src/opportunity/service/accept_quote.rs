@@ -0,0 +1,192 @@
+use {
+    super::{
+        update_opportunities_state::UpdateOpportunitiesStateInput,
+        ChainTypeSvm,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        auction::{
+            entities::{
+                Auction,
+                BidId,
+                BidStatusAuction,
+            },
+            service::{
+                add_auction::AddAuctionInput,
+                auction_manager::AuctionManager,
+                update_bid_status::UpdateBidStatusInput,
+                update_submitted_auction::UpdateSubmittedAuctionInput,
+                Service as AuctionService,
+            },
+        },
+        kernel::entities::Svm,
+        opportunity::entities::{
+            self,
+            Opportunity as _,
+        },
+    },
+    axum_prometheus::metrics,
+    futures::future::join_all,
+    time::OffsetDateTime,
+    uuid::Uuid,
+};
+
+pub struct AcceptQuoteInput {
+    pub quote_request: entities::QuoteRequest,
+    /// The live bid on the quote opportunity which wins the auction.
+    pub bid_id:        BidId,
+}
+
+impl Service<ChainTypeSvm> {
+    /// Runs the auction of the quote opportunity with the accepted bid as the winner
+    /// and returns the relayer signed transaction of the bid.
+    #[tracing::instrument(skip_all, fields(bid_id = %input.bid_id))]
+    pub async fn accept_quote(
+        &self,
+        input: AcceptQuoteInput,
+    ) -> Result<entities::Quote, RestError> {
+        let quote_request = input.quote_request;
+        let config = self.get_config(&quote_request.quote_create.chain_id)?;
+        let auction_service = config.get_auction_service().await;
+
+        let bid_collection_time = OffsetDateTime::now_utc();
+        let bids = self.get_quote_bids(&quote_request).await?;
+        let winner_bid = bids
+            .iter()
+            .find(|bid| bid.id == input.bid_id)
+            .cloned()
+            .ok_or(RestError::BadParameters(
+                "Bid is not live for the quote anymore".to_string(),
+            ))?;
+
+        // Find the submit bid instruction from bid transaction to extract the deadline
+        let submit_bid_instruction = auction_service
+            .verify_submit_bid_instruction(winner_bid.chain_data.transaction.clone())
+            .map_err(|e| {
+                tracing::error!("Failed to verify submit bid instruction: {:?}", e);
+                RestError::TemporarilyUnavailable
+            })?;
+        let submit_bid_data = AuctionService::<Svm>::extract_submit_bid_data(
+            &submit_bid_instruction,
+        )
+        .map_err(|e| {
+            tracing::error!("Failed to extract submit bid data: {:?}", e);
+            RestError::TemporarilyUnavailable
+        })?;
+
+        // The opportunity can not be removed or accepted again while its auction is running
+        let opportunity_key = quote_request.opportunity.get_key();
+        let in_auction = self
+            .update_opportunities_state(UpdateOpportunitiesStateInput {
+                key:   opportunity_key.clone(),
+                state: entities::OpportunityState::InAuction,
+            })
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "Failed to start the quote auction");
+                RestError::TemporarilyUnavailable
+            })?;
+        if !in_auction
+            .iter()
+            .any(|opportunity| opportunity.id == quote_request.opportunity.id)
+        {
+            return Err(RestError::BadParameters(
+                "Quote is already accepted or cancelled".to_string(),
+            ));
+        }
+
+        let result: Result<_, RestError> = async {
+            // Bids is not empty
+            let auction = Auction::try_new(bids.clone(), bid_collection_time)
+                .expect("Failed to create auction for bids");
+
+            let auction = auction_service
+                .add_auction(AddAuctionInput { auction })
+                .await?;
+
+            let mut bid = winner_bid.clone();
+            auction_service
+                .add_relayer_signature(&mut bid)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to add relayer signature: {:?}", e);
+                    RestError::TemporarilyUnavailable
+                })?;
+
+            let quote = entities::Quote {
+                id:                          Uuid::new_v4(),
+                opportunity_id:              quote_request.opportunity.id,
+                bid_id:                      bid.id,
+                user_wallet_address:         quote_request.quote_create.user_wallet_address,
+                transaction:                 bid.chain_data.transaction.clone(),
+                expiration_time:             submit_bid_data.deadline,
+                input_token:                 quote_request.price_estimate.input_token.clone(),
+                output_token:                quote_request.price_estimate.output_token.clone(),
+                maximum_slippage_percentage: quote_request.quote_create.maximum_slippage_percentage,
+                chain_id:                    quote_request.quote_create.chain_id.clone(),
+            };
+            // The quote is stored before the auction is submitted, so every submitted auction has its quote
+            self.repo.add_quote(&self.db, &quote).await?;
+
+            let auction = auction_service
+                .update_submitted_auction(UpdateSubmittedAuctionInput {
+                    auction,
+                    transaction_hash: bid.chain_data.transaction.signatures[0],
+                })
+                .await?;
+            Ok((auction, bid, quote))
+        }
+        .await;
+
+        // The opportunity stays in the auction until the quote transaction is concluded,
+        // and the quote can be accepted again if the auction failed
+        if result.is_err() {
+            if let Err(e) = self
+                .update_opportunities_state(UpdateOpportunitiesStateInput {
+                    key:   opportunity_key,
+                    state: entities::OpportunityState::Live,
+                })
+                .await
+            {
+                tracing::error!(error = ?e, "Failed to move the quote opportunity back to live");
+            }
+        }
+        let (auction, bid, quote) = result?;
+        let signature = bid.chain_data.transaction.signatures[0];
+
+        metrics::counter!(
+            "quote_status_updates_total",
+            &[
+                ("chain_id", quote.chain_id.clone()),
+                ("status", "issued".to_string()),
+            ]
+        )
+        .increment(1);
+
+        self.task_tracker.spawn({
+            let winner_bid = winner_bid.clone();
+            let auction_service = auction_service.clone();
+            async move {
+                join_all(auction.bids.iter().map(|bid| {
+                    auction_service.update_bid_status(UpdateBidStatusInput {
+                        new_status: AuctionService::get_new_status(
+                            bid,
+                            &vec![winner_bid.clone()],
+                            BidStatusAuction {
+                                tx_hash: signature,
+                                id:      auction.id,
+                            },
+                        ),
+                        bid:        bid.clone(),
+                    })
+                }))
+                .await;
+                // The opportunity is not removed here, as it can not be removed while in the auction.
+                // It keeps refusing new bids until the verification loop removes it after the auction is concluded.
+            }
+        });
+
+        Ok(quote)
+    }
+}
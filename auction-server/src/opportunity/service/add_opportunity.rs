@@ -2,11 +2,15 @@ use {
     super::{
         verification::Verification,
         ChainType,
+        Config,
         Service,
     },
     crate::{
         api::{
-            ws::UpdateEvent::NewOpportunity,
+            ws::UpdateEvent::{
+                NewOpportunity,
+                OpportunityExclusivityEnded,
+            },
             RestError,
         },
         opportunity::{
@@ -19,6 +23,7 @@ use {
             service::verification::VerifyOpportunityInput,
         },
     },
+    time::OffsetDateTime,
 };
 
 pub struct AddOpportunityInput<T: entities::OpportunityCreate> {
@@ -58,11 +63,50 @@ where
         }
         OpportunityAction::Add
     }
+
+    /// The searchers out of the allowlist do not receive the opportunity during the exclusivity window,
+    /// so the opportunity is sent to them when the window ends if it is still available.
+    fn broadcast_after_exclusivity(
+        &self,
+        opportunity_id: entities::OpportunityId,
+        exclusivity: entities::OpportunityExclusivity,
+    ) {
+        self.task_tracker.spawn({
+            let (store, repo) = (self.store.clone(), self.repo.clone());
+            async move {
+                let delay = exclusivity.end_time - OffsetDateTime::now_utc();
+                tokio::time::sleep(delay.try_into().unwrap_or_default()).await;
+                if let Some(opportunity) =
+                    repo.get_in_memory_opportunity_by_id(opportunity_id).await
+                {
+                    let fields = opportunity.get_subscription_fields();
+                    if let Err(e) = store
+                        .ws
+                        .broadcast_sender
+                        .send(OpportunityExclusivityEnded(opportunity.into(), fields))
+                    {
+                        tracing::error!(
+                            error = e.to_string(),
+                            opportunity_id = %opportunity_id,
+                            "Failed to broadcast opportunity after its exclusivity window"
+                        );
+                    }
+                }
+            }
+        });
+    }
+
     pub async fn add_opportunity(
         &self,
         input: AddOpportunityInput<OpportunityCreateType<T>>,
     ) -> Result<<T::InMemoryStore as InMemoryStore>::Opportunity, RestError> {
         let opportunity_create = input.opportunity;
+        let config = self.get_config(&opportunity_create.get_key().0)?;
+        if !config.get_chain_status().await.accepts_opportunities() {
+            return Err(RestError::ChainUnderMaintenance);
+        }
+        opportunity_create.validate_lifetime()?;
+
         let action = self.assess_action(&opportunity_create).await;
         if let OpportunityAction::Ignore = action {
             tracing::info!("Submitted opportunity ignored: {:?}", opportunity_create);
@@ -87,15 +131,23 @@ where
         let opportunity = if let OpportunityAction::Refresh(opp) = action {
             self.repo.refresh_in_memory_opportunity(opp.clone()).await
         } else {
-            self.repo
+            let opportunity = self
+                .repo
                 .add_opportunity(&self.db, opportunity_create.clone())
-                .await?
+                .await?;
+            if let Some(exclusivity) = opportunity.exclusivity.clone() {
+                self.broadcast_after_exclusivity(opportunity.id, exclusivity);
+            }
+            opportunity
         };
 
         self.store
             .ws
             .broadcast_sender
-            .send(NewOpportunity(opportunity.clone().into()))
+            .send(NewOpportunity(
+                opportunity.clone().into(),
+                opportunity.get_subscription_fields(),
+            ))
             .map_err(|e| {
                 tracing::error!(
                     "Failed to send update: {} - opportunity: {:?}",
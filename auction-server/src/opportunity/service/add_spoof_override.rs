@@ -0,0 +1,46 @@
+use {
+    super::{
+        ChainTypeEvm,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    ethers::types::{
+        Address,
+        U256,
+    },
+};
+
+pub struct AddSpoofOverrideInput {
+    pub chain_id:       ChainId,
+    pub token:          Address,
+    pub balance_slot:   U256,
+    pub allowance_slot: U256,
+    pub layout:         entities::StorageLayout,
+}
+
+impl Service<ChainTypeEvm> {
+    /// Set the balance and allowance slots of a token which can not be found by the server.
+    #[tracing::instrument(skip_all, fields(token=%input.token))]
+    pub async fn add_spoof_override(&self, input: AddSpoofOverrideInput) -> Result<(), RestError> {
+        self.get_config(&input.chain_id)?;
+        self.repo
+            .add_spoof_override(
+                &self.db,
+                input.chain_id,
+                entities::SpoofInfo {
+                    token:          input.token,
+                    state:          entities::SpoofState::Spoofed {
+                        balance_slot:   input.balance_slot,
+                        allowance_slot: input.allowance_slot,
+                        layout:         input.layout,
+                    },
+                    implementation: None,
+                },
+            )
+            .await
+    }
+}
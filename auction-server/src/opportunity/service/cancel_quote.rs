@@ -0,0 +1,59 @@
+use {
+    super::{
+        ChainTypeSvm,
+        Service,
+    },
+    crate::{
+        api::{
+            ws::UpdateEvent,
+            RestError,
+        },
+        opportunity::entities::{
+            self,
+            Opportunity as _,
+        },
+    },
+};
+
+pub struct CancelQuoteInput {
+    pub quote_request: entities::QuoteRequest,
+}
+
+impl Service<ChainTypeSvm> {
+    /// Removes the opportunity of a quote which will not be accepted,
+    /// so that the searchers stop bidding on it.
+    pub async fn cancel_quote(&self, input: CancelQuoteInput) -> Result<(), RestError> {
+        let opportunity = input.quote_request.opportunity;
+        let removal_reason = entities::OpportunityRemovalReason::Invalid(
+            RestError::InvalidOpportunity("Quote was cancelled".to_string()),
+        );
+        let opportunity_delete = opportunity.get_opportunity_delete((&removal_reason).into());
+        let is_removed = self
+            .repo
+            .remove_opportunity(&self.db, &opportunity, removal_reason)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, opportunity = ?opportunity, "Failed to remove quote opportunity");
+                RestError::TemporarilyUnavailable
+            })?;
+        if !is_removed {
+            return Err(RestError::BadParameters(
+                "Quote is already accepted".to_string(),
+            ));
+        }
+
+        self.store
+            .ws
+            .broadcast_sender
+            .send(UpdateEvent::RemoveOpportunities(opportunity_delete))
+            .map_err(|e| {
+                tracing::error!(
+                    error = e.to_string(),
+                    opportunity = ?opportunity,
+                    "Failed to send remove opportunities",
+                );
+                RestError::TemporarilyUnavailable
+            })?;
+        Ok(())
+    }
+}
@@ -7,17 +7,166 @@ use {
         api::RestError,
         opportunity::entities,
     },
+    axum_prometheus::metrics,
+    time::{
+        Duration,
+        OffsetDateTime,
+    },
 };
 
+/// Only the trades in this window before the quote are used for the estimation.
+const TRADE_HISTORY_WINDOW: Duration = Duration::hours(24);
+const TRADE_HISTORY_LIMIT: i64 = 50;
+/// Number of recent trades needed to fully trust the trade history.
+const FULL_CONFIDENCE_TRADES: usize = 10;
+/// Confidence of an estimation only based on the reference price source.
+const REFERENCE_PRICE_CONFIDENCE: f64 = 0.5;
+
 pub struct EstimatePriceInput {
-    #[allow(dead_code)]
     pub quote_create: entities::QuoteCreate,
 }
 
+fn get_median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let middle = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
+
+/// Returns the median rate of the trades and how much it can be trusted.
+/// The confidence grows with the number of trades and shrinks with their dispersion.
+fn get_history_rate(trades: &[entities::Trade]) -> Option<(f64, f64)> {
+    if trades.is_empty() {
+        return None;
+    }
+    let rates: Vec<f64> = trades
+        .iter()
+        .map(|trade| trade.output_amount as f64 / trade.input_amount as f64)
+        .collect();
+    let median = get_median(rates.clone());
+    let deviation = get_median(
+        rates
+            .iter()
+            .map(|rate| (rate - median).abs() / median)
+            .collect(),
+    );
+    let confidence = (trades.len().min(FULL_CONFIDENCE_TRADES) as f64
+        / FULL_CONFIDENCE_TRADES as f64)
+        * (1.0 - deviation).max(0.0);
+    Some((median, confidence))
+}
+
 impl Service<ChainTypeSvm> {
+    async fn get_reference_rate(&self, quote_create: &entities::QuoteCreate) -> Option<f64> {
+        let config = self.get_config(&quote_create.chain_id).ok()?;
+        let price_source = config.price_source.as_ref()?;
+        price_source
+            .get_rate(
+                &quote_create.tokens.get_input_mint(),
+                &quote_create.tokens.get_output_mint(),
+            )
+            .await
+            .map_err(|e| {
+                tracing::warn!(error = ?e, "Failed to get the reference price");
+            })
+            .ok()
+            .flatten()
+    }
+
+    /// Estimates the amount of the quote which is not specified by the user from the recent limo trades
+    /// of the same token pair and the reference price source of the chain.
     #[tracing::instrument(skip_all)]
-    pub async fn estimate_price(&self, _input: EstimatePriceInput) -> Result<u64, RestError> {
-        // TODO implement
-        return Ok(0);
+    pub async fn estimate_price(
+        &self,
+        input: EstimatePriceInput,
+    ) -> Result<entities::PriceEstimate, RestError> {
+        let quote_create = input.quote_create;
+        let trades = self
+            .repo
+            .get_recent_trades(
+                &self.db,
+                &quote_create.chain_id,
+                &quote_create.tokens.get_input_mint(),
+                &quote_create.tokens.get_output_mint(),
+                OffsetDateTime::now_utc() - TRADE_HISTORY_WINDOW,
+                TRADE_HISTORY_LIMIT,
+            )
+            .await?;
+        let history = get_history_rate(&trades);
+        let reference_rate = self.get_reference_rate(&quote_create).await;
+
+        let (rate, confidence) = match (history, reference_rate) {
+            (Some((history_rate, history_confidence)), Some(reference_rate)) => {
+                // The history gets more weight as it becomes more reliable,
+                // and the confidence drops when the two sources disagree.
+                let rate =
+                    history_rate * history_confidence + reference_rate * (1.0 - history_confidence);
+                let agreement =
+                    (1.0 - (history_rate - reference_rate).abs() / reference_rate).max(0.0);
+                let confidence = (REFERENCE_PRICE_CONFIDENCE
+                    + (1.0 - REFERENCE_PRICE_CONFIDENCE) * history_confidence)
+                    * (1.0 - history_confidence * (1.0 - agreement));
+                (rate, confidence)
+            }
+            (Some((history_rate, history_confidence)), None) => (history_rate, history_confidence),
+            (None, Some(reference_rate)) => (reference_rate, REFERENCE_PRICE_CONFIDENCE),
+            (None, None) => {
+                tracing::warn!(quote_create = ?quote_create, "No price source is available for the quote");
+                return Err(RestError::QuoteNotFound);
+            }
+        };
+
+        metrics::histogram!(
+            "quote_price_estimation_confidence",
+            &[("chain_id", quote_create.chain_id.clone())]
+        )
+        .record(confidence);
+        tracing::info!(
+            trades = trades.len(),
+            reference_rate = ?reference_rate,
+            rate,
+            confidence,
+            "Estimated quote price"
+        );
+        // The conversions saturate for out of range values
+        let (input_token, output_token) = match quote_create.tokens {
+            entities::QuoteTokens::InputTokenSpecified {
+                input_token,
+                output_token,
+            } => {
+                let output_token = entities::TokenAmountSvm {
+                    token:  output_token,
+                    amount: (input_token.amount as f64 * rate) as u64,
+                };
+                (input_token, output_token)
+            }
+            entities::QuoteTokens::OutputTokenSpecified {
+                input_token,
+                output_token,
+            } => {
+                let input_token = entities::TokenAmountSvm {
+                    token:  input_token,
+                    amount: if rate > 0.0 {
+                        (output_token.amount as f64 / rate).ceil() as u64
+                    } else {
+                        0
+                    },
+                };
+                (input_token, output_token)
+            }
+        };
+        // The searchers cannot size their bids against a zero reference
+        if input_token.amount == 0 || output_token.amount == 0 {
+            tracing::warn!(input_token = ?input_token, output_token = ?output_token, rate, "Estimated quote amount is zero");
+            return Err(RestError::QuoteNotFound);
+        }
+        Ok(entities::PriceEstimate {
+            input_token,
+            output_token,
+            confidence,
+        })
     }
 }
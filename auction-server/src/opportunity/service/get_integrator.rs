@@ -0,0 +1,28 @@
+use {
+    super::{
+        ChainTypeSvm,
+        Service,
+    },
+    crate::{
+        kernel::entities::ChainId,
+        models,
+    },
+    solana_sdk::pubkey::Pubkey,
+};
+
+pub struct GetIntegratorByRouterInput {
+    pub chain_id: ChainId,
+    pub router:   Pubkey,
+}
+
+impl Service<ChainTypeSvm> {
+    /// Get the registered integrator which receives its fees on the router account.
+    pub async fn get_integrator_by_router(
+        &self,
+        input: GetIntegratorByRouterInput,
+    ) -> Option<models::Integrator> {
+        self.store
+            .get_integrator_by_router(&input.chain_id, &input.router)
+            .await
+    }
+}
@@ -1,6 +1,7 @@
 use {
     super::{
         ChainType,
+        ChainTypeEnum,
         Service,
     },
     crate::{
@@ -10,9 +11,14 @@ use {
                 GetOpportunitiesQueryParams,
                 OpportunityMode,
             },
+            entities,
             repository::InMemoryStore,
         },
     },
+    ethers::types::Address,
+    serde_json::Value as JsonValue,
+    solana_sdk::pubkey::Pubkey,
+    std::str::FromStr,
 };
 
 pub struct GetOpportunitiesInput {
@@ -20,6 +26,22 @@ pub struct GetOpportunitiesInput {
 }
 
 impl<T: ChainType> Service<T> {
+    /// Parse the token filter into the representation the token amounts of this chain type are stored with.
+    fn parse_token(token: &str) -> Result<JsonValue, RestError> {
+        let invalid_token = || RestError::BadParameters(format!("Invalid token: {}", token));
+        match T::get_type() {
+            ChainTypeEnum::Evm => {
+                serde_json::to_value(Address::from_str(token).map_err(|_| invalid_token())?)
+                    .map_err(|_| invalid_token())
+            }
+            ChainTypeEnum::Svm => Ok(JsonValue::String(
+                Pubkey::from_str(token)
+                    .map_err(|_| invalid_token())?
+                    .to_string(),
+            )),
+        }
+    }
+
     pub async fn get_opportunities(
         &self,
         input: GetOpportunitiesInput,
@@ -29,6 +51,28 @@ impl<T: ChainType> Service<T> {
             self.get_config(&chain_id)?;
         }
 
+        // The program and router filters only apply to the svm opportunities
+        if matches!(T::get_type(), ChainTypeEnum::Evm)
+            && (query_params.program.is_some() || query_params.router.is_some())
+        {
+            return Ok(vec![]);
+        }
+
+        let filter = entities::OpportunityFilter {
+            chain_id:       query_params.chain_id.clone(),
+            permission_key: query_params.permission_key.clone(),
+            from_time:      query_params.from_time,
+            cursor:         query_params.cursor.map(|cursor| cursor.0),
+            program:        query_params.program.clone(),
+            router:         query_params.router,
+            token:          query_params
+                .token
+                .as_deref()
+                .map(Self::parse_token)
+                .transpose()?,
+            profile_id:     query_params.profile_id,
+        };
+
         match query_params.mode.clone() {
             OpportunityMode::Live => Ok(self
                 .repo
@@ -41,32 +85,16 @@ impl<T: ChainType> Service<T> {
                         .expect("An opportunity key vector should have at least one opportunity");
                     opportunity.clone()
                 })
-                .filter(|opportunity| {
-                    let filter_time = if let Some(from_time) = query_params.from_time {
-                        opportunity.creation_time >= from_time
-                    } else {
-                        true
-                    };
-
-                    let filter_chain_id = if let Some(chain_id) = &query_params.chain_id {
-                        opportunity.chain_id == *chain_id
-                    } else {
-                        true
-                    };
-                    filter_time && filter_chain_id
-                })
+                .filter(|opportunity| filter.matches(opportunity))
                 .collect()),
             OpportunityMode::Historical => {
-                let chain_id = query_params.chain_id.clone().ok_or_else(|| {
-                    RestError::BadParameters("Chain id is required on historical mode".to_string())
-                })?;
+                if filter.chain_id.is_none() {
+                    return Err(RestError::BadParameters(
+                        "Chain id is required on historical mode".to_string(),
+                    ));
+                }
                 self.repo
-                    .get_opportunities(
-                        &self.db,
-                        chain_id,
-                        query_params.permission_key.clone(),
-                        query_params.from_time,
-                    )
+                    .get_opportunities(&self.db, filter, query_params.limit)
                     .await
             }
         }
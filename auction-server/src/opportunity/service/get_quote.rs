@@ -6,71 +6,222 @@ use {
     crate::{
         api::RestError,
         auction::{
-            entities::{
-                Auction,
-                BidStatusAuction,
-            },
+            entities::Bid,
             service::{
-                add_auction::AddAuctionInput,
-                auction_manager::AuctionManager,
+                get_accounts::GetAccountsInput,
                 get_live_bids::GetLiveBidsInput,
-                update_bid_status::UpdateBidStatusInput,
-                update_submitted_auction::UpdateSubmittedAuctionInput,
+                simulator::PendingAccounts,
                 Service as AuctionService,
             },
         },
-        kernel::entities::{
-            PermissionKeySvm,
-            Svm,
-        },
+        kernel::entities::Svm,
         opportunity::{
             entities,
             service::{
+                accept_quote::AcceptQuoteInput,
                 add_opportunity::AddOpportunityInput,
+                cancel_quote::CancelQuoteInput,
                 estimate_price::EstimatePriceInput,
+                verification::get_wallet_balances,
             },
         },
     },
     axum_prometheus::metrics,
-    futures::future::join_all,
     rand::Rng,
     solana_sdk::{
+        account::Account,
         clock::Slot,
-        pubkey::Pubkey,
+        pubkey::{
+            self,
+            Pubkey,
+        },
+    },
+    std::{
+        sync::Arc,
+        time::Duration,
     },
-    std::time::Duration,
     time::OffsetDateTime,
     tokio::time::sleep,
 };
 
 /// Time to wait for searchers to submit bids.
 const BID_COLLECTION_TIME: Duration = Duration::from_millis(500);
+/// The quote opportunity is removed after this duration if the quote is neither accepted nor cancelled.
+/// It should be longer than the quotes streamed over the websocket stay open.
+const QUOTE_OPPORTUNITY_LIFETIME: time::Duration = time::Duration::minutes(1);
+
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+/// Offset of the supply in the spl token mint account data, after the mint authority.
+const MINT_SUPPLY_OFFSET: usize = 36;
+const MINT_DECIMALS_OFFSET: usize = 44;
+const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+
+struct TokenMint {
+    supply:   u64,
+    decimals: u8,
+}
+
+impl TokenMint {
+    /// Formats the amount in whole tokens.
+    fn format_amount(&self, amount: u64) -> String {
+        let unit = 10u128.pow(self.decimals.into());
+        let amount = u128::from(amount);
+        format!(
+            "{}.{:0width$}",
+            amount / unit,
+            amount % unit,
+            width = self.decimals.into()
+        )
+    }
+}
+
+/// Returns the mint if the account is an initialized spl token mint.
+/// Native sol is also quoted through its wrapped mint.
+fn get_token_mint(account: &Option<Account>) -> Option<TokenMint> {
+    let account = account.as_ref()?;
+    if account.owner != TOKEN_PROGRAM_ID && account.owner != TOKEN_2022_PROGRAM_ID {
+        return None;
+    }
+    if account.data.get(MINT_IS_INITIALIZED_OFFSET) != Some(&1) {
+        return None;
+    }
+    let supply = account
+        .data
+        .get(MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8)?
+        .try_into()
+        .ok()?;
+    Some(TokenMint {
+        supply:   u64::from_le_bytes(supply),
+        decimals: *account.data.get(MINT_DECIMALS_OFFSET)?,
+    })
+}
 
 pub struct GetQuoteInput {
-    pub quote_create: entities::QuoteCreate,
+    pub quote_create:    entities::QuoteCreate,
+    /// The router account of the integrator which requested the quote.
+    pub router:          Pubkey,
+    /// The name of the integrator which requested the quote.
+    pub integrator_name: String,
 }
 
 impl Service<ChainTypeSvm> {
+    /// Checks the quote before spending a bid collection window on it.
+    /// The amount of the input token is checked against the user balance if it is specified by the user.
+    async fn validate_quote(
+        &self,
+        auction_service: &AuctionService<Svm>,
+        pending_accounts: Arc<PendingAccounts>,
+        quote_create: &entities::QuoteCreate,
+    ) -> Result<(), RestError> {
+        let specified_amount = quote_create.tokens.get_specified_amount();
+        if specified_amount == 0 {
+            return Err(RestError::BadParameters(
+                "Token amount should be greater than zero".to_string(),
+            ));
+        }
+        let input_mint = quote_create.tokens.get_input_mint();
+        let output_mint = quote_create.tokens.get_output_mint();
+        if input_mint == output_mint {
+            return Err(RestError::BadParameters(
+                "Input and output tokens should be different".to_string(),
+            ));
+        }
+
+        let mint_accounts = auction_service
+            .get_accounts(GetAccountsInput {
+                keys:             vec![input_mint, output_mint],
+                pending_accounts: Some(pending_accounts.clone()),
+            })
+            .await?;
+        let mut token_mints = vec![];
+        for (mint, account) in [input_mint, output_mint].iter().zip(mint_accounts.iter()) {
+            match get_token_mint(account) {
+                Some(token_mint) => token_mints.push(token_mint),
+                None => {
+                    return Err(RestError::BadParameters(format!(
+                        "Token {} is not a valid token mint",
+                        mint
+                    )))
+                }
+            }
+        }
+        // The specified amount is in the smallest unit of the token, so amounts which are
+        // larger than the whole supply are most likely not scaled with the token decimals
+        let specified_mint = match quote_create.tokens {
+            entities::QuoteTokens::InputTokenSpecified { .. } => &token_mints[0],
+            entities::QuoteTokens::OutputTokenSpecified { .. } => &token_mints[1],
+        };
+        if specified_amount > specified_mint.supply {
+            return Err(RestError::BadParameters(format!(
+                "Token amount {} is {} tokens with {} decimals, which is more than the token supply",
+                specified_amount,
+                specified_mint.format_amount(specified_amount),
+                specified_mint.decimals,
+            )));
+        }
+
+        if let entities::QuoteTokens::InputTokenSpecified { input_token, .. } = &quote_create.tokens
+        {
+            self.validate_input_balance(
+                auction_service,
+                pending_accounts,
+                quote_create.user_wallet_address,
+                input_token,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// The user wallet should hold enough of the input token, so that the quote can be executed.
+    async fn validate_input_balance(
+        &self,
+        auction_service: &AuctionService<Svm>,
+        pending_accounts: Arc<PendingAccounts>,
+        user_wallet_address: Pubkey,
+        input_token: &entities::TokenAmountSvm,
+    ) -> Result<(), RestError> {
+        let balance = get_wallet_balances(
+            auction_service,
+            pending_accounts,
+            user_wallet_address,
+            &[input_token.token],
+        )
+        .await?
+        .first()
+        .cloned()
+        .flatten()
+        .unwrap_or_default();
+        if balance < input_token.amount {
+            return Err(RestError::BadParameters(format!(
+                "User wallet balance of token {} is {}, which is less than the input amount {}",
+                input_token.token, balance, input_token.amount
+            )));
+        }
+        Ok(())
+    }
+
     async fn get_opportunity_create_for_quote(
         &self,
         quote_create: entities::QuoteCreate,
-        output_amount: u64,
+        router: Pubkey,
+        price_estimate: &entities::PriceEstimate,
     ) -> Result<entities::OpportunityCreateSvm, RestError> {
-        let chain_config = self.get_config(&quote_create.chain_id)?;
-        let router = chain_config.wallet_program_router_account;
+        self.get_config(&quote_create.chain_id)?;
         let permission_account = Pubkey::new_from_array(rand::thread_rng().gen());
 
         let core_fields = entities::OpportunityCoreFieldsCreate {
-            permission_key: entities::OpportunitySvm::get_permission_key(
+            permission_key:  entities::OpportunitySvm::get_permission_key(
                 router,
                 permission_account,
             ),
-            chain_id:       quote_create.chain_id,
-            sell_tokens:    vec![quote_create.input_token],
-            buy_tokens:     vec![entities::TokenAmountSvm {
-                token:  quote_create.output_mint_token,
-                amount: output_amount,
-            }],
+            chain_id:        quote_create.chain_id,
+            sell_tokens:     vec![price_estimate.input_token.clone()],
+            buy_tokens:      vec![price_estimate.output_token.clone()],
+            expiration_time: Some(OffsetDateTime::now_utc() + QUOTE_OPPORTUNITY_LIFETIME),
+            exclusivity:     None,
+            profile_id:      None,
         };
 
         Ok(entities::OpportunityCreateSvm {
@@ -81,6 +232,8 @@ impl Service<ChainTypeSvm> {
                 entities::OpportunitySvmProgramWallet {
                     user_wallet_address:         quote_create.user_wallet_address,
                     maximum_slippage_percentage: quote_create.maximum_slippage_percentage,
+                    price_confidence:            Some(price_estimate.confidence),
+                    specified_token_side:        quote_create.tokens.get_specified_token_side(),
                 },
             ),
             // TODO extract latest slot
@@ -88,21 +241,45 @@ impl Service<ChainTypeSvm> {
         })
     }
 
+    /// Validates the quote and creates the opportunity on which the searchers can bid.
     #[tracing::instrument(skip_all)]
-    pub async fn get_quote(&self, input: GetQuoteInput) -> Result<entities::Quote, RestError> {
+    pub async fn create_quote_request(
+        &self,
+        input: GetQuoteInput,
+    ) -> Result<entities::QuoteRequest, RestError> {
         let config = self.get_config(&input.quote_create.chain_id)?;
         let auction_service = config.get_auction_service().await;
 
-        // TODO Check for the input amount
         tracing::info!(quote_create = ?input.quote_create, "Received request to get quote");
-        let output_amount = self
+        let pending_accounts = auction_service.get_pending_accounts().await?;
+        self.validate_quote(
+            &auction_service,
+            pending_accounts.clone(),
+            &input.quote_create,
+        )
+        .await?;
+        let price_estimate = self
             .estimate_price(EstimatePriceInput {
                 quote_create: input.quote_create.clone(),
             })
             .await?;
+        if let entities::QuoteTokens::OutputTokenSpecified { .. } = input.quote_create.tokens {
+            // The input amount is only known after the estimation for the exact output quotes
+            self.validate_input_balance(
+                &auction_service,
+                pending_accounts,
+                input.quote_create.user_wallet_address,
+                &price_estimate.input_token,
+            )
+            .await?;
+        }
 
         let opportunity_create = self
-            .get_opportunity_create_for_quote(input.quote_create.clone(), output_amount)
+            .get_opportunity_create_for_quote(
+                input.quote_create.clone(),
+                input.router,
+                &price_estimate,
+            )
             .await?;
         let opportunity = self
             .add_opportunity(AddOpportunityInput {
@@ -110,23 +287,41 @@ impl Service<ChainTypeSvm> {
             })
             .await?;
 
-        // NOTE: This part will be removed after refactoring the permission key type
-        let slice: [u8; 64] = opportunity
-            .permission_key
-            .to_vec()
-            .try_into()
-            .expect("Failed to convert permission key to slice");
-        let permission_key_svm = PermissionKeySvm(slice);
-        // Wait to make sure searchers had enough time to submit bids
-        sleep(BID_COLLECTION_TIME).await;
+        Ok(entities::QuoteRequest {
+            quote_create: input.quote_create,
+            price_estimate,
+            opportunity,
+        })
+    }
 
-        let bid_collection_time = OffsetDateTime::now_utc();
-        let mut bids = auction_service
+    /// Get the live bids on the quote opportunity, sorted from the best to the worst.
+    pub async fn get_quote_bids(
+        &self,
+        quote_request: &entities::QuoteRequest,
+    ) -> Result<Vec<Bid<Svm>>, RestError> {
+        let config = self.get_config(&quote_request.quote_create.chain_id)?;
+        let mut bids = config
+            .get_auction_service()
+            .await
             .get_live_bids(GetLiveBidsInput {
-                permission_key: permission_key_svm,
+                permission_key: quote_request.get_permission_key(),
             })
             .await;
+        let tokens = &quote_request.quote_create.tokens;
+        bids.sort_by(|a, b| tokens.compare_bid_amounts(a.amount, b.amount));
+        Ok(bids)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn get_quote(&self, input: GetQuoteInput) -> Result<entities::Quote, RestError> {
+        let chain_id = input.quote_create.chain_id.clone();
+        let integrator_name = input.integrator_name.clone();
+        let quote_request = self.create_quote_request(input).await?;
+
+        // Wait to make sure searchers had enough time to submit bids
+        sleep(BID_COLLECTION_TIME).await;
 
+        let bids = self.get_quote_bids(&quote_request).await?;
         let total_bids = if bids.len() < 10 {
             bids.len().to_string()
         } else {
@@ -134,105 +329,29 @@ impl Service<ChainTypeSvm> {
         };
         // Add metrics
         let labels = [
-            ("chain_id", input.quote_create.chain_id.to_string()),
-            ("wallet", "phantom".to_string()),
+            ("chain_id", chain_id.to_string()),
+            ("wallet", integrator_name),
             ("total_bids", total_bids),
         ];
         metrics::counter!("get_quote_total_bids", &labels).increment(1);
 
-        if bids.is_empty() {
-            tracing::warn!(opportunity = ?opportunity, "No bids found for quote opportunity");
-
-            return Err(RestError::QuoteNotFound);
-        }
-
-        // Find winner bid: the bid with the highest bid amount
-        bids.sort_by(|a, b| b.amount.cmp(&a.amount));
-        let winner_bid = bids.first().expect("failed to get first bid");
-
-        // Find the submit bid instruction from bid transaction to extract the deadline
-        let submit_bid_instruction = auction_service
-            .verify_submit_bid_instruction(winner_bid.chain_data.transaction.clone())
-            .map_err(|e| {
-                tracing::error!("Failed to verify submit bid instruction: {:?}", e);
-                RestError::TemporarilyUnavailable
-            })?;
-        let submit_bid_data = AuctionService::<Svm>::extract_submit_bid_data(
-            &submit_bid_instruction,
-        )
-        .map_err(|e| {
-            tracing::error!("Failed to extract submit bid data: {:?}", e);
-            RestError::TemporarilyUnavailable
-        })?;
-
-        // Bids is not empty
-        let auction = Auction::try_new(bids.clone(), bid_collection_time)
-            .expect("Failed to create auction for bids");
-
-        let mut auction = auction_service
-            .add_auction(AddAuctionInput { auction })
-            .await?;
-
-        let mut bid = winner_bid.clone();
-        auction_service.add_relayer_signature(&mut bid);
-
-        let signature = bid.chain_data.transaction.signatures[0];
-        auction = auction_service
-            .update_submitted_auction(UpdateSubmittedAuctionInput {
-                auction,
-                transaction_hash: signature,
-            })
-            .await?;
-
-        self.task_tracker.spawn({
-            let (repo, db, winner_bid) = (self.repo.clone(), self.db.clone(), winner_bid.clone());
-            let auction_service = auction_service.clone();
-            async move {
-                join_all(auction.bids.iter().map(|bid| {
-                    auction_service.update_bid_status(UpdateBidStatusInput {
-                        new_status: AuctionService::get_new_status(
-                            bid,
-                            &vec![winner_bid.clone()],
-                            BidStatusAuction {
-                                tx_hash: signature,
-                                id:      auction.id,
-                            },
-                        ),
-                        bid:        bid.clone(),
-                    })
-                }))
-                .await;
-                // Remove opportunity to prevent further bids
-                // The handle auction loop will take care of the bids that were submitted late
-
-                // TODO
-                // Maybe we should add state for opportunity.
-                // Right now logic for removing halted/expired bids, checks if opportunity exists.
-                // We should remove opportunity only after the auction bid result is broadcasted.
-                // This is to make sure we are not gonna remove the bids that are currently in the auction in the handle_auction loop.
-                let removal_reason =
-                    entities::OpportunityRemovalReason::Invalid(RestError::InvalidOpportunity(
-                        "Auction finished for the opportunity".to_string(),
-                    ));
-                if let Err(e) = repo
-                    .remove_opportunity(&db, &opportunity, removal_reason)
-                    .await
-                {
-                    tracing::error!("Failed to remove opportunity: {:?}", e);
+        // Find winner bid: the bid with the best amount for the user
+        let winner_bid = match bids.first() {
+            Some(bid) => bid,
+            None => {
+                tracing::warn!(opportunity = ?quote_request.opportunity, "No bids found for quote opportunity");
+                // The searchers should stop bidding on the quote which is not returned to the user
+                if let Err(e) = self.cancel_quote(CancelQuoteInput { quote_request }).await {
+                    tracing::error!(error = ?e, "Failed to cancel quote without bids");
                 }
+                return Err(RestError::QuoteNotFound);
             }
-        });
-
-        Ok(entities::Quote {
-            transaction:                 bid.chain_data.transaction.clone(),
-            expiration_time:             submit_bid_data.deadline,
-            input_token:                 input.quote_create.input_token,
-            output_token:                entities::TokenAmountSvm {
-                token:  input.quote_create.output_mint_token,
-                amount: output_amount,
-            },
-            maximum_slippage_percentage: input.quote_create.maximum_slippage_percentage,
-            chain_id:                    input.quote_create.chain_id,
+        };
+
+        self.accept_quote(AcceptQuoteInput {
+            bid_id: winner_bid.id,
+            quote_request,
         })
+        .await
     }
 }
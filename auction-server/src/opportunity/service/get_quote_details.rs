@@ -0,0 +1,23 @@
+use {
+    super::{
+        ChainTypeSvm,
+        Service,
+    },
+    crate::{
+        api::RestError,
+        opportunity::entities,
+    },
+};
+
+pub struct GetQuoteDetailsInput {
+    pub quote_id: entities::QuoteId,
+}
+
+impl Service<ChainTypeSvm> {
+    pub async fn get_quote_details(
+        &self,
+        input: GetQuoteDetailsInput,
+    ) -> Result<entities::QuoteDetails, RestError> {
+        self.repo.get_quote(&self.db, input.quote_id).await
+    }
+}
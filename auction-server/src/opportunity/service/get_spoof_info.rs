@@ -8,13 +8,24 @@ use {
         kernel::entities::ChainId,
         opportunity::{
             entities,
-            token_spoof::find_spoof_info,
+            token_spoof::{
+                find_spoof_info,
+                get_implementation,
+            },
         },
     },
     ethers::types::Address,
     std::sync::Arc,
+    time::{
+        Duration,
+        OffsetDateTime,
+    },
 };
 
+/// The implementation of a proxy token is checked again after this duration,
+/// so the slots are not checked on chain for every verification of the opportunities.
+const IMPLEMENTATION_CHECK_INTERVAL: Duration = Duration::minutes(5);
+
 pub struct GetSpoofInfoInput {
     pub chain_id: ChainId,
     pub token:    Address,
@@ -22,29 +33,76 @@ pub struct GetSpoofInfoInput {
 
 impl Service<ChainTypeEvm> {
     /// Find the spoof info for an ERC20 token. This includes the balance slot and the allowance slot.
-    /// Returns an error if no balance or allowance slot is found.
+    /// The slots are looked up in memory, then in the database and are only searched on chain if not found.
+    /// Slots found on chain for a proxy token are searched again when its implementation changes,
+    /// which is checked at most once per `IMPLEMENTATION_CHECK_INTERVAL`.
     #[tracing::instrument(skip_all, fields(token=%input.token))]
     pub(super) async fn get_spoof_info(
         &self,
         input: GetSpoofInfoInput,
     ) -> Result<entities::SpoofInfo, RestError> {
         let config = self.get_config(&input.chain_id)?;
-        match self.repo.get_spoof_info(input.token).await {
-            Some(info) => Ok(info),
+        let provider = Arc::new(config.provider.clone());
+        let cached = match self
+            .repo
+            .get_spoof_info(input.chain_id.clone(), input.token)
+            .await
+        {
+            Some(cached) => {
+                if cached.spoof_info.implementation.is_none()
+                    || cached.check_time + IMPLEMENTATION_CHECK_INTERVAL > OffsetDateTime::now_utc()
+                {
+                    return Ok(cached.spoof_info);
+                }
+                Some(cached.spoof_info)
+            }
             None => {
-                let result = find_spoof_info(input.token, Arc::new(config.provider.clone()))
-                    .await
-                    .unwrap_or_else(|e| {
-                        tracing::error!("Error finding spoof info: {:?}", e);
-                        entities::SpoofInfo {
-                            token: input.token,
-                            state: entities::SpoofState::UnableToSpoof,
-                        }
-                    });
+                self.repo
+                    .load_spoof_info(&self.db, &input.chain_id, input.token)
+                    .await?
+            }
+        };
 
-                self.repo.add_spoof_info(result.clone()).await;
-                Ok(result)
+        if let Some(info) = cached {
+            let implementation = match info.implementation {
+                Some(_) => get_implementation(input.token, provider.clone())
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Error getting token implementation: {:?}", e);
+                        RestError::TemporarilyUnavailable
+                    })?,
+                None => None,
+            };
+            if implementation == info.implementation {
+                self.repo
+                    .add_spoof_info(input.chain_id.clone(), info.clone())
+                    .await;
+                return Ok(info);
             }
+            tracing::info!(
+                token = ?input.token,
+                implementation = ?implementation,
+                "Token implementation changed, searching the spoof slots again"
+            );
         }
+
+        let result = find_spoof_info(input.token, provider, config.spoof_slot_search_limit)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!("Error finding spoof info: {:?}", e);
+                entities::SpoofInfo {
+                    token:          input.token,
+                    state:          entities::SpoofState::UnableToSpoof,
+                    implementation: None,
+                }
+            });
+
+        self.repo
+            .save_spoof_info(&self.db, &input.chain_id, &result)
+            .await?;
+        self.repo
+            .add_spoof_info(input.chain_id, result.clone())
+            .await;
+        Ok(result)
     }
 }
@@ -1,13 +1,22 @@
 use {
-    super::repository::{
-        InMemoryStore,
-        InMemoryStoreEvm,
-        InMemoryStoreSvm,
-        Repository,
+    super::{
+        price_source::{
+            get_price_source,
+            PriceSource,
+        },
+        repository::{
+            InMemoryStore,
+            InMemoryStoreEvm,
+            InMemoryStoreSvm,
+            Repository,
+        },
     },
     crate::{
-        auction::service::{
-            self as auction_service,
+        auction::{
+            entities as auction_entities,
+            service::{
+                self as auction_service,
+            },
         },
         kernel::{
             contracts::AdapterFactory,
@@ -30,7 +39,10 @@ use {
         providers::Provider,
         types::Address,
     },
-    futures::future::try_join_all,
+    futures::{
+        future::try_join_all,
+        Future,
+    },
     solana_sdk::pubkey::Pubkey,
     std::{
         collections::HashMap,
@@ -40,14 +52,21 @@ use {
     tokio_util::task::TaskTracker,
 };
 
+pub mod accept_quote;
 pub mod add_opportunity;
+pub mod add_spoof_override;
+pub mod cancel_quote;
 pub mod get_config;
+pub mod get_integrator;
 pub mod get_live_opportunities;
 pub mod get_opportunities;
 pub mod get_quote;
+pub mod get_quote_details;
 pub mod handle_opportunity_bid;
 pub mod remove_invalid_or_expired_opportunities;
 pub mod remove_opportunities;
+pub mod update_opportunities_state;
+pub mod update_quote_status;
 pub mod verification;
 
 mod estimate_price;
@@ -64,6 +83,7 @@ pub struct ConfigEvm {
     pub permit2:                  Address,
     pub provider:                 Provider<TracedClient>,
     pub weth:                     Address,
+    pub spoof_slot_search_limit:  u64,
     pub auction_service:          RwLock<Option<auction_service::Service<Evm>>>,
 }
 
@@ -84,8 +104,8 @@ impl ConfigEvm {
 
 // NOTE: Do not implement debug here. it has a circular reference to auction_service
 pub struct ConfigSvm {
-    pub wallet_program_router_account: Pubkey,
-    pub auction_service:               RwLock<Option<auction_service::Service<Svm>>>,
+    pub auction_service: RwLock<Option<auction_service::Service<Svm>>>,
+    pub price_source:    Option<Box<dyn PriceSource>>,
 }
 
 impl ConfigSvm {
@@ -103,12 +123,19 @@ impl ConfigSvm {
     }
 }
 
-#[allow(dead_code)]
-pub trait Config: Send + Sync {}
+pub trait Config: Send + Sync {
+    fn get_chain_status(&self) -> impl Future<Output = auction_entities::ChainStatus>;
+}
 
 impl Config for ConfigEvm {
+    async fn get_chain_status(&self) -> auction_entities::ChainStatus {
+        self.get_auction_service().await.get_chain_status().await
+    }
 }
 impl Config for ConfigSvm {
+    async fn get_chain_status(&self) -> auction_entities::ChainStatus {
+        self.get_auction_service().await.get_chain_status().await
+    }
 }
 
 impl ConfigEvm {
@@ -152,6 +179,7 @@ impl ConfigEvm {
         adapter_factory_contract: Address,
         provider: Provider<TracedClient>,
         chain_id_num: u64,
+        spoof_slot_search_limit: u64,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             adapter_bytecode_hash: Self::get_adapter_bytecode_hash(
@@ -164,6 +192,7 @@ impl ConfigEvm {
             adapter_factory_contract,
             chain_id_num,
             provider,
+            spoof_slot_search_limit,
             auction_service: RwLock::new(None),
         })
     }
@@ -180,6 +209,7 @@ impl ConfigEvm {
                     adapter_factory_contract,
                     provider_cloned.clone(),
                     chain_store.network_id,
+                    chain_store.config.spoof_slot_search_limit,
                 )
                 .await?;
                 Ok::<(ChainId, Self), anyhow::Error>((chain_id_cloned, config))
@@ -196,18 +226,23 @@ impl ConfigSvm {
     pub async fn from_chains(
         chains: &HashMap<ChainId, ChainStoreSvm>,
     ) -> anyhow::Result<HashMap<ChainId, Self>> {
-        Ok(chains
+        chains
             .iter()
             .map(|(chain_id, config)| {
-                (
+                Ok((
                     chain_id.clone(),
                     Self {
-                        wallet_program_router_account: config.wallet_program_router_account,
-                        auction_service:               RwLock::new(None),
+                        auction_service: RwLock::new(None),
+                        price_source:    config
+                            .config
+                            .price_source
+                            .as_ref()
+                            .map(get_price_source)
+                            .transpose()?,
                     },
-                )
+                ))
             })
-            .collect())
+            .collect()
     }
 }
 
@@ -5,6 +5,7 @@ use {
             VerifyOpportunityInput,
         },
         ChainType,
+        ChainTypeEnum,
         Service,
     },
     crate::{
@@ -12,12 +13,9 @@ use {
             ws::UpdateEvent,
             RestError,
         },
-        opportunity::{
-            entities::{
-                self,
-                Opportunity as _,
-            },
-            service::ChainTypeEnum,
+        opportunity::entities::{
+            self,
+            Opportunity as _,
         },
     },
     time::{
@@ -26,6 +24,8 @@ use {
     },
 };
 
+/// Opportunities without an expiration time are removed if they are not refreshed within this duration,
+/// unless they are verified to be valid on an evm chain.
 const MAX_STALE_OPPORTUNITY_DURATION: Duration = Duration::minutes(2);
 
 impl<T: ChainType> Service<T>
@@ -34,76 +34,105 @@ where
 {
     pub async fn remove_invalid_or_expired_opportunities(&self) {
         let all_opportunities = self.repo.get_in_memory_opportunities().await;
-        for (_, opportunities) in all_opportunities.iter() {
-            // check each of the opportunities for this permission key for validity
-            for opportunity in opportunities.iter() {
-                let reason = match self
-                    .verify_opportunity(VerifyOpportunityInput {
-                        opportunity: opportunity.clone().into(),
+        // The bids of an opportunity in an auction are still valid until the auction is concluded,
+        // so the opportunity is verified again after its auction
+        let (expired_opportunities, live_opportunities): (Vec<_>, Vec<_>) = all_opportunities
+            .values()
+            .flatten()
+            .filter(|opportunity| opportunity.state != entities::OpportunityState::InAuction)
+            .partition(|opportunity| opportunity.is_expired());
+        let verification_results = self
+            .verify_opportunities(
+                live_opportunities
+                    .iter()
+                    .map(|opportunity| VerifyOpportunityInput {
+                        opportunity: (*opportunity).clone().into(),
                     })
-                    .await
-                {
-                    Ok(entities::OpportunityVerificationResult::UnableToSpoof) => {
-                        if OffsetDateTime::now_utc() - opportunity.refresh_time
-                            > MAX_STALE_OPPORTUNITY_DURATION
-                        {
-                            Some(entities::OpportunityRemovalReason::Expired)
-                        } else {
-                            None
-                        }
-                    }
-                    Err(e) => {
-                        if let RestError::InvalidOpportunity(_) = e {
-                            Some(entities::OpportunityRemovalReason::Invalid(e))
-                        } else {
-                            None
-                        }
-                    }
-                    _ => None,
-                };
+                    .collect(),
+            )
+            .await;
 
-                if let Some(reason) = reason {
-                    tracing::info!(
-                        opportunity = ?opportunity,
-                        reason = ?reason,
-                        "Removing Opportunity",
-                    );
-                    match self
-                        .repo
-                        .remove_opportunity(&self.db, opportunity, reason)
-                        .await
-                    {
-                        Ok(()) => {
-                            // TODO Remove this later
-                            // For now we don't want searchers to update any of their code on EVM chains.
-                            // So we are not broadcasting remove opportunities event for EVM chains.
-                            if T::get_type() == ChainTypeEnum::Evm {
-                                continue;
+        let now = OffsetDateTime::now_utc();
+        let reasons = expired_opportunities
+            .into_iter()
+            .map(|opportunity| {
+                (
+                    opportunity,
+                    Some(entities::OpportunityRemovalReason::Expired),
+                )
+            })
+            .chain(
+                live_opportunities
+                    .into_iter()
+                    .zip(verification_results)
+                    .map(|(opportunity, result)| {
+                        // Opportunities with an expiration time set by the protocol are kept until they expire
+                        let is_stale = opportunity.expiration_time.is_none()
+                            && now - opportunity.refresh_time > MAX_STALE_OPPORTUNITY_DURATION;
+                        let reason = match result {
+                            Ok(entities::OpportunityVerificationResult::UnableToSpoof) => {
+                                is_stale.then_some(entities::OpportunityRemovalReason::Expired)
+                            }
+                            // The svm opportunities are only checked against the accounts they depend on,
+                            // so they are not kept forever without being refreshed
+                            Ok(entities::OpportunityVerificationResult::Success) => (is_stale
+                                && matches!(T::get_type(), ChainTypeEnum::Svm))
+                            .then_some(entities::OpportunityRemovalReason::Expired),
+                            Err(e) => {
+                                if let RestError::InvalidOpportunity(_) = e {
+                                    Some(entities::OpportunityRemovalReason::Invalid(e))
+                                } else {
+                                    None
+                                }
                             }
+                        };
+                        (opportunity, reason)
+                    }),
+            );
 
-                            // If there are no more opportunities with this key, it means all of the
-                            // opportunities have been removed for this key, so we can broadcast remove opportunities event.
-                            if self
-                                .repo
-                                .get_in_memory_opportunities_by_key(&opportunity.get_key())
-                                .await
-                                .is_empty()
+        for (opportunity, reason) in reasons {
+            if let Some(reason) = reason {
+                tracing::info!(
+                    opportunity = ?opportunity,
+                    reason = ?reason,
+                    "Removing Opportunity",
+                );
+                let opportunity_delete = opportunity.get_opportunity_delete((&reason).into());
+                match self
+                    .repo
+                    .remove_opportunity(&self.db, opportunity, reason)
+                    .await
+                {
+                    Ok(false) => {
+                        tracing::info!(
+                            opportunity_id = %opportunity.id,
+                            "Opportunity entered an auction before its removal",
+                        );
+                    }
+                    Ok(true) => {
+                        // If there are no more opportunities with this key, it means all of the
+                        // opportunities have been removed for this key, so we can broadcast remove opportunities event.
+                        if self
+                            .repo
+                            .get_in_memory_opportunities_by_key(&opportunity.get_key())
+                            .await
+                            .is_empty()
+                        {
+                            if let Err(e) = self
+                                .store
+                                .ws
+                                .broadcast_sender
+                                .send(UpdateEvent::RemoveOpportunities(opportunity_delete))
                             {
-                                if let Err(e) = self.store.ws.broadcast_sender.send(
-                                    UpdateEvent::RemoveOpportunities(
-                                        opportunity.get_opportunity_delete(),
-                                    ),
-                                ) {
-                                    tracing::error!(
-                                        error = e.to_string(),
-                                        "Failed to broadcast remove opportunity"
-                                    );
-                                }
+                                tracing::error!(
+                                    error = e.to_string(),
+                                    "Failed to broadcast remove opportunity"
+                                );
                             }
                         }
-                        Err(e) => {
-                            tracing::error!(error = ?e, "Failed to remove opportunity");
-                        }
+                    }
+                    Err(e) => {
+                        tracing::error!(error = ?e, "Failed to remove opportunity");
                     }
                 }
             }
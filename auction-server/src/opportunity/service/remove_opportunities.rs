@@ -1,6 +1,6 @@
 use {
     super::{
-        ChainTypeSvm,
+        ChainType,
         Service,
     },
     crate::{
@@ -8,8 +8,8 @@ use {
             ws::UpdateEvent,
             RestError,
         },
-        kernel::entities::ChainId,
         opportunity::{
+            api,
             entities::{
                 self,
                 Opportunity as _,
@@ -19,50 +19,46 @@ use {
             },
         },
     },
-    solana_sdk::pubkey::Pubkey,
 };
 
 pub struct RemoveOpportunitiesInput {
-    pub chain_id:           ChainId,
-    pub permission_account: Pubkey,
-    pub router:             Pubkey,
+    pub key: entities::OpportunityKey,
 }
 
-impl Service<ChainTypeSvm> {
+impl<T: ChainType> Service<T> {
     pub async fn remove_opportunities(
         &self,
         input: RemoveOpportunitiesInput,
     ) -> Result<(), RestError> {
-        self.get_config(&input.chain_id)?;
-        let permission_key =
-            entities::OpportunitySvm::get_permission_key(input.router, input.permission_account);
+        let entities::OpportunityKey(chain_id, permission_key) = input.key.clone();
+        self.get_config(&chain_id)?;
         let opportunities = self
             .repo
             .remove_opportunities(
                 &self.db,
                 permission_key.clone(),
-                input.chain_id.clone(),
-                &entities::OpportunityKey(input.chain_id.clone(), permission_key),
+                chain_id.clone(),
+                &input.key,
                 repository::OpportunityRemovalReason::Invalid,
             )
             .await
             .map_err(|e| {
                 tracing::error!(
                     error = ?e,
-                    chain_id = input.chain_id,
-                    permission_key =
+                    chain_id = chain_id,
+                    permission_key = permission_key.to_string(),
                     "Failed to remove opportunities",
                 );
                 RestError::TemporarilyUnavailable
             })?;
 
-        if !opportunities.is_empty() {
-            let opportunity = opportunities[0].clone();
+        // The opportunities in an auction are not removed, so nothing is broadcast if all of them are in an auction
+        if let Some(opportunity) = opportunities.first().cloned() {
             self.store
                 .ws
                 .broadcast_sender
                 .send(UpdateEvent::RemoveOpportunities(
-                    opportunity.get_opportunity_delete(),
+                    opportunity.get_opportunity_delete(api::OpportunityRemovalReason::Invalid),
                 ))
                 .map_err(|e| {
                     tracing::error!(
@@ -0,0 +1,29 @@
+use {
+    super::{
+        ChainType,
+        Service,
+    },
+    crate::opportunity::{
+        entities,
+        repository::InMemoryStore,
+    },
+};
+
+pub struct UpdateOpportunitiesStateInput {
+    pub key:   entities::OpportunityKey,
+    pub state: entities::OpportunityState,
+}
+
+impl<T: ChainType> Service<T> {
+    /// Moves the opportunities of the key to the new state and returns the updated opportunities.
+    /// The opportunities which can not move to the new state from their current state are left untouched.
+    #[tracing::instrument(skip_all, fields(key = ?input.key, state = ?input.state))]
+    pub async fn update_opportunities_state(
+        &self,
+        input: UpdateOpportunitiesStateInput,
+    ) -> anyhow::Result<Vec<<T::InMemoryStore as InMemoryStore>::Opportunity>> {
+        self.repo
+            .update_opportunities_state(&self.db, &input.key, input.state)
+            .await
+    }
+}
@@ -0,0 +1,40 @@
+use {
+    super::{
+        ChainTypeSvm,
+        Service,
+    },
+    crate::{
+        auction::entities::BidId,
+        kernel::entities::ChainId,
+        opportunity::entities,
+    },
+    axum_prometheus::metrics,
+};
+
+pub struct UpdateQuoteStatusInput {
+    pub chain_id: ChainId,
+    pub bid_id:   BidId,
+    pub status:   entities::QuoteStatus,
+}
+
+impl Service<ChainTypeSvm> {
+    /// Updates the status of the quote issued for the bid, if there is any.
+    #[tracing::instrument(skip_all, fields(bid_id = %input.bid_id, status = ?input.status))]
+    pub async fn update_quote_status(&self, input: UpdateQuoteStatusInput) -> anyhow::Result<()> {
+        let is_updated = self
+            .repo
+            .update_quote_status(&self.db, input.bid_id, input.status)
+            .await?;
+        if is_updated {
+            metrics::counter!(
+                "quote_status_updates_total",
+                &[
+                    ("chain_id", input.chain_id),
+                    ("status", format!("{:?}", input.status).to_lowercase()),
+                ]
+            )
+            .increment(1);
+        }
+        Ok(())
+    }
+}
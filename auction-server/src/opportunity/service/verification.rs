@@ -10,10 +10,21 @@ use {
     },
     crate::{
         api::RestError,
-        kernel::contracts::{
-            ExecutionParams,
-            MulticallData,
-            MulticallReturn,
+        auction::service::{
+            self as auction_service,
+            get_accounts::GetAccountsInput,
+            simulator::PendingAccounts,
+        },
+        kernel::{
+            contracts::{
+                ExecutionParams,
+                MulticallData,
+                MulticallReturn,
+            },
+            entities::{
+                ChainId,
+                Svm,
+            },
         },
         opportunity::{
             api::OpportunityBidEvm,
@@ -43,6 +54,13 @@ use {
         utils::get_create2_address_from_hash,
     },
     rand::Rng,
+    solana_sdk::{
+        account::Account,
+        pubkey::{
+            self,
+            Pubkey,
+        },
+    },
     std::{
         collections::HashMap,
         future::Future,
@@ -56,11 +74,28 @@ pub struct VerifyOpportunityInput<T: entities::OpportunityCreate> {
     pub opportunity: T,
 }
 
+type OpportunityCreateType<T> = <<<T as ChainType>::InMemoryStore as InMemoryStore>::Opportunity as entities::Opportunity>::OpportunityCreate;
+
 pub trait Verification<T: ChainType> {
     fn verify_opportunity(
         &self,
-        input: VerifyOpportunityInput<<<T::InMemoryStore as InMemoryStore>::Opportunity as entities::Opportunity>::OpportunityCreate>,
+        input: VerifyOpportunityInput<OpportunityCreateType<T>>,
     ) -> impl Future<Output = Result<entities::OpportunityVerificationResult, RestError>>;
+
+    /// Verifies the opportunities of a single verification round.
+    /// The chain state needed for the verification can be loaded once and shared between the opportunities.
+    fn verify_opportunities(
+        &self,
+        inputs: Vec<VerifyOpportunityInput<OpportunityCreateType<T>>>,
+    ) -> impl Future<Output = Vec<Result<entities::OpportunityVerificationResult, RestError>>> {
+        async move {
+            let mut results = vec![];
+            for input in inputs {
+                results.push(self.verify_opportunity(input).await);
+            }
+            results
+        }
+    }
 }
 
 fn generate_random_u256() -> U256 {
@@ -224,10 +259,12 @@ impl Verification<ChainTypeEvm> for Service<ChainTypeEvm> {
                 entities::SpoofState::Spoofed {
                     balance_slot,
                     allowance_slot,
+                    layout,
                 } => {
                     let balance_storage_key = token_spoof::calculate_balance_storage_key(
                         fake_wallet.address(),
                         balance_slot,
+                        layout,
                     );
                     let value: [u8; 32] = amount.into();
                     state
@@ -238,6 +275,7 @@ impl Verification<ChainTypeEvm> for Service<ChainTypeEvm> {
                         fake_wallet.address(),
                         config.permit2,
                         allowance_slot,
+                        layout,
                     );
                     let value: [u8; 32] = amount.into();
                     state
@@ -275,15 +313,274 @@ impl Verification<ChainTypeEvm> for Service<ChainTypeEvm> {
     }
 }
 
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+/// Offset of the amount in the spl token account data, after the mint and the owner.
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+fn get_token_account_amount(account: &Option<Account>, token_program: &Pubkey) -> u64 {
+    account
+        .as_ref()
+        .filter(|account| account.owner == *token_program)
+        .and_then(|account| {
+            account
+                .data
+                .get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+        })
+        .map(|amount| u64::from_le_bytes(amount.try_into().expect("slice length is 8")))
+        .unwrap_or_default()
+}
+
+/// Returns the balance of the wallet for each of the mints, or None if the mint does not exist.
+/// The native sol of the wallet is counted for the native mint, as it is wrapped by the swap transaction.
+pub(super) async fn get_wallet_balances(
+    auction_service: &auction_service::Service<Svm>,
+    pending_accounts: Arc<PendingAccounts>,
+    wallet: Pubkey,
+    mints: &[Pubkey],
+) -> Result<Vec<Option<u64>>, RestError> {
+    let mut keys = vec![wallet];
+    keys.extend(mints.iter().cloned());
+    let accounts = auction_service
+        .get_accounts(GetAccountsInput {
+            keys,
+            pending_accounts: Some(pending_accounts.clone()),
+        })
+        .await?;
+    let wallet_lamports = accounts[0]
+        .as_ref()
+        .map(|account| account.lamports)
+        .unwrap_or_default();
+
+    // The token program of the mint is the owner of the mint account
+    let token_programs: Vec<Option<Pubkey>> = accounts[1..]
+        .iter()
+        .map(|mint_account| mint_account.as_ref().map(|account| account.owner))
+        .collect();
+    let token_account_keys = mints
+        .iter()
+        .zip(token_programs.iter())
+        .filter_map(|(mint, token_program)| {
+            token_program
+                .as_ref()
+                .map(|token_program| get_associated_token_address(&wallet, mint, token_program))
+        })
+        .collect();
+    let mut token_accounts = auction_service
+        .get_accounts(GetAccountsInput {
+            keys:             token_account_keys,
+            pending_accounts: Some(pending_accounts),
+        })
+        .await?
+        .into_iter();
+
+    Ok(mints
+        .iter()
+        .zip(token_programs)
+        .map(|(mint, token_program)| {
+            let token_program = token_program?;
+            let token_account = token_accounts.next().flatten();
+            let mut balance = get_token_account_amount(&token_account, &token_program);
+            if *mint == NATIVE_MINT {
+                balance = balance.saturating_add(wallet_lamports);
+            }
+            Some(balance)
+        })
+        .collect())
+}
+
+/// The posted tokens and accounts should be the ones of the decoded order.
+fn validate_limo_order(
+    opportunity: &entities::OpportunityCreateSvm,
+    program: &entities::OpportunitySvmProgramLimo,
+) -> Result<(), RestError> {
+    let order = program.get_order().map_err(|e| {
+        RestError::InvalidOpportunity(format!("Failed to decode the limo order: {}", e))
+    })?;
+    if order.status != entities::LimoOrderStatus::Active || order.remaining_input_amount == 0 {
+        return Err(RestError::InvalidOpportunity(
+            "Limo order is not active".to_string(),
+        ));
+    }
+    if opportunity.permission_account != program.order_address {
+        return Err(RestError::InvalidOpportunity(
+            "Permission account should be the limo order address".to_string(),
+        ));
+    }
+    if opportunity.router != order.get_router() {
+        return Err(RestError::InvalidOpportunity(
+            "Router should be the authority of the limo order global config".to_string(),
+        ));
+    }
+
+    let expected_buy_token = entities::TokenAmountSvm {
+        token:  order.input_mint,
+        amount: order.remaining_input_amount,
+    };
+    if opportunity.core_fields.buy_tokens != vec![expected_buy_token.clone()] {
+        return Err(RestError::InvalidOpportunity(format!(
+            "Buy tokens should only contain the remaining input of the limo order: {:?}",
+            expected_buy_token
+        )));
+    }
+    let expected_sell_token = entities::TokenAmountSvm {
+        token:  order.output_mint,
+        amount: order.get_remaining_output_amount(),
+    };
+    if opportunity.core_fields.sell_tokens != vec![expected_sell_token.clone()] {
+        return Err(RestError::InvalidOpportunity(format!(
+            "Sell tokens should only contain the remaining output of the limo order: {:?}",
+            expected_sell_token
+        )));
+    }
+    Ok(())
+}
+
+impl Service<ChainTypeSvm> {
+    /// The order account should still exist and be the same as the posted order.
+    /// Filled or cancelled orders are either closed or have a different content.
+    async fn verify_limo_opportunity(
+        &self,
+        auction_service: &auction_service::Service<Svm>,
+        pending_accounts: Arc<PendingAccounts>,
+        opportunity: &entities::OpportunityCreateSvm,
+        program: &entities::OpportunitySvmProgramLimo,
+    ) -> Result<entities::OpportunityVerificationResult, RestError> {
+        validate_limo_order(opportunity, program)?;
+        let accounts = auction_service
+            .get_accounts(GetAccountsInput {
+                keys:             vec![program.order_address],
+                pending_accounts: Some(pending_accounts),
+            })
+            .await?;
+        match accounts.first().cloned().flatten() {
+            None => Err(RestError::InvalidOpportunity(
+                "Limo order account does not exist".to_string(),
+            )),
+            Some(account) if account.owner != entities::LIMO_PROGRAM_ID => {
+                Err(RestError::InvalidOpportunity(
+                    "Limo order account is not owned by the limo program".to_string(),
+                ))
+            }
+            Some(account) if account.data != program.order => Err(RestError::InvalidOpportunity(
+                "Limo order account does not match the opportunity order".to_string(),
+            )),
+            Some(_) => Ok(entities::OpportunityVerificationResult::Success),
+        }
+    }
+
+    /// The user wallet should hold enough of the sell tokens.
+    async fn verify_phantom_opportunity(
+        &self,
+        auction_service: &auction_service::Service<Svm>,
+        pending_accounts: Arc<PendingAccounts>,
+        sell_tokens: &[entities::TokenAmountSvm],
+        program: &entities::OpportunitySvmProgramWallet,
+    ) -> Result<entities::OpportunityVerificationResult, RestError> {
+        let mut required_amounts = HashMap::<Pubkey, u64>::new();
+        for token_amount in sell_tokens {
+            let amount = required_amounts.entry(token_amount.token).or_default();
+            *amount = amount.saturating_add(token_amount.amount);
+        }
+        let mints: Vec<Pubkey> = required_amounts.keys().cloned().collect();
+        let balances = get_wallet_balances(
+            auction_service,
+            pending_accounts,
+            program.user_wallet_address,
+            &mints,
+        )
+        .await?;
+
+        for (mint, balance) in mints.iter().zip(balances) {
+            let balance = balance.ok_or_else(|| {
+                RestError::InvalidOpportunity(format!("Sell token mint {} does not exist", mint))
+            })?;
+            let required_amount = required_amounts[mint];
+            if balance < required_amount {
+                return Err(RestError::InvalidOpportunity(format!(
+                    "User wallet balance of token {} is {}, which is less than the sell amount {}",
+                    mint, balance, required_amount
+                )));
+            }
+        }
+        Ok(entities::OpportunityVerificationResult::Success)
+    }
+}
+
+impl Service<ChainTypeSvm> {
+    /// Verifies the opportunity against the pending accounts of its chain.
+    /// The pending accounts are loaded for the chain if they are not loaded yet.
+    async fn verify_opportunity_with_pending_accounts(
+        &self,
+        input: VerifyOpportunityInput<entities::OpportunityCreateSvm>,
+        pending_accounts_by_chain: &mut HashMap<ChainId, Arc<PendingAccounts>>,
+    ) -> Result<entities::OpportunityVerificationResult, RestError> {
+        let chain_id = &input.opportunity.core_fields.chain_id;
+        let config = self.get_config(chain_id)?;
+        let auction_service = config.get_auction_service().await;
+        let pending_accounts = match pending_accounts_by_chain.get(chain_id) {
+            Some(pending_accounts) => pending_accounts.clone(),
+            None => {
+                let pending_accounts = auction_service.get_pending_accounts().await?;
+                pending_accounts_by_chain.insert(chain_id.clone(), pending_accounts.clone());
+                pending_accounts
+            }
+        };
+        match &input.opportunity.program {
+            entities::OpportunitySvmProgram::Limo(program) => {
+                self.verify_limo_opportunity(
+                    &auction_service,
+                    pending_accounts,
+                    &input.opportunity,
+                    program,
+                )
+                .await
+            }
+            entities::OpportunitySvmProgram::Phantom(program) => {
+                self.verify_phantom_opportunity(
+                    &auction_service,
+                    pending_accounts,
+                    &input.opportunity.core_fields.sell_tokens,
+                    program,
+                )
+                .await
+            }
+        }
+    }
+}
+
 impl Verification<ChainTypeSvm> for Service<ChainTypeSvm> {
     async fn verify_opportunity(
         &self,
         input: VerifyOpportunityInput<entities::OpportunityCreateSvm>,
     ) -> Result<entities::OpportunityVerificationResult, RestError> {
-        self.get_config(&input.opportunity.core_fields.chain_id)?;
+        self.verify_opportunity_with_pending_accounts(input, &mut HashMap::new())
+            .await
+    }
 
-        // To make sure it'll be expired after a minute
-        // TODO - change this to a more realistic value
-        Ok(entities::OpportunityVerificationResult::UnableToSpoof)
+    async fn verify_opportunities(
+        &self,
+        inputs: Vec<VerifyOpportunityInput<entities::OpportunityCreateSvm>>,
+    ) -> Vec<Result<entities::OpportunityVerificationResult, RestError>> {
+        let mut pending_accounts_by_chain = HashMap::new();
+        let mut results = vec![];
+        for input in inputs {
+            results.push(
+                self.verify_opportunity_with_pending_accounts(
+                    input,
+                    &mut pending_accounts_by_chain,
+                )
+                .await,
+            );
+        }
+        results
     }
 }
@@ -5,8 +5,10 @@
 /// The spoofing is done by finding the storage slot of the balance and allowance of an address.
 /// This approach is just a heuristic and will not work for all tokens, specially if the token
 /// has a custom storage layout or logic to calculate the balance or allowance
-/// Finding the storage slot is done by brute forcing the storage slots (only the first 32 slots)
-/// and checking if the output of the balance or allowance is the expected value.
+/// Finding the storage slot is done by overriding a batch of candidate slots at once with different values
+/// and checking which value is returned by the balance or allowance call.
+/// The candidates are the first slots of the contract, with both the Solidity and the Vyper mapping layouts,
+/// and the namespaced storage of the upgradeable OpenZeppelin tokens.
 use ethers::addressbook::Address;
 use {
     super::entities,
@@ -21,28 +23,55 @@ use {
             spoof,
             Bytes,
             LocalWallet,
+            Middleware,
             Provider,
             RawCall,
             Signer,
             H256,
             U256,
         },
+        types::transaction::eip2718::TypedTransaction,
         utils::keccak256,
     },
-    std::sync::Arc,
+    std::{
+        collections::HashMap,
+        str::FromStr,
+        sync::Arc,
+    },
 };
 
+/// Calculate the storage key of a mapping entry.
+/// Solidity hashes the key before the slot of the mapping, while Vyper hashes the slot first.
+fn calculate_mapping_storage_key(key: H256, slot: H256, layout: entities::StorageLayout) -> H256 {
+    let mut buffer: [u8; 64] = [0; 64];
+    let (first, second) = match layout {
+        entities::StorageLayout::Solidity => (key, slot),
+        entities::StorageLayout::Vyper => (slot, key),
+    };
+    buffer[0..32].copy_from_slice(first.as_bytes());
+    buffer[32..64].copy_from_slice(second.as_bytes());
+    keccak256(Bytes::from(buffer)).into()
+}
+
+fn slot_to_h256(slot: U256) -> H256 {
+    let mut buffer: [u8; 32] = [0; 32];
+    slot.to_big_endian(&mut buffer);
+    H256::from(buffer)
+}
+
 /// Calculate the storage key for the balance of an address in an ERC20 token. This is used to spoof the balance.
 ///
 /// # Arguments
 ///
 /// * `owner`: The address of the owner of the balance.
 /// * `balance_slot`: The slot where the balance mapping is located inside the contract storage.
-pub fn calculate_balance_storage_key(owner: Address, balance_slot: U256) -> H256 {
-    let mut buffer: [u8; 64] = [0; 64];
-    buffer[12..32].copy_from_slice(owner.as_bytes());
-    balance_slot.to_big_endian(buffer[32..64].as_mut());
-    keccak256(Bytes::from(buffer)).into()
+/// * `layout`: The layout of the mapping in the contract storage.
+pub fn calculate_balance_storage_key(
+    owner: Address,
+    balance_slot: U256,
+    layout: entities::StorageLayout,
+) -> H256 {
+    calculate_mapping_storage_key(owner.into(), slot_to_h256(balance_slot), layout)
 }
 
 /// Calculate the storage key for the allowance of an spender for an address in an ERC20 token.
@@ -53,50 +82,111 @@ pub fn calculate_balance_storage_key(owner: Address, balance_slot: U256) -> H256
 /// * `owner`: The address of the owner where the allowance is calculated.
 /// * `spender`: The address of the spender where the allowance is calculated.
 /// * `allowance_slot`: The slot where the allowance mapping is located inside the contract storage.
+/// * `layout`: The layout of the mapping in the contract storage.
 pub fn calculate_allowance_storage_key(
     owner: Address,
     spender: Address,
     allowance_slot: U256,
+    layout: entities::StorageLayout,
 ) -> H256 {
-    let mut buffer_spender: [u8; 64] = [0; 64];
-    buffer_spender[12..32].copy_from_slice(owner.as_bytes());
-    allowance_slot.to_big_endian(buffer_spender[32..64].as_mut());
-    let spender_slot = keccak256(Bytes::from(buffer_spender));
+    let spender_slot =
+        calculate_mapping_storage_key(owner.into(), slot_to_h256(allowance_slot), layout);
+    calculate_mapping_storage_key(spender.into(), spender_slot, layout)
+}
 
-    let mut buffer_allowance: [u8; 64] = [0; 64];
-    buffer_allowance[12..32].copy_from_slice(spender.as_bytes());
-    buffer_allowance[32..64].copy_from_slice(&spender_slot);
-    keccak256(Bytes::from(buffer_allowance)).into()
+/// Number of candidate slots which are overridden in a single call.
+const SLOT_BATCH_SIZE: usize = 128;
+/// The ERC-7201 storage location of the ERC20 state of the OpenZeppelin v5 upgradeable tokens.
+/// The balances mapping is the first field and the allowances mapping is the second one.
+const OPENZEPPELIN_ERC20_STORAGE_LOCATION: &str =
+    "52c63247e1f47db19d5ce0460030c497f067ca4cebf71ba98eeadabe20bace00";
+/// The EIP-1967 slot holding the implementation address of a proxy.
+const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+fn get_candidate_slots(search_limit: u64) -> Vec<U256> {
+    let namespace = U256::from_str_radix(OPENZEPPELIN_ERC20_STORAGE_LOCATION, 16)
+        .expect("Failed to parse the storage location");
+    (0..search_limit)
+        .map(U256::from)
+        .chain([namespace, namespace + 1])
+        .collect()
+}
+
+/// Get the implementation of the token if it is an EIP-1967 proxy.
+/// The storage of a proxy is used by its implementation, so the slots are found on the proxy itself,
+/// but they should be found again if the implementation is upgraded.
+pub async fn get_implementation(
+    token: Address,
+    client: Arc<Provider<TracedClient>>,
+) -> anyhow::Result<Option<Address>> {
+    let slot = H256::from_str(EIP1967_IMPLEMENTATION_SLOT)
+        .expect("Failed to parse the implementation slot");
+    let value = client.get_storage_at(token, slot, None).await?;
+    if value.is_zero() {
+        Ok(None)
+    } else {
+        Ok(Some(Address::from(value)))
+    }
 }
 
-const MAX_SLOT_FOR_BRUTEFORCE: i32 = 32;
+/// Find the slot for which the spoofed value is returned by the call.
+/// All the candidate slots of a batch are spoofed with different values in the same call.
+async fn find_spoofed_slot(
+    token: Address,
+    client: Arc<Provider<TracedClient>>,
+    tx: &TypedTransaction,
+    candidate_slots: &[U256],
+    get_storage_key: impl Fn(U256) -> H256,
+) -> anyhow::Result<Option<U256>> {
+    for batch in candidate_slots.chunks(SLOT_BATCH_SIZE) {
+        let mut state = spoof::State::default();
+        let mut values = HashMap::new();
+        for slot in batch {
+            let value: [u8; 32] = rand::random();
+            state
+                .account(token)
+                .store(get_storage_key(*slot), value.into());
+            values.insert(value, *slot);
+        }
+        let result = client.call_raw(tx).state(&state).await?;
+        if let Ok(value) = <[u8; 32]>::try_from(result.as_ref()) {
+            if let Some(slot) = values.get(&value) {
+                return Ok(Some(*slot));
+            }
+        }
+    }
+    Ok(None)
+}
 
-/// Find the balance slot of an ERC20 token that can be used to spoof the balance of an address.
+/// Find the balance slot of an ERC20 token and the layout of its mappings
+/// that can be used to spoof the balance of an address.
 /// Returns an error if no slot is found or if the network calls fail.
 ///
 /// # Arguments
 ///
 /// * `token`: ERC20 token address.
 /// * `client`: Client to interact with the blockchain.
+/// * `candidate_slots`: The slots to check.
 #[tracing::instrument(skip_all)]
 async fn find_spoof_balance_slot(
     token: Address,
     client: Arc<Provider<TracedClient>>,
-) -> anyhow::Result<U256> {
+    candidate_slots: &[U256],
+) -> anyhow::Result<(U256, entities::StorageLayout)> {
     let contract = ERC20::new(token, client.clone());
     let fake_owner = LocalWallet::new(&mut rand::thread_rng());
-    for balance_slot in 0..MAX_SLOT_FOR_BRUTEFORCE {
-        let tx = contract.balance_of(fake_owner.address()).tx;
-        let mut state = spoof::State::default();
-        let balance_storage_key =
-            calculate_balance_storage_key(fake_owner.address(), balance_slot.into());
-        let value: [u8; 32] = rand::random();
-        state
-            .account(token)
-            .store(balance_storage_key, value.into());
-        let result = client.call_raw(&tx).state(&state).await?;
-        if result == Bytes::from(value) {
-            return Ok(balance_slot.into());
+    let tx = contract.balance_of(fake_owner.address()).tx;
+    for layout in [
+        entities::StorageLayout::Solidity,
+        entities::StorageLayout::Vyper,
+    ] {
+        if let Some(slot) = find_spoofed_slot(token, client.clone(), &tx, candidate_slots, |slot| {
+            calculate_balance_storage_key(fake_owner.address(), slot, layout)
+        })
+        .await?
+        {
+            return Ok((slot, layout));
         }
     }
     Err(anyhow!("Could not find balance slot"))
@@ -109,35 +199,26 @@ async fn find_spoof_balance_slot(
 ///
 /// * `token`: ERC20 token address.
 /// * `client`: Client to interact with the blockchain.
+/// * `candidate_slots`: The slots to check.
+/// * `layout`: The layout of the mappings, found with the balance slot.
 #[tracing::instrument(skip_all)]
 async fn find_spoof_allowance_slot(
     token: Address,
     client: Arc<Provider<TracedClient>>,
+    candidate_slots: &[U256],
+    layout: entities::StorageLayout,
 ) -> anyhow::Result<U256> {
     let contract = ERC20::new(token, client.clone());
     let fake_owner = LocalWallet::new(&mut rand::thread_rng());
     let fake_spender = LocalWallet::new(&mut rand::thread_rng());
-
-    for allowance_slot in 0..MAX_SLOT_FOR_BRUTEFORCE {
-        let tx = contract
-            .allowance(fake_owner.address(), fake_spender.address())
-            .tx;
-        let mut state = spoof::State::default();
-        let allowance_storage_key = calculate_allowance_storage_key(
-            fake_owner.address(),
-            fake_spender.address(),
-            allowance_slot.into(),
-        );
-        let value: [u8; 32] = rand::random();
-        state
-            .account(token)
-            .store(allowance_storage_key, value.into());
-        let result = client.call_raw(&tx).state(&state).await?;
-        if result == Bytes::from(value) {
-            return Ok(allowance_slot.into());
-        }
-    }
-    Err(anyhow!("Could not find allowance slot"))
+    let tx = contract
+        .allowance(fake_owner.address(), fake_spender.address())
+        .tx;
+    find_spoofed_slot(token, client, &tx, candidate_slots, |slot| {
+        calculate_allowance_storage_key(fake_owner.address(), fake_spender.address(), slot, layout)
+    })
+    .await?
+    .ok_or_else(|| anyhow!("Could not find allowance slot"))
 }
 
 /// Find the spoof info for an ERC20 token. This includes the balance slot and the allowance slot.
@@ -146,18 +227,26 @@ async fn find_spoof_allowance_slot(
 ///
 /// * `token`: ERC20 token address.
 /// * `client`: Client to interact with the blockchain.
+/// * `search_limit`: Number of the first storage slots to check.
 #[tracing::instrument(skip_all, fields(token=%token))]
 pub async fn find_spoof_info(
     token: Address,
     client: Arc<Provider<TracedClient>>,
+    search_limit: u64,
 ) -> anyhow::Result<entities::SpoofInfo> {
-    let balance_slot = find_spoof_balance_slot(token, client.clone()).await?;
-    let allowance_slot = find_spoof_allowance_slot(token, client.clone()).await?;
+    let candidate_slots = get_candidate_slots(search_limit);
+    let implementation = get_implementation(token, client.clone()).await?;
+    let (balance_slot, layout) =
+        find_spoof_balance_slot(token, client.clone(), &candidate_slots).await?;
+    let allowance_slot =
+        find_spoof_allowance_slot(token, client.clone(), &candidate_slots, layout).await?;
     Ok(entities::SpoofInfo {
         token,
         state: entities::SpoofState::Spoofed {
             balance_slot,
             allowance_slot,
+            layout,
         },
+        implementation,
     })
 }
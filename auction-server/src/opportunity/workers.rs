@@ -4,9 +4,12 @@ use {
         ChainType,
         Service,
     },
-    crate::server::{
-        EXIT_CHECK_INTERVAL,
-        SHOULD_EXIT,
+    crate::{
+        kernel::health::LoopHealthRecorder,
+        server::{
+            EXIT_CHECK_INTERVAL,
+            SHOULD_EXIT,
+        },
     },
     std::{
         sync::{
@@ -17,7 +20,10 @@ use {
     },
 };
 
-pub async fn run_verification_loop<T: ChainType>(service: Arc<Service<T>>) -> anyhow::Result<()>
+pub async fn run_verification_loop<T: ChainType>(
+    service: Arc<Service<T>>,
+    health: LoopHealthRecorder,
+) -> anyhow::Result<()>
 where
     Service<T>: Verification<T>,
 {
@@ -30,6 +36,7 @@ where
     // this should be replaced by a subscription to the chain and have a different trigger
     let mut submission_interval = tokio::time::interval(Duration::from_secs(5));
     while !SHOULD_EXIT.load(Ordering::Acquire) {
+        health.record_tick();
         tokio::select! {
             _ = submission_interval.tick() => {
                 service.remove_invalid_or_expired_opportunities().await;
@@ -15,7 +15,19 @@ use {
             MigrateOptions,
             RunOptions,
         },
-        kernel::traced_sender_svm::TracedSenderSvm,
+        kernel::{
+            health::{
+                HealthRegistry,
+                LoopHealthRecorder,
+            },
+            signer::{
+                RelayerSignerEvm,
+                RelayerSignerSvm,
+                RemoteSigner,
+                RemoteSignerEndpoint,
+            },
+            traced_sender_svm::TracedSenderSvm,
+        },
         models,
         opportunity::{
             service as opportunity_service,
@@ -76,21 +88,24 @@ use {
         time::sleep,
     },
     tokio_util::task::TaskTracker,
+    uuid::Uuid,
 };
 
-async fn fault_tolerant_handler<F, Fut>(name: String, f: F)
+async fn fault_tolerant_handler<F, Fut>(name: String, health: Arc<HealthRegistry>, f: F)
 where
-    F: Fn() -> Fut,
+    F: Fn(LoopHealthRecorder) -> Fut,
     Fut: Future<Output = Result<()>> + Send + 'static,
     Fut::Output: Send + 'static,
 {
+    let recorder = LoopHealthRecorder::new(name.clone(), health);
     loop {
-        let res = tokio::spawn(f()).await;
+        let res = tokio::spawn(f(recorder.clone())).await;
         match res {
             Ok(result) => match result {
                 Ok(_) => break, // This will happen on graceful shutdown
                 Err(err) => {
                     tracing::error!("{} returned error: {:?}", name, err);
+                    recorder.record_error();
                     sleep(Duration::from_millis(500)).await;
                 }
             },
@@ -103,6 +118,61 @@ where
     }
 }
 
+async fn fetch_integrators(db: &PgPool) -> Vec<models::Integrator> {
+    sqlx::query_as(
+        "SELECT integrator.*, COALESCE(ARRAY_AGG(integrator_profile.profile_id) FILTER (WHERE integrator_profile.profile_id IS NOT NULL), '{}') AS profile_ids
+        FROM integrator LEFT JOIN integrator_profile ON integrator_profile.integrator_id = integrator.id
+        GROUP BY integrator.id",
+    )
+    .fetch_all(db)
+    .await
+    .expect("Failed to fetch integrators from database")
+}
+
+async fn fetch_permission_key_namespaces(db: &PgPool) -> Vec<models::PermissionKeyNamespace> {
+    sqlx::query_as("SELECT * FROM permission_key_namespace")
+        .fetch_all(db)
+        .await
+        .expect("Failed to fetch permission key namespaces from database")
+}
+
+/// Registers the wallet router account of the chain config as the phantom integrator,
+/// with the profiles named after it, if the router is not registered yet.
+async fn register_legacy_integrators(
+    db: &PgPool,
+    chains_svm: &HashMap<ChainId, Arc<ChainStoreSvm>>,
+) -> Result<()> {
+    for (chain_id, chain_store) in chains_svm {
+        let router = match chain_store.config.wallet_program_router_account {
+            Some(router) => router.to_string(),
+            None => continue,
+        };
+        let id = Uuid::new_v4();
+        let result = sqlx::query(
+            "INSERT INTO integrator (id, chain_id, name, program, router_account, submitter) VALUES ($1, $2, 'phantom', 'phantom', $3, 'integrator') ON CONFLICT DO NOTHING",
+        )
+        .bind(id)
+        .bind(chain_id)
+        .bind(router.clone())
+        .execute(db)
+        .await?;
+        if result.rows_affected() > 0 {
+            tracing::info!(
+                chain_id = %chain_id,
+                router = %router,
+                "Registered the legacy phantom integrator"
+            );
+            sqlx::query(
+                "INSERT INTO integrator_profile (integrator_id, profile_id) SELECT $1, id FROM profile WHERE name = 'phantom'",
+            )
+            .bind(id)
+            .execute(db)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
 async fn fetch_access_tokens(db: &PgPool) -> HashMap<models::AccessTokenToken, models::Profile> {
     let access_tokens = sqlx::query_as!(
         models::AccessToken,
@@ -140,7 +210,10 @@ pub fn setup_metrics_recorder() -> Result<PrometheusHandle> {
 }
 
 
-async fn setup_chain_store_evm(config_map: ConfigMap) -> Result<HashMap<ChainId, ChainStoreEvm>> {
+async fn setup_chain_store_evm(
+    config_map: ConfigMap,
+    health: Arc<HealthRegistry>,
+) -> Result<HashMap<ChainId, ChainStoreEvm>> {
     join_all(
         config_map
             .chains
@@ -149,10 +222,11 @@ async fn setup_chain_store_evm(config_map: ConfigMap) -> Result<HashMap<ChainId,
                 Config::Svm(_) => None,
                 Config::Evm(chain_config) => {
                     let (chain_id, chain_config) = (chain_id.clone(), chain_config.clone());
+                    let health = health.clone();
                     Some(async move {
                         Ok((
                             chain_id.clone(),
-                            ChainStoreEvm::create_store(chain_id, chain_config).await?,
+                            ChainStoreEvm::create_store(chain_id, chain_config, health).await?,
                         ))
                     })
                 }
@@ -163,6 +237,42 @@ async fn setup_chain_store_evm(config_map: ConfigMap) -> Result<HashMap<ChainId,
     .collect()
 }
 
+async fn setup_relayer_signer_evm(run_options: &RunOptions) -> Result<RelayerSignerEvm> {
+    match &run_options.remote_signer.url {
+        Some(url) => {
+            RelayerSignerEvm::new_remote(RemoteSigner::new(
+                url.parse::<RemoteSignerEndpoint>()?,
+                run_options.remote_signer.key_id_evm.clone(),
+                run_options.remote_signer.timeout,
+            )?)
+            .await
+        }
+        None => Ok(RelayerSignerEvm::Local(
+            run_options
+                .subwallet_private_key
+                .as_ref()
+                .ok_or_else(|| anyhow!("No evm relayer private key or remote signer provided"))?
+                .parse::<LocalWallet>()?,
+        )),
+    }
+}
+
+async fn setup_relayer_signer_svm(run_options: &RunOptions) -> Result<Option<RelayerSignerSvm>> {
+    match &run_options.remote_signer.url {
+        Some(url) => Ok(Some(
+            RelayerSignerSvm::new_remote(RemoteSigner::new(
+                url.parse::<RemoteSignerEndpoint>()?,
+                run_options.remote_signer.key_id_svm.clone(),
+                run_options.remote_signer.timeout,
+            )?)
+            .await?,
+        )),
+        None => Ok(run_options.private_key_svm.as_ref().map(|private_key| {
+            RelayerSignerSvm::Local(Arc::new(Keypair::from_base58_string(private_key)))
+        })),
+    }
+}
+
 const NOTIFICATIONS_CHAN_LEN: usize = 1000;
 
 // TODO move to kernel repo
@@ -216,12 +326,18 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
         )
     })?;
 
-    let wallet = run_options.subwallet_private_key.parse::<LocalWallet>()?;
+    let wallet = setup_relayer_signer_evm(&run_options).await?;
     tracing::info!("Using wallet address: {:?}", wallet.address());
 
-    let chains_evm = setup_chain_store_evm(config_map.clone()).await?;
+    let health = Arc::new(HealthRegistry::default());
+    let chains_evm = setup_chain_store_evm(config_map.clone(), health.clone()).await?;
 
     let chains_svm = setup_chain_store_svm(config_map)?;
+    let relayer_svm = if chains_svm.is_empty() {
+        None
+    } else {
+        setup_relayer_signer_svm(&run_options).await?
+    };
 
     let (broadcast_sender, broadcast_receiver) =
         tokio::sync::broadcast::channel(NOTIFICATIONS_CHAN_LEN);
@@ -244,19 +360,25 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
         .collect::<HashMap<_, _>>();
 
     let access_tokens = fetch_access_tokens(&pool).await;
+    register_legacy_integrators(&pool, &chains_svm).await?;
+    let integrators = fetch_integrators(&pool).await;
+    let permission_key_namespaces = fetch_permission_key_namespaces(&pool).await;
     let store = Arc::new(Store {
-        db:               pool.clone(),
-        chains_evm:       chains_evm.clone(),
-        chains_svm:       chains_svm.clone(),
-        ws:               ws::WsState {
+        db:                        pool.clone(),
+        chains_evm:                chains_evm.clone(),
+        chains_svm:                chains_svm.clone(),
+        ws:                        ws::WsState {
             subscriber_counter: AtomicUsize::new(0),
             broadcast_sender,
             broadcast_receiver,
         },
-        task_tracker:     task_tracker.clone(),
-        secret_key:       run_options.secret_key.clone(),
-        access_tokens:    RwLock::new(access_tokens),
-        metrics_recorder: setup_metrics_recorder()?,
+        task_tracker:              task_tracker.clone(),
+        secret_key:                run_options.secret_key.clone(),
+        access_tokens:             RwLock::new(access_tokens),
+        integrators:               RwLock::new(integrators),
+        permission_key_namespaces: RwLock::new(permission_key_namespaces),
+        metrics_recorder:          setup_metrics_recorder()?,
+        health:                    health.clone(),
     });
 
     let opportunity_service_evm = Arc::new(opportunity_service::Service::<
@@ -282,14 +404,19 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 auction_service::ServiceEnum::Evm(auction_service::Service::new(
                     pool.clone(),
                     auction_service::Config {
-                        chain_id:     chain_id.clone(),
-                        chain_config: auction_service::ConfigEvm::new(
+                        chain_id:                   chain_id.clone(),
+                        relayer_balance_thresholds: chain_store
+                            .config
+                            .relayer_balance_thresholds
+                            .clone(),
+                        chain_config:               auction_service::ConfigEvm::new(
                             wallet.clone(),
                             chain_store.config.express_relay_contract,
                             chain_store.provider.clone(),
                             chain_store.block_gas_limit,
                             chain_store.config.geth_ws_addr.clone(),
                             chain_store.network_id,
+                            chain_store.config.confirmations,
                         ),
                     },
                     opportunity_service_evm.clone(),
@@ -306,35 +433,40 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 auction_service::ServiceEnum::Svm(auction_service::Service::new(
                     pool.clone(),
                     auction_service::Config {
-                        chain_id:     chain_id.clone(),
-                        chain_config: auction_service::ConfigSvm {
+                        chain_id:                   chain_id.clone(),
+                        relayer_balance_thresholds: chain_store
+                            .config
+                            .relayer_balance_thresholds
+                            .clone(),
+                        chain_config:               auction_service::ConfigSvm {
                             client:                        TracedSenderSvm::new_client(
                                 chain_id.clone(),
-                                chain_store.config.rpc_read_url.as_str(),
+                                &chain_store.config.get_rpc_read_urls(),
                                 chain_store.config.rpc_timeout,
+                                chain_store.config.get_rpc_hedge_delay(),
                                 RpcClientConfig::with_commitment(CommitmentConfig::processed()),
+                                health.clone(),
                             ),
                             simulator:                     Simulator::new(
                                 TracedSenderSvm::new_client(
                                     chain_id.clone(),
-                                    chain_store.config.rpc_read_url.as_str(),
+                                    &chain_store.config.get_rpc_read_urls(),
                                     chain_store.config.rpc_timeout,
+                                    chain_store.config.get_rpc_hedge_delay(),
                                     RpcClientConfig::with_commitment(CommitmentConfig::processed()),
+                                    health.clone(),
                                 ),
+                                CommitmentConfig {
+                                    commitment: chain_store.config.commitment.simulation,
+                                },
                             ),
-                            wallet_program_router_account: chain_store
-                                .config
-                                .wallet_program_router_account,
                             express_relay:                 auction_service::ExpressRelaySvm {
                                 program_id:                  chain_store
                                     .config
                                     .express_relay_program_id,
-                                relayer:                     Keypair::from_base58_string(
-                                    &run_options
-                                        .private_key_svm
-                                        .clone()
-                                        .expect("No svm private key provided for chain"),
-                                ),
+                                relayer:                     relayer_svm
+                                    .clone()
+                                    .expect("No svm relayer key provided for chain"),
                                 permission_account_position: env!(
                                     "SUBMIT_BID_PERMISSION_ACCOUNT_POSITION"
                                 )
@@ -349,14 +481,24 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                             ws_address:                    chain_store.config.ws_addr.clone(),
                             tx_broadcaster_client:         TracedSenderSvm::new_client(
                                 chain_id.clone(),
-                                chain_store.config.rpc_tx_submission_url.as_str(),
+                                &chain_store.config.get_rpc_tx_submission_urls(),
                                 chain_store.config.rpc_timeout,
+                                chain_store.config.get_rpc_hedge_delay(),
                                 RpcClientConfig::with_commitment(CommitmentConfig::processed()),
+                                health.clone(),
                             ),
                             log_sender:                    chain_store.log_sender.clone(),
                             prioritization_fee_percentile: chain_store
                                 .config
                                 .prioritization_fee_percentile,
+                            conclusion_commitment:         CommitmentConfig {
+                                commitment: chain_store.config.commitment.conclusion,
+                            },
+                            settlement_commitment:         chain_store
+                                .config
+                                .commitment
+                                .settlement
+                                .map(|commitment| CommitmentConfig { commitment }),
                             // _dummy_log_receiver: chain_store._dummy_log_receiver.clone(),
                         },
                     },
@@ -374,12 +516,14 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
     for (chain_id, service) in auction_services.iter() {
         match service {
             auction_service::ServiceEnum::Evm(service) => {
+                service.load_chain_status().await?;
                 let config = opportunity_service_evm
                     .get_config(chain_id)
                     .expect("Failed to get opportunity service evm config");
                 config.inject_auction_service(service.clone()).await;
             }
             auction_service::ServiceEnum::Svm(service) => {
+                service.load_chain_status().await?;
                 let config = opportunity_service_svm
                     .get_config(chain_id)
                     .expect("Failed to get opportunity service svm config");
@@ -401,9 +545,10 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 if let auction_service::ServiceEnum::Evm(service) = service {
                     Some(fault_tolerant_handler(
                         format!("submission loop for chain {}", chain_id.clone()),
-                        || {
+                        health.clone(),
+                        |recorder| {
                             let service = service.clone();
-                            async move { service.run_submission_loop().await }
+                            async move { service.run_submission_loop(recorder).await }
                         },
                     ))
                 } else {
@@ -417,9 +562,10 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 if let auction_service::ServiceEnum::Svm(service) = service {
                     Some(fault_tolerant_handler(
                         format!("submission loop for chain {}", chain_id.clone()),
-                        || {
+                        health.clone(),
+                        |recorder| {
                             let service = service.clone();
-                            async move { service.run_submission_loop().await }
+                            async move { service.run_submission_loop(recorder).await }
                         },
                     ))
                 } else {
@@ -433,9 +579,10 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 if let auction_service::ServiceEnum::Svm(service) = service {
                     Some(fault_tolerant_handler(
                         format!("log listener loop for chain {}", chain_id.clone()),
-                        || {
+                        health.clone(),
+                        |recorder| {
                             let service = service.clone();
-                            async move { service.run_log_listener_loop().await }
+                            async move { service.run_log_listener_loop(recorder).await }
                         },
                     ))
                 } else {
@@ -453,9 +600,10 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                                 "auction conclusion loops loop for chain {}",
                                 chain_id.clone()
                             ),
-                            || {
+                            health.clone(),
+                            |recorder| {
                                 let service = service.clone();
-                                async move { service.run_auction_conclusion_loop().await }
+                                async move { service.run_auction_conclusion_loop(recorder).await }
                             },
                         ))
                     } else {
@@ -469,9 +617,27 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
                 if let auction_service::ServiceEnum::Evm(service) = service {
                     Some(fault_tolerant_handler(
                         format!("tracker loop for chain {}", chain_id.clone()),
-                        || {
+                        health.clone(),
+                        |recorder| {
+                            let service = service.clone();
+                            async move { service.run_tracker_loop(recorder).await }
+                        },
+                    ))
+                } else {
+                    None
+                }
+            });
+            join_all(tracker_loops).await;
+        },
+        async {
+            let tracker_loops = auction_services.iter().filter_map(|(chain_id, service)| {
+                if let auction_service::ServiceEnum::Svm(service) = service {
+                    Some(fault_tolerant_handler(
+                        format!("tracker loop for chain {}", chain_id.clone()),
+                        health.clone(),
+                        |recorder| {
                             let service = service.clone();
-                            async move { service.run_tracker_loop().await }
+                            async move { service.run_tracker_loop(recorder).await }
                         },
                     ))
                 } else {
@@ -480,14 +646,32 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
             });
             join_all(tracker_loops).await;
         },
+        async {
+            let settlement_loops = auction_services.iter().filter_map(|(chain_id, service)| {
+                if let auction_service::ServiceEnum::Svm(service) = service {
+                    Some(fault_tolerant_handler(
+                        format!("settlement loop for chain {}", chain_id.clone()),
+                        health.clone(),
+                        |recorder| {
+                            let service = service.clone();
+                            async move { service.run_settlement_loop(recorder).await }
+                        },
+                    ))
+                } else {
+                    None
+                }
+            });
+            join_all(settlement_loops).await;
+        },
         async {
             let watcher_loops = auction_services.iter().filter_map(|(chain_id, service)| {
                 if let auction_service::ServiceEnum::Svm(service) = service {
                     Some(fault_tolerant_handler(
                         format!("watcher loop for chain {}", chain_id.clone()),
-                        || {
+                        health.clone(),
+                        |recorder| {
                             let service = service.clone();
-                            async move { service.run_watcher_loop().await }
+                            async move { service.run_watcher_loop(recorder).await }
                         },
                     ))
                 } else {
@@ -496,20 +680,23 @@ pub async fn start_server(run_options: RunOptions) -> Result<()> {
             });
             join_all(watcher_loops).await;
         },
-        fault_tolerant_handler("evm verification loop".to_string(), || {
-            run_verification_loop(store_new.opportunity_service_evm.clone())
-        }),
-        fault_tolerant_handler("svm verification loop".to_string(), || {
-            run_verification_loop(store_new.opportunity_service_svm.clone())
-        }),
-        fault_tolerant_handler("start api".to_string(), || api::start_api(
+        fault_tolerant_handler(
+            "evm verification loop".to_string(),
+            health.clone(),
+            |recorder| run_verification_loop(store_new.opportunity_service_evm.clone(), recorder)
+        ),
+        fault_tolerant_handler(
+            "svm verification loop".to_string(),
+            health.clone(),
+            |recorder| run_verification_loop(store_new.opportunity_service_svm.clone(), recorder)
+        ),
+        fault_tolerant_handler("start api".to_string(), health.clone(), |_| api::start_api(
             run_options.clone(),
             store_new.clone(),
         )),
-        fault_tolerant_handler("start metrics".to_string(), || per_metrics::start_metrics(
-            run_options.clone(),
-            store.clone()
-        )),
+        fault_tolerant_handler("start metrics".to_string(), health.clone(), |_| {
+            per_metrics::start_metrics(run_options.clone(), store.clone())
+        }),
     );
 
     // To make sure all the spawned tasks will finish their job before shut down
@@ -1,6 +1,8 @@
 use {
     crate::{
         api::{
+            integrator as ApiIntegrator,
+            permission_key_namespace as ApiPermissionKeyNamespace,
             profile as ApiProfile,
             ws::WsState,
             RestError,
@@ -11,7 +13,10 @@ use {
             ConfigEvm,
             ConfigSvm,
         },
-        kernel::traced_client::TracedClient,
+        kernel::{
+            health::HealthRegistry,
+            traced_client::TracedClient,
+        },
         models,
         opportunity::service as opportunity_service,
     },
@@ -65,11 +70,14 @@ impl ChainStoreEvm {
     pub fn get_chain_provider(
         chain_id: &String,
         chain_config: &ConfigEvm,
+        health: Arc<HealthRegistry>,
     ) -> anyhow::Result<Provider<TracedClient>> {
         let mut provider = TracedClient::new(
             chain_id.clone(),
-            &chain_config.geth_rpc_addr,
+            &chain_config.get_rpc_addrs(),
             chain_config.rpc_timeout,
+            chain_config.get_rpc_hedge_delay(),
+            health,
         )
         .map_err(|err| {
             tracing::error!(
@@ -88,8 +96,12 @@ impl ChainStoreEvm {
         provider.set_interval(Duration::from_secs(chain_config.poll_interval));
         Ok(provider)
     }
-    pub async fn create_store(chain_id: String, config: ConfigEvm) -> anyhow::Result<Self> {
-        let provider = Self::get_chain_provider(&chain_id, &config)?;
+    pub async fn create_store(
+        chain_id: String,
+        config: ConfigEvm,
+        health: Arc<HealthRegistry>,
+    ) -> anyhow::Result<Self> {
+        let provider = Self::get_chain_provider(&chain_id, &config, health)?;
 
         let id = provider.get_chainid().await?.as_u64();
         let block = provider
@@ -107,11 +119,10 @@ impl ChainStoreEvm {
 }
 
 pub struct ChainStoreSvm {
-    pub log_sender:                    Sender<Response<RpcLogsResponse>>,
+    pub log_sender:          Sender<Response<RpcLogsResponse>>,
     // only to avoid closing the channel
-    pub _dummy_log_receiver:           Receiver<Response<RpcLogsResponse>>,
-    pub config:                        ConfigSvm,
-    pub wallet_program_router_account: Pubkey,
+    pub _dummy_log_receiver: Receiver<Response<RpcLogsResponse>>,
+    pub config:              ConfigSvm,
 }
 
 impl ChainStoreSvm {
@@ -122,21 +133,23 @@ impl ChainStoreSvm {
             log_sender: tx,
             _dummy_log_receiver: rx,
 
-            wallet_program_router_account: config.wallet_program_router_account,
             config,
         }
     }
 }
 
 pub struct Store {
-    pub chains_evm:       HashMap<ChainId, Arc<ChainStoreEvm>>,
-    pub chains_svm:       HashMap<ChainId, Arc<ChainStoreSvm>>,
-    pub ws:               WsState,
-    pub db:               sqlx::PgPool,
-    pub task_tracker:     TaskTracker,
-    pub secret_key:       String,
-    pub access_tokens:    RwLock<HashMap<models::AccessTokenToken, models::Profile>>,
-    pub metrics_recorder: PrometheusHandle,
+    pub chains_evm:                HashMap<ChainId, Arc<ChainStoreEvm>>,
+    pub chains_svm:                HashMap<ChainId, Arc<ChainStoreSvm>>,
+    pub ws:                        WsState,
+    pub db:                        sqlx::PgPool,
+    pub task_tracker:              TaskTracker,
+    pub secret_key:                String,
+    pub access_tokens:             RwLock<HashMap<models::AccessTokenToken, models::Profile>>,
+    pub integrators:               RwLock<Vec<models::Integrator>>,
+    pub permission_key_namespaces: RwLock<Vec<models::PermissionKeyNamespace>>,
+    pub metrics_recorder:          PrometheusHandle,
+    pub health:                    Arc<HealthRegistry>,
 }
 
 pub struct StoreNew {
@@ -339,4 +352,164 @@ impl Store {
             .cloned()
             .ok_or(RestError::InvalidToken)
     }
+
+    pub async fn create_integrator(
+        &self,
+        create_integrator: ApiIntegrator::CreateIntegrator,
+    ) -> Result<models::Integrator, RestError> {
+        if !self.chains_svm.contains_key(&create_integrator.chain_id) {
+            return Err(RestError::InvalidChainId);
+        }
+        let id = Uuid::new_v4();
+        let program: models::IntegratorProgram = create_integrator.program.clone().into();
+        let submitter: models::IntegratorSubmitter = create_integrator.submitter.clone().into();
+        let map_err = |e: sqlx::Error| {
+            if let Some(true) = e.as_database_error().map(|e| e.is_unique_violation()) {
+                return RestError::BadParameters(
+                    "Integrator with this name or router account already exists on the chain"
+                        .to_string(),
+                );
+            }
+            if let Some(true) = e.as_database_error().map(|e| e.is_foreign_key_violation()) {
+                return RestError::ProfileNotFound;
+            }
+            tracing::error!(
+                "DB: Failed to insert integrator: {} - integrator_data: {:?}",
+                e,
+                create_integrator
+            );
+            RestError::TemporarilyUnavailable
+        };
+
+        let mut tx = self.db.begin().await.map_err(map_err)?;
+        let mut integrator: models::Integrator = sqlx::query_as(
+            "INSERT INTO integrator (id, chain_id, name, program, router_account, submitter) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *, '{}'::UUID[] AS profile_ids",
+        )
+        .bind(id)
+        .bind(create_integrator.chain_id.clone())
+        .bind(create_integrator.name.clone())
+        .bind(program)
+        .bind(create_integrator.router_account.to_string())
+        .bind(submitter)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        sqlx::query(
+            "INSERT INTO integrator_profile (integrator_id, profile_id) SELECT $1, UNNEST($2::UUID[]) ON CONFLICT DO NOTHING",
+        )
+        .bind(id)
+        .bind(create_integrator.profile_ids.clone())
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+        tx.commit().await.map_err(map_err)?;
+
+        integrator.profile_ids = create_integrator.profile_ids.clone();
+        self.integrators.write().await.push(integrator.clone());
+        Ok(integrator)
+    }
+
+    pub async fn get_integrators(&self) -> Vec<models::Integrator> {
+        self.integrators.read().await.clone()
+    }
+
+    pub async fn get_integrator_by_router(
+        &self,
+        chain_id: &ChainId,
+        router: &Pubkey,
+    ) -> Option<models::Integrator> {
+        let router = router.to_string();
+        self.integrators
+            .read()
+            .await
+            .iter()
+            .find(|integrator| {
+                integrator.chain_id == *chain_id && integrator.router_account == router
+            })
+            .cloned()
+    }
+
+    pub async fn get_integrator_by_profile(
+        &self,
+        chain_id: &ChainId,
+        profile_id: models::ProfileId,
+    ) -> Option<models::Integrator> {
+        self.integrators
+            .read()
+            .await
+            .iter()
+            .find(|integrator| {
+                integrator.chain_id == *chain_id && integrator.profile_ids.contains(&profile_id)
+            })
+            .cloned()
+    }
+
+    pub async fn create_permission_key_namespace(
+        &self,
+        create_namespace: ApiPermissionKeyNamespace::CreatePermissionKeyNamespace,
+    ) -> Result<models::PermissionKeyNamespace, RestError> {
+        if !self.chains_evm.contains_key(&create_namespace.chain_id) {
+            return Err(RestError::InvalidChainId);
+        }
+        if create_namespace.prefix.is_empty() {
+            return Err(RestError::BadParameters(
+                "Permission key prefix should not be empty".to_string(),
+            ));
+        }
+
+        // The write lock is held until the namespace is added, so that overlapping namespaces can not be created concurrently
+        let mut namespaces = self.permission_key_namespaces.write().await;
+        let prefix = create_namespace.prefix.to_vec();
+        if namespaces.iter().any(|namespace| {
+            namespace.chain_id == create_namespace.chain_id
+                && (namespace.prefix.starts_with(&prefix) || prefix.starts_with(&namespace.prefix))
+        }) {
+            return Err(RestError::BadParameters(
+                "Permission key prefix overlaps with another namespace on the chain".to_string(),
+            ));
+        }
+        let namespace: models::PermissionKeyNamespace = sqlx::query_as(
+            "INSERT INTO permission_key_namespace (id, chain_id, profile_id, prefix) VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(Uuid::new_v4())
+        .bind(create_namespace.chain_id.clone())
+        .bind(create_namespace.profile_id)
+        .bind(prefix)
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| {
+            if let Some(true) = e.as_database_error().map(|e| e.is_foreign_key_violation()) {
+                return RestError::ProfileNotFound;
+            }
+            tracing::error!(
+                "DB: Failed to insert permission key namespace: {} - namespace_data: {:?}",
+                e,
+                create_namespace
+            );
+            RestError::TemporarilyUnavailable
+        })?;
+
+        namespaces.push(namespace.clone());
+        Ok(namespace)
+    }
+
+    pub async fn get_permission_key_namespaces(&self) -> Vec<models::PermissionKeyNamespace> {
+        self.permission_key_namespaces.read().await.clone()
+    }
+
+    /// Returns the profile which owns the namespace of the permission key on the chain.
+    pub async fn get_permission_key_owner(
+        &self,
+        chain_id: &ChainId,
+        permission_key: &[u8],
+    ) -> Option<models::ProfileId> {
+        self.permission_key_namespaces
+            .read()
+            .await
+            .iter()
+            .find(|namespace| {
+                namespace.chain_id == *chain_id && permission_key.starts_with(&namespace.prefix)
+            })
+            .map(|namespace| namespace.profile_id)
+    }
 }
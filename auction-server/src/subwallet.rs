@@ -8,6 +8,10 @@ use {
             ConfigMap,
             SubwalletOptions,
         },
+        kernel::{
+            health::HealthRegistry,
+            signer::RelayerSignerEvm,
+        },
         state::ChainStoreEvm,
     },
     anyhow::{
@@ -19,6 +23,7 @@ use {
         prelude::LocalWallet,
         signers::Signer,
     },
+    std::sync::Arc,
 };
 
 pub async fn sync_subwallets(opts: SubwalletOptions) -> Result<()> {
@@ -51,12 +56,16 @@ async fn sync_subwallets_for_chain(
     chain_config: &ConfigEvm,
     wallet: LocalWallet,
 ) -> Result<()> {
-    let provider = ChainStoreEvm::get_chain_provider(chain_id, chain_config)?;
+    let provider = ChainStoreEvm::get_chain_provider(
+        chain_id,
+        chain_config,
+        Arc::new(HealthRegistry::default()),
+    )?;
     let id = provider.get_chainid().await?.as_u64();
     let express_relay_contract = get_express_relay_contract(
         chain_config.express_relay_contract,
         provider.clone(),
-        wallet.clone(),
+        RelayerSignerEvm::Local(wallet.clone()),
         chain_config.legacy_tx,
         id,
     );